@@ -0,0 +1,36 @@
+use ordmask::packed::{query_included, PackedMask};
+use ordmask::OrdMask;
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let size = 100_000u32;
+    let mask = OrdMask::from((0..size).map(|i| i * 10).collect::<Vec<_>>());
+    let bytes = PackedMask::from_mask(&mask).unwrap().as_bytes();
+    let iterations = 1000;
+
+    for value in [5, size * 5, size * 10] {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            let packed = PackedMask::from_bytes(black_box(&bytes)).unwrap();
+            black_box(packed.included(value));
+        }
+        println!(
+            "decode then query {} on {} key points: {:?}",
+            value,
+            size,
+            start.elapsed() / iterations
+        );
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(query_included(black_box(&bytes), value).unwrap());
+        }
+        println!(
+            "query_included {} on {} key points: {:?}",
+            value,
+            size,
+            start.elapsed() / iterations
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use ordmask::OrdMask;
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let queries: Vec<u64> = (0..1_000_000u64)
+        .map(|i| i.wrapping_mul(6364136223846793005) % 2_000_000)
+        .collect();
+
+    for size in [16, 1024, 65536] {
+        let mask = OrdMask::from((0..size).map(|i| i * 2_000_000 / size).collect::<Vec<_>>());
+
+        let start = Instant::now();
+        let mut count = 0;
+        for value in &queries {
+            count += black_box(&mask).included(value) as usize;
+        }
+        black_box(count);
+        println!(
+            "included on {} key points: {:?} per query",
+            size,
+            start.elapsed() / queries.len() as u32
+        );
+
+        let start = Instant::now();
+        let mut count = 0;
+        for value in &queries {
+            count += black_box(&mask).excluded(value) as usize;
+        }
+        black_box(count);
+        println!(
+            "excluded on {} key points: {:?} per query",
+            size,
+            start.elapsed() / queries.len() as u32
+        );
+    }
+}
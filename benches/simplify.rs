@@ -0,0 +1,48 @@
+use ordmask::OrdMask;
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let size = 1_000_000u64;
+    let iterations = 10;
+    let cases: [(&str, Vec<u64>); 3] = [
+        ("strictly increasing", (0..size).collect()),
+        ("pairs of duplicates", (0..size).map(|i| i / 2).collect()),
+        (
+            "runs of three duplicates",
+            (0..size).map(|i| i / 3).collect(),
+        ),
+    ];
+
+    for (name, key_points) in cases {
+        let mut masks: Vec<_> = (0..iterations)
+            .map(|_| unsafe { OrdMask::with_unchecked(key_points.clone(), false) })
+            .collect();
+        let start = Instant::now();
+        for mask in &mut masks {
+            black_box(mask).simplify();
+        }
+        println!(
+            "simplify {} key points, {}: {:?}",
+            size,
+            name,
+            start.elapsed() / iterations
+        );
+    }
+
+    let mut strings: Vec<OrdMask<String>> = (0..iterations)
+        .map(|_| {
+            let key_points = (0..size / 10).map(|i| format!("{:08}", i / 3)).collect();
+            unsafe { OrdMask::with_unchecked(key_points, false) }
+        })
+        .collect();
+    let start = Instant::now();
+    for mask in &mut strings {
+        black_box(mask).simplify();
+    }
+    println!(
+        "simplify {} string key points, runs of three duplicates: {:?}",
+        size / 10,
+        start.elapsed() / iterations
+    );
+}
@@ -0,0 +1,52 @@
+use ordmask::OrdMask;
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let masks: Vec<OrdMask<u64>> = (0..100)
+        .map(|i| OrdMask::from((0..1000).map(|j| j * 1000 + i * 7).collect::<Vec<_>>()))
+        .collect();
+    let masks: Vec<_> = masks.iter().collect();
+    let iterations = 10;
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(OrdMask::union(&masks));
+    }
+    println!(
+        "union of {} masks through BTreeSet: {:?}",
+        masks.len(),
+        start.elapsed() / iterations
+    );
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(OrdMask::merge_with_capacity_growth(&masks, 1000));
+    }
+    println!(
+        "union of {} masks through k-way merge: {:?}",
+        masks.len(),
+        start.elapsed() / iterations
+    );
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let complements: Vec<_> = masks.iter().map(|mask| mask.new_complement()).collect();
+        black_box(OrdMask::union(&complements.iter().collect::<Vec<_>>()));
+    }
+    println!(
+        "union of {} complements: {:?}",
+        masks.len(),
+        start.elapsed() / iterations
+    );
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(OrdMask::union_of_complements(&masks));
+    }
+    println!(
+        "union of {} complements through De Morgan's law: {:?}",
+        masks.len(),
+        start.elapsed() / iterations
+    );
+}
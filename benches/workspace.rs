@@ -0,0 +1,45 @@
+use ordmask::{OrdMask, Workspace};
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let masks: Vec<OrdMask<u64>> = (0..100)
+        .map(|i| OrdMask::from((0..100).map(|j| j * 1000 + i * 3).collect::<Vec<_>>()))
+        .collect();
+    let iterations = 10_000;
+
+    let start = Instant::now();
+    for i in 0..iterations {
+        black_box(&masks[i % 100] | &masks[(i + 1) % 100]);
+    }
+    println!(
+        "{} unions without workspace: {:?}",
+        iterations,
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let mut ws = Workspace::new();
+    for i in 0..iterations {
+        black_box(OrdMask::union_with_workspace(
+            &masks[i % 100],
+            &masks[(i + 1) % 100],
+            &mut ws,
+        ));
+    }
+    println!(
+        "{} unions with workspace: {:?}",
+        iterations,
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    for i in 0..iterations {
+        black_box(OrdMask::union(&[&masks[i % 100], &masks[(i + 1) % 100]]));
+    }
+    println!(
+        "{} unions through BTreeSet: {:?}",
+        iterations,
+        start.elapsed()
+    );
+}
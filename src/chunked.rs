@@ -0,0 +1,324 @@
+//! A chunked on-disk format of `OrdMask<u64>` for querying a narrow window of a huge mask.
+//!
+//! Enabled by the `io` feature.
+//!
+//! The format is the key points as little-endian `u64` in chunks of `chunk_points`,
+//! followed by an index with the first key point of each chunk
+//! and whether the mask includes the values right before it,
+//! and a trailer with the offset of the index, the number of key points,
+//! the chunk size, whether the mask includes the minimum value and a magic number.
+//! A reader only loads the trailer and the index, and then seeks to the chunks it needs.
+//!
+//! With the `file-io` feature, `OrdMask::save_to_file` and `OrdMask::load_from_file`
+//! store a whole mask in a file of this format.
+
+use crate::OrdMask;
+use std::cell::RefCell;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::{Bound, RangeBounds};
+#[cfg(feature = "file-io")]
+use std::{fs::File, io::BufWriter, path::Path};
+
+const MAGIC: &[u8; 4] = b"OMCK";
+
+/// The size of the trailer: the index offset, the number of key points, the chunk size,
+/// whether the mask includes the minimum value and the magic number.
+const TRAILER_SIZE: u64 = 8 + 8 + 8 + 1 + 4;
+
+/// The size of an index entry: the first key point and the state before it.
+const INDEX_ENTRY_SIZE: u64 = 8 + 1;
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_bool(reader: &mut impl Read) -> io::Result<bool> {
+    let mut byte = [0; 1];
+    reader.read_exact(&mut byte)?;
+    match byte[0] {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(invalid_data("invalid boolean in a chunked mask")),
+    }
+}
+
+impl OrdMask<u64> {
+    /// Write the mask in the chunked format, with `chunk_points` key points per chunk.
+    ///
+    /// The mask is normalized and a key point at 0 is folded, so equal sets are written the same way.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if `chunk_points` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::chunked::ChunkedMaskReader;
+    /// use ordmask::ordmask;
+    /// use std::io::Cursor;
+    ///
+    /// let mask = ordmask![0u64, 10, 20, 30, 40];
+    /// let mut bytes = Vec::new();
+    /// mask.write_chunked(&mut bytes, 2).unwrap();
+    ///
+    /// let reader = ChunkedMaskReader::new(Cursor::new(bytes)).unwrap();
+    /// assert!(reader.included(&25).unwrap());
+    /// assert_eq!(reader.load_window(5..35).unwrap(), ordmask![5, 10, 20, 30]);
+    /// ```
+    pub fn write_chunked<W: Write>(&self, mut writer: W, chunk_points: usize) -> io::Result<()> {
+        assert!(chunk_points > 0, "the chunk size must be positive");
+        let mask = self.normalized_folded();
+        let key_points = mask.key_points();
+        for point in key_points {
+            writer.write_all(&point.to_le_bytes())?;
+        }
+        for (i, chunk) in key_points.chunks(chunk_points).enumerate() {
+            let before = mask.is_include_min_value() ^ (i * chunk_points % 2 == 1);
+            writer.write_all(&chunk[0].to_le_bytes())?;
+            writer.write_all(&[before as u8])?;
+        }
+        let index_offset = key_points.len() as u64 * 8;
+        writer.write_all(&index_offset.to_le_bytes())?;
+        writer.write_all(&(key_points.len() as u64).to_le_bytes())?;
+        writer.write_all(&(chunk_points as u64).to_le_bytes())?;
+        writer.write_all(&[mask.is_include_min_value() as u8])?;
+        writer.write_all(MAGIC)?;
+        writer.flush()
+    }
+}
+
+/// The number of key points per chunk of the files written by `OrdMask::save_to_file`.
+#[cfg(feature = "file-io")]
+pub const FILE_CHUNK_POINTS: usize = 1024;
+
+/// Add the stage and the path to the message of `error`, keeping its kind.
+#[cfg(feature = "file-io")]
+fn with_context(error: io::Error, stage: &str, path: &Path) -> io::Error {
+    io::Error::new(
+        error.kind(),
+        format!("failed to {stage} {}: {error}", path.display()),
+    )
+}
+
+#[cfg(feature = "file-io")]
+impl OrdMask<u64> {
+    /// Save the mask to the file at `path` in the chunked format,
+    /// with `FILE_CHUNK_POINTS` key points per chunk.
+    ///
+    /// The file is created, or truncated if it exists.
+    /// It's written in place without locking,
+    /// so a concurrent reader may see a partial file and fail to load it.
+    /// Write to a temporary file and rename it over `path` to replace a file atomically.
+    ///
+    /// The error keeps the kind of the underlying error,
+    /// and its message tells the path and whether creating or writing the file failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let path = std::env::temp_dir().join("ordmask-save-to-file-example.omck");
+    /// let mask = ordmask![_, 10u64, 20, 30];
+    /// mask.save_to_file(&path).unwrap();
+    /// assert_eq!(OrdMask::load_from_file(&path).unwrap(), mask);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn save_to_file(&self, path: &Path) -> io::Result<()> {
+        let file = File::create(path).map_err(|error| with_context(error, "create", path))?;
+        self.write_chunked(BufWriter::new(file), FILE_CHUNK_POINTS)
+            .map_err(|error| with_context(error, "write", path))
+    }
+
+    /// Load a mask from the file at `path`, written by `save_to_file` or `write_chunked`.
+    ///
+    /// The trailer, the index and the key points are all validated,
+    /// including that the index agrees with the key points and their parity,
+    /// so a file in another format, a truncated file or a corrupted index is an `InvalidData` error,
+    /// and the error message tells the path and whether opening, reading the trailer and index,
+    /// or reading the key points failed.
+    /// It may also be opened by `ChunkedMaskReader` to query it without loading it.
+    pub fn load_from_file(path: &Path) -> io::Result<Self> {
+        let file = File::open(path).map_err(|error| with_context(error, "open", path))?;
+        let reader = ChunkedMaskReader::new(io::BufReader::new(file))
+            .map_err(|error| with_context(error, "read the trailer and index of", path))?;
+        reader
+            .load_window(..)
+            .map_err(|error| with_context(error, "read the key points of", path))
+    }
+}
+
+/// A reader of a mask written by `OrdMask::write_chunked`, which only decodes the chunks it needs.
+///
+/// It keeps the index in memory, which takes 9 bytes per chunk.
+/// The queries take `&self`, so the underlying reader is kept in a `RefCell`.
+#[derive(Debug)]
+pub struct ChunkedMaskReader<R> {
+    reader: RefCell<R>,
+    len: usize,
+    chunk_points: usize,
+    reversed: bool,
+    /// The first key point of each chunk.
+    firsts: Vec<u64>,
+    /// Whether the mask includes the values right before the first key point of each chunk.
+    befores: Vec<bool>,
+}
+
+impl<R: Read + Seek> ChunkedMaskReader<R> {
+    /// Open a chunked mask by reading its trailer and index.
+    ///
+    /// It returns an `InvalidData` error if the data is not written by `OrdMask::write_chunked`.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let size = reader.seek(SeekFrom::End(0))?;
+        if size < TRAILER_SIZE {
+            return Err(invalid_data("too short for a chunked mask"));
+        }
+        reader.seek(SeekFrom::Start(size - TRAILER_SIZE))?;
+        let index_offset = read_u64(&mut reader)?;
+        let len = read_u64(&mut reader)?;
+        let chunk_points = read_u64(&mut reader)?;
+        let reversed = read_bool(&mut reader)?;
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC || chunk_points == 0 || len.checked_mul(8) != Some(index_offset) {
+            return Err(invalid_data("not a chunked mask"));
+        }
+        let chunks = len.div_ceil(chunk_points);
+        if chunks
+            .checked_mul(INDEX_ENTRY_SIZE)
+            .and_then(|index_size| index_size.checked_add(index_offset + TRAILER_SIZE))
+            != Some(size)
+        {
+            return Err(invalid_data(
+                "the size of a chunked mask doesn't match its trailer",
+            ));
+        }
+
+        reader.seek(SeekFrom::Start(index_offset))?;
+        let mut firsts = Vec::with_capacity(chunks as usize);
+        let mut befores = Vec::with_capacity(chunks as usize);
+        for i in 0..chunks {
+            let first = read_u64(&mut reader)?;
+            if firsts.last().is_some_and(|last| *last >= first) {
+                return Err(invalid_data(
+                    "the index of a chunked mask is not increasing",
+                ));
+            }
+            firsts.push(first);
+            // the state before a chunk is implied by the number of key points before it
+            if read_bool(&mut reader)? != reversed ^ (i * chunk_points % 2 == 1) {
+                return Err(invalid_data(
+                    "the index of a chunked mask doesn't match its parity",
+                ));
+            }
+            befores.push(reversed ^ (i * chunk_points % 2 == 1));
+        }
+        Ok(Self {
+            reader: RefCell::new(reader),
+            len: len as usize,
+            chunk_points: chunk_points as usize,
+            reversed,
+            firsts,
+            befores,
+        })
+    }
+
+    /// Get the number of key points of the mask.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the mask has no key points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Read the key points of the chunks in `chunks`,
+    /// and check that they agree with the index.
+    fn read_chunks(&self, chunks: std::ops::Range<usize>) -> io::Result<Vec<u64>> {
+        let start = chunks.start * self.chunk_points;
+        let end = (chunks.end * self.chunk_points).min(self.len);
+        if start >= end {
+            return Ok(Vec::new());
+        }
+        let mut bytes = vec![0; (end - start) * 8];
+        let mut reader = self.reader.borrow_mut();
+        reader.seek(SeekFrom::Start(start as u64 * 8))?;
+        reader.read_exact(&mut bytes)?;
+        let points: Vec<u64> = bytes
+            .chunks_exact(8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+        if points.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(invalid_data(
+                "the key points of a chunked mask are not increasing",
+            ));
+        }
+        let firsts = points.iter().step_by(self.chunk_points);
+        let next_first = self.firsts.get(chunks.end);
+        if !firsts.eq(&self.firsts[chunks.start..chunks.end.min(self.firsts.len())])
+            || next_first.is_some_and(|next| points.last() >= Some(next))
+        {
+            return Err(invalid_data(
+                "the index of a chunked mask doesn't match its key points",
+            ));
+        }
+        Ok(points)
+    }
+
+    /// Get whether the mask includes the values right before the first key point of `chunk`.
+    fn before(&self, chunk: usize) -> bool {
+        self.befores.get(chunk).copied().unwrap_or(self.reversed)
+    }
+
+    /// Check if `value` is included, by reading only the chunk containing it.
+    pub fn included(&self, value: &u64) -> io::Result<bool> {
+        let chunk = self.firsts.partition_point(|x| x <= value);
+        if chunk == 0 {
+            return Ok(self.reversed);
+        }
+        let points = self.read_chunks(chunk - 1..chunk)?;
+        let crossed = points.partition_point(|x| x <= value);
+        Ok(self.before(chunk - 1) ^ (crossed % 2 == 1))
+    }
+
+    /// Load the mask restricted to `range`, by reading only the chunks overlapping it.
+    ///
+    /// An excluded start or an included end is converted to the half-open convention
+    /// by adding one to it, so `5..=u64::MAX` loads the values from 5 to the maximum value.
+    pub fn load_window(&self, range: impl RangeBounds<u64>) -> io::Result<OrdMask<u64>> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => Some(*start),
+            Bound::Unbounded => None,
+            Bound::Excluded(start) => match start.checked_add(1) {
+                Some(start) => Some(start),
+                None => return Ok(OrdMask::empty()),
+            },
+        };
+        let end = match range.end_bound() {
+            Bound::Excluded(end) => Some(*end),
+            Bound::Unbounded => None,
+            Bound::Included(end) => end.checked_add(1),
+        };
+        let first_chunk = start.map_or(0, |start| {
+            self.firsts
+                .partition_point(|x| *x <= start)
+                .saturating_sub(1)
+        });
+        let end_chunk = end.map_or(self.firsts.len(), |end| {
+            self.firsts.partition_point(|x| *x < end)
+        });
+        let points = self.read_chunks(first_chunk..end_chunk.max(first_chunk))?;
+        // the loaded points agree with the whole mask from the first loaded chunk to the end chunk
+        let reversed = self.before(first_chunk);
+        let mask = OrdMask::from_parts_unchecked(points, reversed);
+        Ok(mask.restrict(start.as_ref(), end.as_ref()))
+    }
+}
@@ -0,0 +1,60 @@
+//! A comparison counter for testing the complexity contracts of the mask operations.
+//!
+//! Enabled by the `contracts` feature.
+//!
+//! All comparisons of the key points go through `Ord` and `PartialEq`,
+//! so wrapping the keys in [`Counted`] counts every comparison made by an operation,
+//! which catches accidental quadratic regressions at test time.
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A key counting its comparisons in a thread-local counter.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::contracts::{count_comparisons, Counted};
+/// use ordmask::OrdMask;
+///
+/// let mask = OrdMask::from((0..1024).map(Counted).collect::<Vec<_>>());
+/// let (included, comparisons) = count_comparisons(|| mask.included(&Counted(500)));
+/// assert!(included);
+/// assert!(comparisons <= 11);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Counted<T>(pub T);
+
+impl<T: PartialEq> PartialEq for Counted<T> {
+    fn eq(&self, other: &Self) -> bool {
+        COMPARISONS.with(|count| count.set(count.get() + 1));
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq> Eq for Counted<T> {}
+
+impl<T: Ord> PartialOrd for Counted<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Counted<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        COMPARISONS.with(|count| count.set(count.get() + 1));
+        self.0.cmp(&other.0)
+    }
+}
+
+/// Run `f`, and get its result and the number of comparisons of [`Counted`] keys it made on this thread.
+pub fn count_comparisons<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = COMPARISONS.with(Cell::get);
+    let result = f();
+    let after = COMPARISONS.with(Cell::get);
+    (result, after - before)
+}
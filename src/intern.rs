@@ -0,0 +1,82 @@
+//! An interning pool sharing identical masks.
+//!
+//! Enabled by the `intern` feature.
+
+use crate::OrdMask;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+/// A thread-safe pool returning the same `Arc` for equivalent masks.
+///
+/// The masks are simplified before interning, so equivalent masks built by different methods share one allocation.
+/// Masks of bounded types should be folded by `fold_min_value` before interning,
+/// since a key point at the minimum value is another representation of including the minimum value.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::intern::MaskPool;
+/// use ordmask::{OrdMask, ordmask};
+/// use std::sync::Arc;
+///
+/// let pool = MaskPool::new();
+/// let a = pool.intern(ordmask![0, 10]);
+/// let b = pool.intern(OrdMask::in_range(0, 5) | OrdMask::in_range(5, 10));
+/// assert!(Arc::ptr_eq(&a, &b));
+/// assert!(a.included(&5));
+/// assert_eq!(pool.len(), 1);
+///
+/// drop((a, b));
+/// pool.gc();
+/// assert!(pool.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct MaskPool<T: Ord + Clone> {
+    masks: Mutex<HashSet<Arc<OrdMask<T>>>>,
+}
+
+impl<T: Ord + Clone + Hash> MaskPool<T> {
+    /// Create an empty pool.
+    pub fn new() -> Self {
+        Self {
+            masks: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Get the shared mask equivalent to `mask`, adding it to the pool if there is none.
+    pub fn intern(&self, mut mask: OrdMask<T>) -> Arc<OrdMask<T>> {
+        mask.simplify();
+        let mut masks = self.masks.lock().unwrap();
+        if let Some(shared) = masks.get(&mask) {
+            return shared.clone();
+        }
+        let shared = Arc::new(mask);
+        masks.insert(shared.clone());
+        shared
+    }
+
+    /// Get the number of masks in the pool.
+    pub fn len(&self) -> usize {
+        self.masks.lock().unwrap().len()
+    }
+
+    /// Check if the pool has no masks.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop the masks which are not referenced outside the pool.
+    pub fn gc(&self) {
+        self.masks
+            .lock()
+            .unwrap()
+            .retain(|shared| Arc::strong_count(shared) > 1);
+    }
+}
+
+impl<T: Ord + Clone + Hash> Default for MaskPool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
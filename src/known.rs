@@ -0,0 +1,87 @@
+//! Well-known masks of common integer domains.
+//!
+//! Enabled by the `known` feature.
+
+use crate::OrdMask;
+
+/// The valid TCP and UDP port numbers, `1..=65535`.
+///
+/// Port 0 is reserved, and binding to it asks the system for any free port,
+/// so it's not a valid port to connect to and is excluded.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::known::ports;
+///
+/// let mask = ports();
+/// assert!(mask.excluded(&0));
+/// assert!(mask.included(&1));
+/// assert!(mask.included(&443));
+/// assert!(mask.included(&65535));
+/// ```
+pub fn ports() -> OrdMask<u16> {
+    OrdMask::not_less_than(1)
+}
+
+/// The unprivileged port numbers, `1024..=65535`.
+///
+/// The ports below 1024 need privileges to bind on most Unix-like systems.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::known::unprivileged_ports;
+///
+/// let mask = unprivileged_ports();
+/// assert!(mask.excluded(&0));
+/// assert!(mask.excluded(&1023));
+/// assert!(mask.included(&1024));
+/// assert!(mask.included(&65535));
+/// ```
+pub fn unprivileged_ports() -> OrdMask<u16> {
+    OrdMask::not_less_than(1024)
+}
+
+/// The printable ASCII bytes, `0x20..=0x7E`.
+///
+/// The space `0x20` is included.
+/// The control characters below `0x20` and the delete `0x7F` are excluded, like the bytes above ASCII.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::known::ascii_printable;
+///
+/// let mask = ascii_printable();
+/// assert!(mask.excluded(&b'\n'));
+/// assert!(mask.included(&b' '));
+/// assert!(mask.included(&b'~'));
+/// assert!(mask.excluded(&0x7F));
+/// assert!(mask.excluded(&0x80));
+/// ```
+pub fn ascii_printable() -> OrdMask<u8> {
+    OrdMask::in_range(0x20, 0x7F)
+}
+
+/// The Unicode scalar values, `0..=0x10FFFF` except the surrogates `0xD800..=0xDFFF`.
+///
+/// They are the values of `char`, so a value is included if and only if `char::from_u32` accepts it.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::known::unicode_scalar_values;
+///
+/// let mask = unicode_scalar_values();
+/// assert!(mask.included(&0));
+/// assert!(mask.included(&0xD7FF));
+/// assert!(mask.excluded(&0xD800));
+/// assert!(mask.excluded(&0xDFFF));
+/// assert!(mask.included(&0xE000));
+/// assert!(mask.included(&0x10FFFF));
+/// assert!(mask.excluded(&0x110000));
+/// ```
+pub fn unicode_scalar_values() -> OrdMask<u32> {
+    OrdMask::from_complement(vec![0xD800, 0xE000, 0x110000])
+}
@@ -2,3 +2,26 @@
 
 mod ordmask;
 pub use ordmask::*;
+
+pub mod testing;
+
+#[cfg(feature = "contracts")]
+pub mod contracts;
+
+#[cfg(feature = "intern")]
+pub mod intern;
+
+#[cfg(feature = "io")]
+pub mod chunked;
+
+#[cfg(feature = "known")]
+pub mod known;
+
+#[cfg(feature = "packed")]
+pub mod packed;
+
+#[cfg(feature = "shared")]
+pub mod shared;
+
+#[cfg(feature = "validate")]
+pub mod validate;
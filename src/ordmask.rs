@@ -1,7 +1,62 @@
+mod approx;
+mod builder;
+pub use builder::MaskBuilder;
+mod canonical;
+pub use canonical::CanonicalOrd;
+mod capacity;
+mod chunks;
+mod columns;
+pub use columns::StartsEndsError;
+mod consistency;
+pub use consistency::ConsistencyError;
 mod construct;
+pub use construct::TransitionError;
 mod convert;
+pub use convert::{ConvertError, Error};
+mod count;
+pub use count::MultipleBoundaries;
+mod cursor;
+pub use cursor::MaskCursor;
+mod diff;
+pub use diff::{DiffStream, MaskDiff};
+mod discrete;
+pub use discrete::{MaxValue, MinValue, Predecessor, Successor};
+mod display;
+mod edit;
+pub use edit::EditError;
+mod inclusive;
+mod index;
+mod integers;
+pub use integers::CoveredIntegers;
+mod inversion;
+pub use inversion::InversionListError;
+mod lookup;
+mod measure;
+mod multi_index;
+pub use multi_index::MultiMaskIndex;
 mod operations;
 mod ops;
+mod order;
+mod parse;
+pub use parse::ParseError;
+mod points;
+mod random;
+mod ranges;
+pub use ranges::Ranges;
+mod referenced;
+pub use referenced::{MaskLike, ReferencedMask};
+mod reflect;
+mod relation;
+mod repair;
+pub use repair::{RepairPolicy, RepairReport};
+mod runs;
+mod scan;
+mod shift;
+mod slivers;
+mod window;
+pub use window::RangeBoundError;
+mod workspace;
+pub use workspace::Workspace;
 
 /// An `OrdMask` can be used to check if a value is included.
 ///
@@ -47,7 +102,31 @@ mod ops;
 /// (10..20).for_each(|x| should_include(&mask, x));
 /// (20..30).for_each(|x| should_exclude(&mask, x));
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// # Degenerate key types
+///
+/// A type with a single value, like `()`, has only two sets: the empty one and the universal one.
+/// A key point at the single value (or at the minimum value of any type) has no value below it,
+/// so `ordmask![()]` includes the same values as `ordmask![_]` but is not equal to it.
+/// Use `fold_min_value` before comparing masks by `==` or `is_subset_of`.
+///
+/// ```
+/// use ordmask::{OrdMask, ordmask};
+///
+/// let all: OrdMask<()> = OrdMask::universal();
+/// let none: OrdMask<()> = OrdMask::empty();
+/// assert!(OrdMask::not_less_than(()).included(&()));
+/// assert!(OrdMask::less_than(()).excluded(&()));
+/// assert_eq!(OrdMask::in_range((), ()), none);
+/// assert_eq!(OrdMask::exclude_range((), ()), all);
+/// assert_eq!(OrdMask::not_less_than(()).to_inclusive_ranges(), all.to_inclusive_ranges());
+/// assert_ne!(OrdMask::not_less_than(()), all);
+///
+/// let mut mask = OrdMask::not_less_than(());
+/// mask.fold_min_value();
+/// assert_eq!(mask, all);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OrdMask<T: Ord + Clone> {
     key_points: Vec<T>,
     reversed: bool,
@@ -68,13 +147,50 @@ impl<T: Ord + Clone> OrdMask<T> {
         self.key_points.is_empty() && self.reversed
     }
 
-    /// Check if the `OrdMask` is valid.
+    /// Check if the `OrdMask` is valid, which means the key points are non-decreasing.
     ///
-    /// There is no need to test `is_valid()` if you never use unsafe methods.
+    /// It's the same as `is_non_decreasing()`.
+    /// There is no need to test `is_valid()` if you never use `with_unchecked` or `from_parts_unchecked`.
     pub fn is_valid(&self) -> bool {
+        self.is_non_decreasing()
+    }
+
+    /// Check if the key points are non-decreasing, which is required by `with_unchecked`.
+    ///
+    /// A non-decreasing mask may have duplicate key points, which are removed by `simplify()`.
+    pub fn is_non_decreasing(&self) -> bool {
         convert::get_first_falling_index(&self.key_points) == 0
     }
 
+    /// Check if the key points are strictly increasing, which is the canonical form of a mask.
+    ///
+    /// All checked constructors and operations produce strictly increasing key points,
+    /// while `with_unchecked` only requires them to be non-decreasing,
+    /// `from_parts_unchecked` doesn't check them at all,
+    /// and `close_tail_at_max` adds duplicate key points at the maximum value on purpose.
+    /// It's the same as `is_non_decreasing() && is_simplified()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert!(ordmask![0, 1, 2].is_strictly_sorted());
+    /// let mask = unsafe { OrdMask::with_unchecked(vec![0, 1, 1, 2], false) };
+    /// assert!(mask.is_non_decreasing());
+    /// assert!(!mask.is_strictly_sorted());
+    /// ```
+    pub fn is_strictly_sorted(&self) -> bool {
+        self.key_points.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    /// Check if the key points are strictly increasing.
+    ///
+    /// It's the same as `is_strictly_sorted()`.
+    pub fn key_points_are_strictly_increasing(&self) -> bool {
+        self.is_strictly_sorted()
+    }
+
     /// Check if a value is included in this mask.
     pub fn included(&self, value: &T) -> bool {
         self.reversed ^ (self.key_points.partition_point(|x| x <= value) % 2 == 1)
@@ -99,7 +215,7 @@ impl<T: Ord + Clone> OrdMask<T> {
     ///
     /// An simplified `OrdMask` means there are no duplicate values.
     ///
-    /// There is no need to test `is_simplified()` if you never use unsafe methods,  
+    /// There is no need to test `is_simplified()` if you never use `with_unchecked` or `from_parts_unchecked`,  
     /// otherwise you should directly use `simplify()` without checking `is_simplified()`.
     pub fn is_simplified(&self) -> bool {
         for i in 1..self.key_points.len() {
@@ -112,8 +228,8 @@ impl<T: Ord + Clone> OrdMask<T> {
 
     /// Remove meaningless values in the `OrdMask`.
     ///
-    /// The safe methods will ensure the `OrdMask` is simplified automatically.  
-    /// Therefore, there is no need to call it unless you are using unsafe methods.
+    /// The other methods will ensure the `OrdMask` is simplified automatically.  
+    /// Therefore, there is no need to call it unless you are using `with_unchecked` or `from_parts_unchecked`.
     ///
     /// # Examples
     /// ```
@@ -135,6 +251,9 @@ impl<T: Ord + Clone> OrdMask<T> {
             return;
         }
 
+        // like `Vec::dedup`, runs of equal key points are read ahead of a write index,
+        // but a run toggles the state once per key point, so it's kept only if its length is odd.
+        // The write index is never ahead of the read index, so it takes O(n) without allocating.
         let mut write_index = 0;
         let mut read_index = 0;
         while read_index < len {
@@ -151,10 +270,167 @@ impl<T: Ord + Clone> OrdMask<T> {
         self.key_points.truncate(write_index);
     }
 
+    /// Convert the mask to the canonical representation, with strictly increasing key points.
+    ///
+    /// The canonical representation includes the minimum value if and only if
+    /// the values below every key point are included,
+    /// so the empty and universal masks are the only ones without key points.
+    /// It's unique for each set of values of an unbounded type like `i32` or `String`.
+    /// For a type with a minimum value, a key point at `T::MIN_VALUE` is another representation
+    /// of including the minimum value, like `ordmask![0u32, 10]` and `ordmask![_, 10u32]`,
+    /// so `fold_min_value` must be called too to get a unique representation.
+    /// A tail closed by `close_tail_at_max` is opened again by normalizing, like `open_tail_from_max`.
+    ///
+    /// The checked methods always produce strictly increasing key points except `close_tail_at_max`,
+    /// and the serializations normalize the masks built by `with_unchecked` or `from_parts_unchecked`
+    /// or closed by `close_tail_at_max`.
+    /// `to_str_key_points` and the `(Vec<T>, bool)` conversion are generic, so they don't fold the minimum value,
+    /// while `PackedMask::from_mask` and `OrdMask::write_chunked` do,
+    /// so they serialize equal sets the same way. It takes O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mut mask = unsafe { OrdMask::with_unchecked(vec![0, 0, 5, 10, 10, 10], true) };
+    /// assert!(!mask.is_normalized());
+    /// mask.normalize();
+    /// assert!(mask.is_normalized());
+    /// assert_eq!(mask, ordmask![_, 5, 10]);
+    /// ```
+    pub fn normalize(&mut self) {
+        self.simplify();
+    }
+
+    /// Check if the mask is in the canonical representation described in `normalize`.
+    pub fn is_normalized(&self) -> bool {
+        self.is_strictly_sorted()
+    }
+
+    /// Get the mask in the canonical representation, cloning it only if it's not normalized.
+    pub(crate) fn normalized(&self) -> std::borrow::Cow<'_, Self> {
+        match self.is_normalized() {
+            true => std::borrow::Cow::Borrowed(self),
+            false => {
+                let mut mask = self.clone();
+                mask.normalize();
+                std::borrow::Cow::Owned(mask)
+            }
+        }
+    }
+
     pub fn key_points(&self) -> &Vec<T> {
         &self.key_points
     }
 
+    /// Check if `value` is one of the key points, which are the boundaries of the ranges.
+    ///
+    /// It's unrelated to whether `value` is included, and takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10];
+    /// assert!(mask.contains_key_point(&0) && mask.included(&0));
+    /// assert!(mask.contains_key_point(&10) && mask.excluded(&10));
+    /// assert!(!mask.contains_key_point(&5));
+    /// ```
+    pub fn contains_key_point(&self, value: &T) -> bool {
+        self.key_points.binary_search(value).is_ok()
+    }
+
+    /// Remove the key point at `value`, and return whether it was found.
+    ///
+    /// The region before the key point is extended to the next key point,
+    /// so the inclusion of all values from `value` onwards is flipped,
+    /// including whether the mask includes the maximum value.
+    /// The mask remains valid. It takes O(log n) to find the key point, and O(n) to remove it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![0, 10, 20, 30];
+    /// assert!(mask.remove_key_point(&10));
+    /// assert_eq!(mask, ordmask![0, 20, 30]);
+    /// assert!(!mask.remove_key_point(&10));
+    /// assert!(mask.remove_key_point(&0));
+    /// assert_eq!(mask, ordmask![20, 30]);
+    /// ```
+    pub fn remove_key_point(&mut self, value: &T) -> bool {
+        match self.key_points.binary_search(value) {
+            Ok(index) => {
+                self.key_points.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Append a key point after the last one, which flips the inclusion of the values from `value` onwards.
+    ///
+    /// It returns `Error::Falling` or `Error::DuplicateKeyPoint` with the index `value` would have,
+    /// if `value` is not greater than the last key point.
+    /// It takes O(1) amortized, so it's the way to build a mask incrementally in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    ///
+    /// let mut mask = OrdMask::empty();
+    /// mask.push_key_point_at_end(0)?.push_key_point_at_end(10)?.push_key_point_at_end(20)?;
+    /// assert_eq!(mask, ordmask![0, 10, 20]);
+    /// assert_eq!(mask.push_key_point_at_end(15).unwrap_err(), Error::Falling { index: 3 });
+    /// assert_eq!(mask.push_key_point_at_end(20).unwrap_err(), Error::DuplicateKeyPoint { index: 3 });
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn push_key_point_at_end(&mut self, value: T) -> Result<&mut Self, Error> {
+        let index = self.key_points.len();
+        match self.key_points.last().map(|last| value.cmp(last)) {
+            Some(std::cmp::Ordering::Less) => Err(Error::Falling { index }),
+            Some(std::cmp::Ordering::Equal) => Err(Error::DuplicateKeyPoint { index }),
+            _ => {
+                self.key_points.push(value);
+                Ok(self)
+            }
+        }
+    }
+
+    /// Prepend a key point before the first one, which flips the inclusion of the values before `value`.
+    ///
+    /// It's the mirror of `push_key_point_at_end` for building a mask in descending order,
+    /// which returns `Error::Falling` or `Error::DuplicateKeyPoint` at index 1,
+    /// if `value` is not less than the first key point.
+    /// It takes O(n) because the key points are shifted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    ///
+    /// let mut mask = OrdMask::empty();
+    /// mask.push_key_point_at_start(20)?.push_key_point_at_start(10)?.push_key_point_at_start(0)?;
+    /// assert_eq!(mask, ordmask![_, 0, 10, 20]);
+    /// assert_eq!(mask.push_key_point_at_start(5).unwrap_err(), Error::Falling { index: 1 });
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn push_key_point_at_start(&mut self, value: T) -> Result<&mut Self, Error> {
+        match self.key_points.first().map(|first| first.cmp(&value)) {
+            Some(std::cmp::Ordering::Less) => Err(Error::Falling { index: 1 }),
+            Some(std::cmp::Ordering::Equal) => Err(Error::DuplicateKeyPoint { index: 1 }),
+            _ => {
+                self.key_points.insert(0, value);
+                self.reversed = !self.reversed;
+                Ok(self)
+            }
+        }
+    }
+
     /// Get the key points of `masks`.
     pub fn get_key_points_set(masks: &[&OrdMask<T>]) -> std::collections::BTreeSet<T> {
         let mut result = std::collections::BTreeSet::new();
@@ -0,0 +1,35 @@
+use super::OrdMask;
+use std::ops::Sub;
+
+impl<T: Ord + Clone + Sub<Output = T>> OrdMask<T> {
+    /// Check if `other` is approximately the complement of `self`.
+    ///
+    /// It is true if the masks have the same number of key points,
+    /// the corresponding key points differ by no more than `tolerance`,
+    /// and exactly one of them includes the minimum value.
+    ///
+    /// It is designed for masks over floating-point keys (wrapped in a type implementing `Ord`),
+    /// where the exact complement can't be guaranteed due to rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 100];
+    /// assert!(mask.is_approximately_complement_of(&ordmask![_, 1, 99], 1));
+    /// assert!(!mask.is_approximately_complement_of(&ordmask![_, 2, 99], 1));
+    /// assert!(!mask.is_approximately_complement_of(&ordmask![0, 100], 1));
+    /// ```
+    pub fn is_approximately_complement_of(&self, other: &Self, tolerance: T) -> bool {
+        self.reversed != other.reversed
+            && self.key_points.len() == other.key_points.len()
+            && self.key_points.iter().zip(&other.key_points).all(|(a, b)| {
+                let diff = match a >= b {
+                    true => a.clone() - b.clone(),
+                    false => b.clone() - a.clone(),
+                };
+                diff <= tolerance
+            })
+    }
+}
@@ -0,0 +1,152 @@
+use super::window::{half_open_bounds, RangeBoundError};
+use super::OrdMask;
+use std::collections::BTreeSet;
+use std::ops::RangeBounds;
+
+/// A builder composing an `OrdMask` from layered rules in one expression.
+///
+/// It is created by [`OrdMask::builder`].
+/// Each rule includes or excludes a range, and later rules override earlier ones in their range.
+/// The values not covered by any rule are excluded.
+/// A rule whose range has an excluded start or an included end makes `build` return an error.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{OrdMask, ordmask};
+///
+/// let mask = OrdMask::builder().include(0..10).exclude(3..5).include_from(100).build();
+/// assert_eq!(mask, Ok(ordmask![0, 3, 5, 10, 100]));
+///
+/// // The order matters: the later rule wins where they overlap
+/// let include_then_exclude = OrdMask::builder().include(0..10).exclude(5..15).build();
+/// let exclude_then_include = OrdMask::builder().exclude(5..15).include(0..10).build();
+/// assert_eq!(include_then_exclude, Ok(ordmask![0, 5]));
+/// assert_eq!(exclude_then_include, Ok(ordmask![0, 10]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaskBuilder<T> {
+    rules: Vec<(Option<T>, Option<T>, bool)>,
+    error: Option<RangeBoundError>,
+}
+
+impl<T: Ord + Clone> MaskBuilder<T> {
+    fn rule(mut self, range: impl RangeBounds<T>, included: bool) -> Self {
+        match half_open_bounds(&range) {
+            Ok((start, end)) => self.rules.push((start.cloned(), end.cloned(), included)),
+            Err(error) => {
+                self.error.get_or_insert(error);
+            }
+        }
+        self
+    }
+
+    /// Include the values in `range`, overriding the earlier rules.
+    ///
+    /// If the start of `range` is excluded or the end of `range` is included,
+    /// `build` returns an error.
+    pub fn include(self, range: impl RangeBounds<T>) -> Self {
+        self.rule(range, true)
+    }
+
+    /// Exclude the values in `range`, overriding the earlier rules.
+    ///
+    /// If the start of `range` is excluded or the end of `range` is included,
+    /// `build` returns an error.
+    pub fn exclude(self, range: impl RangeBounds<T>) -> Self {
+        self.rule(range, false)
+    }
+
+    /// Include the values greater than or equal to `start`, overriding the earlier rules.
+    pub fn include_from(self, start: T) -> Self {
+        self.include(start..)
+    }
+
+    /// Exclude the values greater than or equal to `start`, overriding the earlier rules.
+    pub fn exclude_from(self, start: T) -> Self {
+        self.exclude(start..)
+    }
+
+    /// Include all values, overriding the earlier rules.
+    pub fn include_all(self) -> Self {
+        self.include(..)
+    }
+
+    /// Exclude all values, overriding the earlier rules.
+    pub fn exclude_all(self) -> Self {
+        self.exclude(..)
+    }
+
+    /// Build the mask by a single sweep over the boundaries of the rules.
+    ///
+    /// It takes O(k log k) where k is the number of rules.
+    ///
+    /// # Errors
+    ///
+    /// It returns the error of the first rule whose range has an excluded start or an included end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, RangeBoundError};
+    ///
+    /// let result = OrdMask::builder().include(0..10).exclude(3..=5).build();
+    /// assert_eq!(result, Err(RangeBoundError::IncludedEnd));
+    /// ```
+    pub fn build(self) -> Result<OrdMask<T>, RangeBoundError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let mut active = BTreeSet::new();
+        let mut events = Vec::with_capacity(self.rules.len() * 2);
+        for (i, (start, end, _)) in self.rules.iter().enumerate() {
+            if let (Some(start), Some(end)) = (start, end) {
+                if start >= end {
+                    continue;
+                }
+            }
+            match start {
+                Some(start) => events.push((start, i, true)),
+                None => {
+                    active.insert(i);
+                }
+            }
+            if let Some(end) = end {
+                events.push((end, i, false));
+            }
+        }
+        events.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        let state = |active: &BTreeSet<usize>| active.last().is_some_and(|i| self.rules[*i].2);
+        let reversed = state(&active);
+        let mut included = reversed;
+        let mut key_points = Vec::new();
+        let mut events = events.into_iter().peekable();
+        while let Some(&(point, _, _)) = events.peek() {
+            while let Some((_, i, is_start)) = events.next_if(|(next, _, _)| *next == point) {
+                match is_start {
+                    true => active.insert(i),
+                    false => active.remove(&i),
+                };
+            }
+            if state(&active) != included {
+                included = !included;
+                key_points.push(point.clone());
+            }
+        }
+        Ok(OrdMask {
+            key_points,
+            reversed,
+        })
+    }
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Create a [`MaskBuilder`] without any rules, which builds an empty mask.
+    pub fn builder() -> MaskBuilder<T> {
+        MaskBuilder {
+            rules: Vec::new(),
+            error: None,
+        }
+    }
+}
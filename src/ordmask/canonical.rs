@@ -0,0 +1,77 @@
+use super::OrdMask;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A mask with a total order, which can be used as the key of a `BTreeMap` or a `HashMap`.
+///
+/// The order is lexicographic over `(is_include_min_value(), key_points())`,
+/// which is NOT the subset order. It's only meaningful for keeping masks sorted and deduplicated.
+///
+/// The mask is simplified when wrapped, so equivalent masks built by different methods are equal.
+/// Masks of bounded types should be folded by `fold_min_value` before wrapping,
+/// since a key point at the minimum value is another representation of including the minimum value.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{CanonicalOrd, OrdMask, ordmask};
+/// use std::collections::BTreeMap;
+///
+/// let mut policies = BTreeMap::new();
+/// policies.insert(CanonicalOrd::new(ordmask![0, 10]), "a");
+/// policies.insert(CanonicalOrd::new(OrdMask::in_range(0, 5) | OrdMask::in_range(5, 10)), "b");
+/// assert_eq!(policies.len(), 1);
+/// assert_eq!(policies[&CanonicalOrd::new(ordmask![0, 10])], "b");
+///
+/// // not the subset order
+/// assert!(CanonicalOrd::new(ordmask![0, 10]) < CanonicalOrd::new(ordmask![5, 6]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CanonicalOrd<T: Ord + Clone>(OrdMask<T>);
+
+impl<T: Ord + Clone> CanonicalOrd<T> {
+    /// Wrap the mask after simplifying it.
+    pub fn new(mut mask: OrdMask<T>) -> Self {
+        mask.simplify();
+        Self(mask)
+    }
+
+    /// Get the wrapped mask.
+    pub fn into_inner(self) -> OrdMask<T> {
+        self.0
+    }
+}
+
+impl<T: Ord + Clone> From<OrdMask<T>> for CanonicalOrd<T> {
+    fn from(mask: OrdMask<T>) -> Self {
+        Self::new(mask)
+    }
+}
+
+impl<T: Ord + Clone> Deref for CanonicalOrd<T> {
+    type Target = OrdMask<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Ord + Clone> Ord for CanonicalOrd<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0.reversed, &self.0.key_points).cmp(&(other.0.reversed, &other.0.key_points))
+    }
+}
+
+impl<T: Ord + Clone> PartialOrd for CanonicalOrd<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord + Clone + Hash> Hash for CanonicalOrd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.reversed.hash(state);
+        self.0.key_points.hash(state);
+    }
+}
@@ -0,0 +1,83 @@
+use super::OrdMask;
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Get the number of key points the mask can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let union = OrdMask::union_with_capacity(&ordmask![0, 10], &ordmask![20, 30], Some(16));
+    /// assert!(union.capacity() >= 16);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.key_points.capacity()
+    }
+
+    fn merge_with_capacity(
+        a: &Self,
+        b: &Self,
+        op: impl Fn(bool, bool) -> bool,
+        capacity: Option<usize>,
+    ) -> Self {
+        let mut key_points = Vec::with_capacity(
+            capacity
+                .unwrap_or(0)
+                .max(a.key_points.len() + b.key_points.len()),
+        );
+        let reversed = Self::merge_into(a, b, op, &mut key_points);
+        Self {
+            key_points,
+            reversed,
+        }
+    }
+
+    /// Create a new OrdMask representing the union of `a` and `b`,
+    /// reserving at least `capacity` key points for the result.
+    ///
+    /// The result is the same as `a | b`.
+    /// Every key point of the result is a key point of `a` or `b`,
+    /// so at least `n + m` key points are reserved anyway, where `n` and `m` are the lengths of `a` and `b`.
+    /// The result is filled without reallocating and is never shrunk,
+    /// so pass a larger `capacity` if you plan to edit the result in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let union = OrdMask::union_with_capacity(&ordmask![0, 10], &ordmask![5, 20], None);
+    /// assert_eq!(union, ordmask![0, 20]);
+    /// assert!(union.capacity() >= 4);
+    /// ```
+    pub fn union_with_capacity(a: &Self, b: &Self, capacity: Option<usize>) -> Self {
+        Self::merge_with_capacity(a, b, |a, b| a || b, capacity)
+    }
+
+    /// Create a new OrdMask representing the intersection of `a` and `b`,
+    /// reserving at least `capacity` key points for the result.
+    ///
+    /// The result is the same as `a & b`. See `union_with_capacity` for the reserved capacity.
+    /// Note that the intersection may have more than `min(n, m)` key points,
+    /// e.g. the intersection of `[0, 2) ∪ [4, 6) ∪ [8, 10)` and `[1, 9)` has 6.
+    pub fn intersection_with_capacity(a: &Self, b: &Self, capacity: Option<usize>) -> Self {
+        Self::merge_with_capacity(a, b, |a, b| a && b, capacity)
+    }
+
+    /// Create a new OrdMask representing the difference of `a` and `b`,
+    /// reserving at least `capacity` key points for the result.
+    ///
+    /// The result is the same as `a - b`. See `union_with_capacity` for the reserved capacity.
+    pub fn minus_with_capacity(a: &Self, b: &Self, capacity: Option<usize>) -> Self {
+        Self::merge_with_capacity(a, b, |a, b| a && !b, capacity)
+    }
+
+    /// Create a new OrdMask representing the symmetric difference of `a` and `b`,
+    /// reserving at least `capacity` key points for the result.
+    ///
+    /// The result is the same as `a ^ b`. See `union_with_capacity` for the reserved capacity.
+    pub fn symmetric_difference_with_capacity(a: &Self, b: &Self, capacity: Option<usize>) -> Self {
+        Self::merge_with_capacity(a, b, |a, b| a != b, capacity)
+    }
+}
@@ -0,0 +1,92 @@
+use super::OrdMask;
+use std::ops::{Add, Sub};
+
+impl<T: Ord + Clone + Add<Output = T> + Sub<Output = T>> OrdMask<T> {
+    /// Split the mask into chunks of `chunk_size` for independent processing.
+    ///
+    /// The implied domain `[first_key_point, last_key_point)` is divided into windows
+    /// of `chunk_size`, and each chunk is the mask restricted to its window,
+    /// so the result has `ceil((last_key_point - first_key_point) / chunk_size)` chunks.
+    /// The first chunk also keeps everything below the domain,
+    /// and the last chunk also keeps everything above it.
+    /// A window is only computed if it ends before the last key point, so it never overflows.
+    ///
+    /// A mask with less than 2 key points is returned as a single chunk.
+    ///
+    /// Use `merge_chunks` to merge the chunks back.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if `chunk_size` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask = ordmask![_, 0, 15, 25];
+    /// let chunks = mask.split_into_chunks(10);
+    /// assert_eq!(chunks, vec![ordmask![_, 0], ordmask![15, 20], ordmask![20, 25]]);
+    /// assert_eq!(OrdMask::merge_chunks(chunks, 10), mask);
+    ///
+    /// let chunks = ordmask![0u8, 255].split_into_chunks(200);
+    /// assert_eq!(chunks, vec![ordmask![0, 200], ordmask![200, 255]]);
+    /// ```
+    pub fn split_into_chunks(&self, chunk_size: T) -> Vec<Self> {
+        let (first, last) = match (self.key_points.first(), self.key_points.last()) {
+            (Some(first), Some(last)) if first < last => (first, last),
+            _ => return vec![self.clone()],
+        };
+        let zero = first.clone() - first.clone();
+        assert!(chunk_size > zero, "chunk_size must be positive");
+
+        let mut window_start = first.clone();
+        let mut start = None;
+        let mut chunks = Vec::new();
+        // the window ends before the last key point, so `window_start + chunk_size` doesn't overflow
+        while last.clone() - window_start.clone() > chunk_size {
+            let end = window_start + chunk_size.clone();
+            chunks.push(self.restrict(start.as_ref(), Some(&end)));
+            window_start = end.clone();
+            start = Some(end);
+        }
+        chunks.push(self.restrict(start.as_ref(), None));
+        chunks
+    }
+
+    /// Merge the chunks created by `split_into_chunks` with the same `chunk_size` back into one mask.
+    ///
+    /// It concatenates the key points of the chunks, which takes O(n).
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the chunks are not the contiguous windows of `chunk_size`
+    /// starting at the first key point, as created by `split_into_chunks`.
+    pub fn merge_chunks(chunks: Vec<Self>, chunk_size: T) -> Self {
+        let reversed = chunks.first().is_some_and(|chunk| chunk.reversed);
+        if let Some(first) = chunks.first().and_then(|chunk| chunk.key_points.first()) {
+            let mut window_start = first.clone();
+            let last_index = chunks.len() - 1;
+            for (i, chunk) in chunks.iter().enumerate() {
+                // only the windows before the last one were computed by `split_into_chunks`
+                let window_end =
+                    (i < last_index).then(|| window_start.clone() + chunk_size.clone());
+                let in_window = |x: &T| {
+                    (i == 0 || *x >= window_start) && window_end.as_ref().is_none_or(|end| x <= end)
+                };
+                assert!(
+                    chunk.key_points.iter().all(in_window),
+                    "the chunk at index {i} is not in its window"
+                );
+                if let Some(end) = window_end {
+                    window_start = end;
+                }
+            }
+        }
+        let key_points = chunks
+            .into_iter()
+            .flat_map(|chunk| chunk.key_points)
+            .collect();
+        Self::try_new(key_points, reversed).unwrap()
+    }
+}
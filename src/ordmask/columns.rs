@@ -0,0 +1,91 @@
+use super::{Error, OrdMask};
+
+/// The error returned by [`OrdMask::from_alternating_starts_ends`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StartsEndsError {
+    /// There are `starts` starts but `ends` ends.
+    LengthMismatch { starts: usize, ends: usize },
+    /// The boundaries are out of order,
+    /// where `index` is the position in the interleaved list `starts[0], ends[0], starts[1], ...`.
+    Order(Error),
+}
+
+impl std::fmt::Display for StartsEndsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch { starts, ends } => write!(
+                f,
+                "There are {} starts but {} ends, which should be the same.",
+                starts, ends
+            ),
+            Self::Order(error) => std::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for StartsEndsError {}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Create an `OrdMask` from the ranges `[starts[i], ends[i])` stored in two parallel sorted arrays,
+    /// like the columns of a columnar storage.
+    ///
+    /// It requires `starts[i] < ends[i]` and `ends[i] <= starts[i + 1]`,
+    /// and the touching ranges are merged.
+    /// If `reversed` is true, the ranges are the excluded ones instead of the included ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, StartsEndsError, ordmask};
+    ///
+    /// let mask = OrdMask::from_alternating_starts_ends(&[0, 10, 20], &[5, 20, 30], false);
+    /// assert_eq!(mask, Ok(ordmask![0, 5, 10, 30]));
+    ///
+    /// let mask = OrdMask::from_alternating_starts_ends(&[0, 10], &[5, 20], true);
+    /// assert_eq!(mask, Ok(ordmask![_, 0, 5, 10, 20]));
+    ///
+    /// let mask = OrdMask::from_alternating_starts_ends(&[0, 10], &[15, 20], false);
+    /// assert_eq!(mask, Err(StartsEndsError::Order(Error::Falling { index: 2 })));
+    ///
+    /// let mask = OrdMask::from_alternating_starts_ends(&[0, 10], &[5], false);
+    /// assert_eq!(mask, Err(StartsEndsError::LengthMismatch { starts: 2, ends: 1 }));
+    /// ```
+    pub fn from_alternating_starts_ends(
+        starts: &[T],
+        ends: &[T],
+        reversed: bool,
+    ) -> Result<Self, StartsEndsError> {
+        if starts.len() != ends.len() {
+            return Err(StartsEndsError::LengthMismatch {
+                starts: starts.len(),
+                ends: ends.len(),
+            });
+        }
+        let mut key_points: Vec<T> = Vec::with_capacity(starts.len() * 2);
+        for (i, (start, end)) in starts.iter().zip(ends).enumerate() {
+            if let Some(prev_end) = key_points.last() {
+                if start < prev_end {
+                    return Err(StartsEndsError::Order(Error::Falling { index: 2 * i }));
+                }
+            }
+            if end < start {
+                return Err(StartsEndsError::Order(Error::Falling { index: 2 * i + 1 }));
+            }
+            if end == start {
+                return Err(StartsEndsError::Order(Error::DuplicateKeyPoint {
+                    index: 2 * i + 1,
+                }));
+            }
+            if key_points.last() == Some(start) {
+                key_points.pop();
+            } else {
+                key_points.push(start.clone());
+            }
+            key_points.push(end.clone());
+        }
+        Ok(Self {
+            key_points,
+            reversed,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use super::OrdMask;
+
+/// The error returned by [`OrdMask::consistency_check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// The key points should be non-decreasing,
+    /// but the value at `index` is less than the value at `index - 1`.
+    Falling { index: usize },
+    /// The inclusion of the key point at `index` by `included`
+    /// differs from the parity implied by `reversed` and the number of key points before it.
+    ParityMismatch { index: usize },
+}
+
+impl std::fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Falling { index } => write!(
+                f,
+                "The key point at index {} is less than the key point at index {}.",
+                index,
+                index - 1
+            ),
+            Self::ParityMismatch { index } => write!(
+                f,
+                "The inclusion of the key point at index {} differs from the parity of the key points.",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConsistencyError {}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Check if the internal state of the mask is consistent.
+    ///
+    /// The key points must be non-decreasing, as `is_valid` checks,
+    /// and `included` at a few probe key points must agree with the parity implied by `reversed`
+    /// and the number of key points, which also catches an `Ord` implementation that is not a total order.
+    ///
+    /// Masks built by the checked methods are always consistent.
+    /// The set operations debug-assert it for their operands,
+    /// so masks broken by `with_unchecked` or `from_parts_unchecked` fail loudly in debug builds rather than producing wrong results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{ConsistencyError, OrdMask, ordmask};
+    ///
+    /// assert_eq!(ordmask![0, 10, 10, 20].consistency_check(), Ok(()));
+    ///
+    /// let broken = unsafe { OrdMask::with_unchecked(vec![0, 20, 10], false) };
+    /// assert_eq!(broken.consistency_check(), Err(ConsistencyError::Falling { index: 2 }));
+    /// ```
+    pub fn consistency_check(&self) -> Result<(), ConsistencyError> {
+        let len = self.key_points.len();
+        for index in 1..len {
+            if self.key_points[index] < self.key_points[index - 1] {
+                return Err(ConsistencyError::Falling { index });
+            }
+        }
+        for probe in [0, len / 2, len.saturating_sub(1)] {
+            // Probe at the last key point of the run of equal key points containing `probe`
+            let Some(point) = self.key_points.get(probe) else {
+                break;
+            };
+            let index = probe + self.key_points[probe..].partition_point(|x| x == point) - 1;
+            if self.included(point) != self.reversed ^ (index % 2 == 0) {
+                return Err(ConsistencyError::ParityMismatch { index });
+            }
+        }
+        Ok(())
+    }
+}
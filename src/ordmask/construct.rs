@@ -1,4 +1,4 @@
-use super::OrdMask;
+use super::{Error, OrdMask, Workspace};
 
 impl<T: Ord + Clone> OrdMask<T> {
     fn new(key_points: Vec<T>, reversed: bool) -> Self {
@@ -88,6 +88,139 @@ impl<T: Ord + Clone> OrdMask<T> {
         }
     }
 
+    /// Create a new OrdMask that includes all values in the range `[start, end)`,
+    /// or return an error if `start > end`.
+    ///
+    /// Unlike `in_range`, which returns an empty mask for swapped arguments,
+    /// it reports them as `Error::Falling { index: 1 }`, the same error as `try_from(vec![start, end])`.
+    /// An empty range with `start == end` is not an error.
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    /// assert_eq!(OrdMask::try_in_range(1, 10), Ok(ordmask![1, 10]));
+    /// assert_eq!(OrdMask::try_in_range(1, 1), Ok(ordmask![]));
+    /// assert_eq!(OrdMask::try_in_range(10, 1), Err(Error::Falling { index: 1 }));
+    /// ```
+    pub fn try_in_range(start: T, end: T) -> Result<Self, Error> {
+        match start <= end {
+            true => Ok(Self::in_range(start, end)),
+            false => Err(Error::Falling { index: 1 }),
+        }
+    }
+
+    /// Create a new OrdMask that excludes all values in the range `[start, end)`,
+    /// or return an error if `start > end`.
+    ///
+    /// It's the fallible version of `exclude_range`, like `try_in_range`.
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    /// assert_eq!(OrdMask::try_exclude_range(1, 10), Ok(ordmask![_, 1, 10]));
+    /// assert_eq!(OrdMask::try_exclude_range(10, 1), Err(Error::Falling { index: 1 }));
+    /// ```
+    pub fn try_exclude_range(start: T, end: T) -> Result<Self, Error> {
+        match start <= end {
+            true => Ok(Self::exclude_range(start, end)),
+            false => Err(Error::Falling { index: 1 }),
+        }
+    }
+
+    /// Create a new OrdMask that includes all values in any of the `ranges`.
+    ///
+    /// Each range `(start, end)` means `[start, end)`, and the ranges with `start >= end` are ignored.
+    /// The ranges can be unsorted and overlapping.
+    /// A half-open range can't include the maximum value of a bounded type,
+    /// so use `from_inclusive_ranges` to build a mask including it.
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    /// assert_eq!(OrdMask::from_ranges([(20, 30), (0, 10), (5, 15), (30, 40), (9, 0)]), ordmask![0, 15, 20, 40]);
+    /// ```
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let ranges: Vec<(T, T)> = ranges
+            .into_iter()
+            .filter(|(start, end)| start < end)
+            .collect();
+        Self::from_non_empty_ranges(ranges)
+    }
+
+    /// Create a new OrdMask that includes all values in any of the `ranges`,
+    /// stopping at the first error.
+    ///
+    /// It's the fallible version of `from_ranges`, for ranges produced by a parser or other fallible source.
+    /// No intermediate `Result<Vec<_>, _>` is collected,
+    /// and the ranges after the first error are not consumed.
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    /// let parsed: [Result<(i32, i32), &str>; 2] = [Ok((20, 30)), Ok((0, 10))];
+    /// assert_eq!(OrdMask::try_from_ranges(parsed), Ok(ordmask![0, 10, 20, 30]));
+    ///
+    /// let parsed = [Ok((20, 30)), Err("bad range"), Err("worse range")];
+    /// assert_eq!(OrdMask::try_from_ranges(parsed), Err("bad range"));
+    /// ```
+    pub fn try_from_ranges<E>(
+        ranges: impl IntoIterator<Item = Result<(T, T), E>>,
+    ) -> Result<Self, E> {
+        let mut non_empty = Vec::new();
+        for range in ranges {
+            let (start, end) = range?;
+            if start < end {
+                non_empty.push((start, end));
+            }
+        }
+        Ok(Self::from_non_empty_ranges(non_empty))
+    }
+
+    fn from_non_empty_ranges(mut ranges: Vec<(T, T)>) -> Self {
+        ranges.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let mut mask: Vec<T> = Vec::with_capacity(ranges.len() * 2);
+        for (start, end) in ranges {
+            match mask.last_mut() {
+                Some(last) if *last >= start => {
+                    if *last < end {
+                        *last = end;
+                    }
+                }
+                _ => {
+                    mask.push(start);
+                    mask.push(end);
+                }
+            }
+        }
+        mask.shrink_to_fit();
+        Self::new(mask, false)
+    }
+
+    /// Create a new OrdMask that includes all values in `outer` except the ones in `inner_exclusions`.
+    ///
+    /// It's the same as `OrdMask::in_range(outer.0, outer.1) - OrdMask::from_ranges(inner_exclusions)`.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if any non-empty inner exclusion is not within the `outer` range.
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    /// // office hours with lunch break excluded
+    /// let mask = OrdMask::from_nested_ranges((9, 18), vec![(12, 13)]);
+    /// assert_eq!(mask, ordmask![9, 12, 13, 18]);
+    /// ```
+    pub fn from_nested_ranges(outer: (T, T), inner_exclusions: Vec<(T, T)>) -> Self {
+        for (start, end) in &inner_exclusions {
+            assert!(
+                start >= end || (outer.0 <= *start && *end <= outer.1),
+                "inner exclusions must be within the outer range"
+            );
+        }
+        Self::minus_with_workspace(
+            &Self::in_range(outer.0, outer.1),
+            &Self::from_ranges(inner_exclusions),
+            &mut Workspace::new(),
+        )
+    }
+
     /// Create a new OrdMask from a set of key points and a predicate.
     ///
     /// The `key_points` are the values where the mask changes its state.
@@ -122,7 +255,8 @@ impl<T: Ord + Clone> OrdMask<T> {
         is_included: impl Fn(&T) -> bool,
         include_min_value: bool,
     ) -> Self {
-        let mut mask = Vec::with_capacity(key_points.len());
+        // most points may not survive, so the output grows from empty instead of reserving the input size
+        let mut mask = Vec::new();
         for point in key_points {
             if (is_included(&point) == (mask.len() % 2 == 0)) ^ include_min_value {
                 mask.push(point);
@@ -132,6 +266,59 @@ impl<T: Ord + Clone> OrdMask<T> {
         Self::new(mask, include_min_value)
     }
 
+    /// Create a new OrdMask from a key points set and a predicate that can stop the evaluation early.
+    ///
+    /// It's the same as `from_key_points_set`, except that `is_included` returns a `ControlFlow`:
+    /// `Continue(included)` gives the state at the key point,
+    /// and `Break(included)` gives the state at the key point and all the remaining key points,
+    /// so the predicate is not called for them.
+    /// It's for expensive predicates that know when they become constant,
+    /// like a monotone predicate which stays `true` after the first `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    /// use std::collections::BTreeSet;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let mut calls = 0;
+    /// // a monotone predicate, which is true from 20 onwards
+    /// let mask = OrdMask::from_key_points_predicate_with_early_exit(
+    ///     BTreeSet::from([0, 10, 20, 30, 40]),
+    ///     |x| {
+    ///         calls += 1;
+    ///         match *x >= 20 {
+    ///             true => ControlFlow::Break(true),
+    ///             false => ControlFlow::Continue(false),
+    ///         }
+    ///     },
+    ///     false,
+    /// );
+    /// assert_eq!(mask, ordmask![20]);
+    /// assert_eq!(calls, 3);
+    /// ```
+    pub fn from_key_points_predicate_with_early_exit(
+        key_points: std::collections::BTreeSet<T>,
+        mut is_included: impl FnMut(&T) -> std::ops::ControlFlow<bool, bool>,
+        include_min_value: bool,
+    ) -> Self {
+        let mut mask = Vec::new();
+        for point in key_points {
+            let (included, done) = match is_included(&point) {
+                std::ops::ControlFlow::Continue(included) => (included, false),
+                std::ops::ControlFlow::Break(included) => (included, true),
+            };
+            if (included == (mask.len() % 2 == 0)) ^ include_min_value {
+                mask.push(point);
+            }
+            if done {
+                break;
+            }
+        }
+        Self::new(mask, include_min_value)
+    }
+
     /// Create a new OrdMask from a key points to boolean map.
     ///
     /// The `map` is a map of key points to the boolean value indicates
@@ -162,7 +349,8 @@ impl<T: Ord + Clone> OrdMask<T> {
         map: std::collections::BTreeMap<T, bool>,
         include_min_value: bool,
     ) -> Self {
-        let mut mask = Vec::with_capacity(map.len());
+        // grow from empty like `from_key_points_set`
+        let mut mask = Vec::new();
         for (point, is_included) in map {
             if (is_included == (mask.len() % 2 == 0)) ^ include_min_value {
                 mask.push(point);
@@ -171,8 +359,161 @@ impl<T: Ord + Clone> OrdMask<T> {
         mask.shrink_to_fit();
         Self::new(mask, include_min_value)
     }
+
+    /// Create a new OrdMask from a stream of `(point, is_now_included)` events in non-decreasing order.
+    ///
+    /// The mask excludes the values before the first event, and switches to the given state at each event.
+    /// An event that doesn't change the state is ignored,
+    /// and if there are multiple events at the same point, the last one wins.
+    ///
+    /// It's the streaming version of `from_key_points_map`,
+    /// which returns `Error::Falling` if an event is before the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    ///
+    /// let events = [(0, true), (5, true), (10, false), (20, true), (20, false)];
+    /// assert_eq!(OrdMask::from_sorted_bool_events(events), Ok(ordmask![0, 10]));
+    ///
+    /// let events = [(0, true), (10, false), (5, true)];
+    /// assert_eq!(OrdMask::from_sorted_bool_events(events), Err(Error::Falling { index: 2 }));
+    /// ```
+    pub fn from_sorted_bool_events(
+        events: impl IntoIterator<Item = (T, bool)>,
+    ) -> Result<Self, Error> {
+        let mut key_points: Vec<T> = Vec::new();
+        let mut included = false;
+        let mut prev: Option<T> = None;
+        for (index, (point, is_now_included)) in events.into_iter().enumerate() {
+            if prev.as_ref().is_some_and(|prev| point < *prev) {
+                return Err(Error::Falling { index });
+            }
+            if is_now_included != included {
+                included = is_now_included;
+                match key_points.last() == Some(&point) {
+                    true => {
+                        key_points.pop();
+                    }
+                    false => key_points.push(point.clone()),
+                }
+            }
+            prev = Some(point);
+        }
+        Ok(Self::new(key_points, false))
+    }
+
+    /// Create a new OrdMask from unsorted `(point, becomes_included)` transitions.
+    ///
+    /// It is lenient about the input:
+    /// if there are multiple transitions at the same point, the last one wins,
+    /// and the transitions that don't change the state are ignored.
+    ///
+    /// The `include_min_value` is the state before the first transition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask = OrdMask::from_transitions_lenient(
+    ///     [(10, false), (0, true), (5, true), (10, true), (10, false)],
+    ///     false,
+    /// );
+    /// assert_eq!(mask, ordmask![0, 10]);
+    /// ```
+    pub fn from_transitions_lenient(
+        transitions: impl IntoIterator<Item = (T, bool)>,
+        include_min_value: bool,
+    ) -> Self {
+        Self::from_key_points_map(transitions.into_iter().collect(), include_min_value)
+    }
+
+    /// Create a new OrdMask from unsorted `(point, becomes_included)` transitions.
+    ///
+    /// Unlike `from_transitions_lenient`, it returns an error describing the first
+    /// (in ascending order of points) redundant or contradictory transition.
+    ///
+    /// The `include_min_value` is the state before the first transition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, TransitionError, ordmask};
+    ///
+    /// let mask = OrdMask::try_from_transitions([(10, false), (0, true)], false);
+    /// assert_eq!(mask, Ok(ordmask![0, 10]));
+    ///
+    /// let mask = OrdMask::try_from_transitions([(10, false), (0, true), (5, true)], false);
+    /// assert_eq!(mask, Err(TransitionError::Redundant { point: 5, included: true }));
+    ///
+    /// let mask = OrdMask::try_from_transitions([(0, true), (0, false)], false);
+    /// assert_eq!(mask, Err(TransitionError::Contradictory { point: 0 }));
+    /// ```
+    pub fn try_from_transitions(
+        transitions: impl IntoIterator<Item = (T, bool)>,
+        include_min_value: bool,
+    ) -> Result<Self, TransitionError<T>> {
+        let mut transitions: Vec<(T, bool)> = transitions.into_iter().collect();
+        transitions.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut mask = Vec::with_capacity(transitions.len());
+        let mut included = include_min_value;
+        let mut prev: Option<&(T, bool)> = None;
+        for transition in &transitions {
+            let (point, is_included) = transition;
+            if let Some((prev_point, prev_included)) = prev {
+                if prev_point == point && prev_included != is_included {
+                    return Err(TransitionError::Contradictory {
+                        point: point.clone(),
+                    });
+                }
+            }
+            if *is_included == included {
+                return Err(TransitionError::Redundant {
+                    point: point.clone(),
+                    included,
+                });
+            }
+            mask.push(point.clone());
+            included = *is_included;
+            prev = Some(transition);
+        }
+        Ok(Self::new(mask, include_min_value))
+    }
 }
 
+/// The error returned by [`OrdMask::try_from_transitions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransitionError<T> {
+    /// The transition at `point` doesn't change the state, which is already `included`.
+    Redundant { point: T, included: bool },
+    /// There are transitions to both states at `point`.
+    Contradictory { point: T },
+}
+
+impl<T: std::fmt::Debug> std::fmt::Display for TransitionError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Redundant { point, included } => write!(
+                f,
+                "The transition at {:?} is redundant because the state is already {}.",
+                point,
+                match included {
+                    true => "included",
+                    false => "excluded",
+                },
+            ),
+            Self::Contradictory { point } => {
+                write!(f, "There are contradictory transitions at {:?}.", point)
+            }
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for TransitionError<T> {}
+
 /// Create an `OrdMask` from a list of key points.
 ///
 /// # Panics
@@ -212,6 +553,33 @@ macro_rules! ordmask {
     };
 }
 
+/// Create an `OrdMask` from a list of key points, returning an error instead of panicking.
+///
+/// It's the fallible version of `ordmask!`, returning `Result<OrdMask<T>, Error>`.
+///
+/// ```
+/// use ordmask::{Error, OrdMask, ordmask, ordmask_try};
+///
+/// assert_eq!(ordmask_try![0, 10, 20], Ok(ordmask![0, 10, 20]));
+/// assert_eq!(ordmask_try![_, 0, 10], Ok(ordmask![_, 0, 10]));
+/// assert_eq!(ordmask_try![10, 0], Err(Error::Falling { index: 1 }));
+/// ```
+#[macro_export]
+macro_rules! ordmask_try {
+    () => {
+        Ok::<_, ordmask::Error>(ordmask::OrdMask::empty())
+    };
+    ($($key_points:expr),+ $(,)?) => {
+        ordmask::OrdMask::try_from(vec![$($key_points),+])
+    };
+    (_, $($key_points:expr),+ $(,)?) => {
+        ordmask::OrdMask::try_from_complement_vec(vec![$($key_points),+])
+    };
+    (_) => {
+        Ok::<_, ordmask::Error>(ordmask::OrdMask::universal())
+    };
+}
+
 /// Create an `OrdMask` from a list of key points without checking if the key points are non-decreasing.
 ///
 /// # Safety
@@ -1,6 +1,6 @@
 use super::OrdMask;
 
-pub fn get_first_falling_index<T: PartialOrd>(vec: &Vec<T>) -> usize {
+pub fn get_first_falling_index<T: PartialOrd>(vec: &[T]) -> usize {
     for i in 1..vec.len() {
         if vec[i] < vec[i - 1] {
             return i;
@@ -9,28 +9,109 @@ pub fn get_first_falling_index<T: PartialOrd>(vec: &Vec<T>) -> usize {
     0
 }
 
-pub struct Error {
-    falling_pos: usize,
+/// The error returned when the key points can't be converted to an `OrdMask`.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The key points should be non-decreasing,
+    /// but the value at `index` is less than the value at `index - 1`.
+    Falling { index: usize },
+    /// The key points should be strictly increasing,
+    /// but the value at `index` is equal to the value at `index - 1`.
+    DuplicateKeyPoint { index: usize },
 }
 
 impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Falling { index } => write!(
+                f,
+                "Can't convert Vec<T> to OrdMask<T> because it should be non-decreasing.\n\
+                The value at index {} is less than the value at index {}.",
+                index,
+                index - 1,
+            ),
+            Self::DuplicateKeyPoint { index } => write!(
+                f,
+                "Can't convert Vec<T> to OrdMask<T> because it should be strictly increasing.\n\
+                The value at index {} is equal to the value at index {}.",
+                index,
+                index - 1,
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The error returned by [`OrdMask::try_convert`] when a key point doesn't fit the target type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConvertError<T> {
+    /// The index of the first key point that doesn't fit.
+    pub index: usize,
+    /// The value of the first key point that doesn't fit.
+    pub value: T,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Display for ConvertError<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Can't convert Vec<T> to OrdMask<T> because it should be non-decreasing.\n\
-            The value at index {} is less than the value at index {}.",
-            self.falling_pos,
-            self.falling_pos - 1,
+            "The key point {:?} at index {} doesn't fit the target type.",
+            self.value, self.index
         )
     }
 }
 
+impl<T: std::fmt::Debug> std::error::Error for ConvertError<T> {}
+
+/// Get the key points of the mask.
+///
+/// It drops whether the mask includes the minimum value, so it's not a way to persist a mask.
+/// Convert the mask to a `(Vec<T>, bool)` pair instead, which is the canonical storage round-trip.
 impl<T: Ord + Clone> From<OrdMask<T>> for Vec<T> {
     fn from(mask: OrdMask<T>) -> Self {
         mask.key_points
     }
 }
 
+/// Get the key points and whether the mask includes the minimum value, which is the canonical storage form.
+///
+/// `OrdMask::try_from` restores the mask from the pair.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{OrdMask, ordmask};
+///
+/// let mask = ordmask![_, 0, 10];
+/// let stored: (Vec<i32>, bool) = mask.clone().into();
+/// assert_eq!(stored, (vec![0, 10], true));
+/// assert_eq!(OrdMask::try_from(stored), Ok(mask));
+/// ```
+impl<T: Ord + Clone> From<OrdMask<T>> for (Vec<T>, bool) {
+    fn from(mut mask: OrdMask<T>) -> Self {
+        mask.normalize();
+        (mask.key_points, mask.reversed)
+    }
+}
+
+/// Restore a mask from the key points and whether it includes the minimum value.
+///
+/// Like `TryFrom<Vec<T>>`, the key points must be non-decreasing, and the mask is simplified.
+impl<T: Ord + Clone> TryFrom<(Vec<T>, bool)> for OrdMask<T> {
+    type Error = Error;
+
+    fn try_from((key_points, reversed): (Vec<T>, bool)) -> Result<Self, Self::Error> {
+        Self::try_new(key_points, reversed)
+    }
+}
+
 impl<T: Ord + Clone> TryFrom<Vec<T>> for OrdMask<T> {
     type Error = Error;
 
@@ -39,8 +120,24 @@ impl<T: Ord + Clone> TryFrom<Vec<T>> for OrdMask<T> {
     }
 }
 
+impl<T: Ord + Clone> TryFrom<&[T]> for OrdMask<T> {
+    type Error = Error;
+
+    fn try_from(key_points: &[T]) -> Result<Self, Self::Error> {
+        Self::try_new(key_points.to_vec(), false)
+    }
+}
+
+impl<T: Ord + Clone, const N: usize> TryFrom<[T; N]> for OrdMask<T> {
+    type Error = Error;
+
+    fn try_from(key_points: [T; N]) -> Result<Self, Self::Error> {
+        Self::try_new(key_points.into(), false)
+    }
+}
+
 impl<T: Ord + Clone> OrdMask<T> {
-    fn try_new(key_points: Vec<T>, reversed: bool) -> Result<Self, Error> {
+    pub(crate) fn try_new(key_points: Vec<T>, reversed: bool) -> Result<Self, Error> {
         match get_first_falling_index(&key_points) {
             0 => {
                 let mut result = Self {
@@ -50,7 +147,7 @@ impl<T: Ord + Clone> OrdMask<T> {
                 result.simplify();
                 Ok(result)
             }
-            n => Err(Error { falling_pos: n }),
+            index => Err(Error::Falling { index }),
         }
     }
 
@@ -69,20 +166,240 @@ impl<T: Ord + Clone> OrdMask<T> {
     /// # Panics
     ///
     /// It will panic if `vec` is not non-decreasing.
-    /// Or you can use `try_from` to handle the error.
+    /// Or you can use `try_from_complement_vec` to handle the error.
     pub fn from_complement(key_points: Vec<T>) -> Self {
         Self::try_new(key_points, true).unwrap()
     }
 
-    /// Create an `OrdMask` from a `Vec<T>`.
+    /// Create an `OrdMask` from a `Vec<T>` that is the complement of the original mask.
+    ///
+    /// It's the fallible version of `from_complement`, as `try_from` is to `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    ///
+    /// assert_eq!(OrdMask::try_from_complement_vec(vec![0, 10, 10]), Ok(ordmask![_, 0]));
+    /// assert_eq!(OrdMask::try_from_complement_vec(vec![0, 10, 5]), Err(Error::Falling { index: 2 }));
+    /// ```
+    pub fn try_from_complement_vec(key_points: Vec<T>) -> Result<Self, Error> {
+        Self::try_new(key_points, true)
+    }
+
+    /// Create an `OrdMask` from strictly increasing key points.
+    ///
+    /// Unlike `try_from`, which accepts equal consecutive key points and simplifies them,
+    /// it requires the key points to be already simplified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    ///
+    /// let mask = OrdMask::from_sorted_key_points_checking_strict(vec![0, 10], true);
+    /// assert_eq!(mask, Ok(ordmask![_, 0, 10]));
+    ///
+    /// let mask = OrdMask::from_sorted_key_points_checking_strict(vec![0, 10, 10], false);
+    /// assert_eq!(mask, Err(Error::DuplicateKeyPoint { index: 2 }));
+    ///
+    /// let mask = OrdMask::from_sorted_key_points_checking_strict(vec![0, 10, 5], false);
+    /// assert_eq!(mask, Err(Error::Falling { index: 2 }));
+    /// ```
+    pub fn from_sorted_key_points_checking_strict(
+        key_points: Vec<T>,
+        reversed: bool,
+    ) -> Result<Self, Error> {
+        for index in 1..key_points.len() {
+            match key_points[index].cmp(&key_points[index - 1]) {
+                std::cmp::Ordering::Less => return Err(Error::Falling { index }),
+                std::cmp::Ordering::Equal => return Err(Error::DuplicateKeyPoint { index }),
+                std::cmp::Ordering::Greater => {}
+            }
+        }
+        Ok(Self {
+            key_points,
+            reversed,
+        })
+    }
+
+    /// Get the key points and whether the mask includes the minimum value, as a flat pair.
+    ///
+    /// It's the stable accessor for bindings to other languages, like a wasm frontend.
+    /// The key points are guaranteed to be strictly increasing unless the mask is built by `with_unchecked` or `from_parts_unchecked`
+    /// or closed by `close_tail_at_max`,
+    /// and `from_flat` restores the mask from the pair.
+    /// This contract will not change in future versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert_eq!(ordmask![_, 0, 10].as_flat(), (&[0, 10][..], true));
+    /// ```
+    pub fn as_flat(&self) -> (&[T], bool) {
+        (&self.key_points, self.reversed)
+    }
+
+    /// Create an `OrdMask` by cloning the flat pair returned by `as_flat`.
+    ///
+    /// The key points must be strictly increasing, as `from_sorted_key_points_checking_strict` checks,
+    /// so `OrdMask::from_flat(mask.as_flat())` equals `mask` for any mask not built by `with_unchecked` or `from_parts_unchecked`
+    /// or closed by `close_tail_at_max`.
+    /// This contract will not change in future versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    ///
+    /// let mask = ordmask![_, 0, 10];
+    /// let (points, reversed) = mask.as_flat();
+    /// assert_eq!(OrdMask::from_flat(points, reversed), Ok(mask));
+    /// assert_eq!(OrdMask::from_flat(&[10, 0], false), Err(Error::Falling { index: 1 }));
+    /// ```
+    pub fn from_flat(points: &[T], reversed: bool) -> Result<Self, Error> {
+        Self::from_sorted_key_points_checking_strict(points.to_vec(), reversed)
+    }
+
+    /// Create an `OrdMask` from the key points `f(0), f(1), ..., f(n - 1)`.
+    ///
+    /// The key points must be non-decreasing, otherwise it returns `Error::Falling`.
+    /// Like `try_from`, equal key points are simplified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{Error, OrdMask, ordmask};
+    ///
+    /// let mask = OrdMask::from_key_points_fn(10, |i| i * 5, false);
+    /// assert_eq!(mask, Ok(ordmask![0, 5, 10, 15, 20, 25, 30, 35, 40, 45]));
+    ///
+    /// let mask = OrdMask::from_key_points_fn(3, |i| [0, 10, 5][i], true);
+    /// assert_eq!(mask, Err(Error::Falling { index: 2 }));
+    /// ```
+    pub fn from_key_points_fn(
+        n: usize,
+        f: impl Fn(usize) -> T,
+        reversed: bool,
+    ) -> Result<Self, Error> {
+        Self::try_new((0..n).map(f).collect(), reversed)
+    }
+
+    /// Create an `OrdMask` from a `Vec<T>` without validating the key points.
+    ///
+    /// It's the same as `from_parts_unchecked`, which should be preferred,
+    /// and it stays `unsafe` only for compatibility.
     ///
     /// # Safety
     ///
-    /// The `vec` must be non-decreasing, otherwise the behavior is undefined.
+    /// No memory safety depends on the order of the key points, so any `vec` is sound.
+    /// The `vec` should be non-decreasing, otherwise the results of the methods are unspecified,
+    /// like `from_parts_unchecked`.
     pub unsafe fn with_unchecked(key_points: Vec<T>, reversed: bool) -> Self {
+        Self::from_parts_unchecked(key_points, reversed)
+    }
+
+    /// Create an `OrdMask` from its fields without validating the key points.
+    ///
+    /// It's the inverse of `into_parts_unchecked`.
+    /// Unlike `with_unchecked`, it's safe, since no memory safety depends on the order of the key points,
+    /// but if they are not non-decreasing, the results of the methods are unspecified.
+    /// Use `is_valid` to check the key points, or `try_from` to build a checked mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask = OrdMask::from_parts_unchecked(vec![0, 10], true);
+    /// assert_eq!(mask, ordmask![_, 0, 10]);
+    /// ```
+    pub fn from_parts_unchecked(key_points: Vec<T>, reversed: bool) -> Self {
         Self {
             key_points,
             reversed,
         }
     }
+
+    /// Take the key points and whether the mask includes the minimum value out of the mask, as they are.
+    ///
+    /// Unlike the `(Vec<T>, bool)` conversion, the mask is not normalized first,
+    /// so it costs nothing but may return duplicate key points of a mask built by `with_unchecked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let (key_points, reversed) = ordmask![_, 0, 10].into_parts_unchecked();
+    /// assert_eq!((key_points, reversed), (vec![0, 10], true));
+    ///
+    /// let mask = OrdMask::from_parts_unchecked(vec![0, 5, 5, 10], false);
+    /// assert_eq!(mask.into_parts_unchecked(), (vec![0, 5, 5, 10], false));
+    /// ```
+    pub fn into_parts_unchecked(self) -> (Vec<T>, bool) {
+        (self.key_points, self.reversed)
+    }
+
+    /// Convert the key type to a wider type, like `u32` to `u64`.
+    ///
+    /// The conversion must preserve the order, which holds for the `From` impls between primitive types,
+    /// so the key points are not validated again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask: OrdMask<u64> = ordmask![_, 10u32, 20].convert();
+    /// assert_eq!(mask, ordmask![_, 10u64, 20]);
+    /// ```
+    pub fn convert<U: Ord + Clone + From<T>>(self) -> OrdMask<U> {
+        let key_points: Vec<U> = self.key_points.into_iter().map(U::from).collect();
+        debug_assert!(
+            key_points.windows(2).all(|pair| pair[0] <= pair[1]),
+            "the conversion must preserve the order"
+        );
+        OrdMask {
+            key_points,
+            reversed: self.reversed,
+        }
+    }
+
+    /// Convert the key type to a narrower type, like `u64` to `u32`.
+    ///
+    /// It returns the first key point that doesn't fit as a `ConvertError`.
+    /// The conversion must preserve the order of the values that fit,
+    /// which holds for the `TryFrom` impls between primitive types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{ConvertError, OrdMask, ordmask};
+    ///
+    /// let mask: Result<OrdMask<u32>, _> = ordmask![10u64, 20].try_convert();
+    /// assert_eq!(mask, Ok(ordmask![10u32, 20]));
+    ///
+    /// let mask: Result<OrdMask<u32>, _> = ordmask![10u64, 1 << 32, 1 << 33].try_convert();
+    /// assert_eq!(mask, Err(ConvertError { index: 1, value: 1 << 32 }));
+    /// ```
+    pub fn try_convert<U: Ord + Clone + TryFrom<T>>(self) -> Result<OrdMask<U>, ConvertError<T>> {
+        let mut key_points: Vec<U> = Vec::with_capacity(self.key_points.len());
+        for (index, value) in self.key_points.into_iter().enumerate() {
+            match U::try_from(value.clone()) {
+                Ok(point) => key_points.push(point),
+                Err(_) => return Err(ConvertError { index, value }),
+            }
+        }
+        debug_assert!(
+            key_points.windows(2).all(|pair| pair[0] <= pair[1]),
+            "the conversion must preserve the order"
+        );
+        Ok(OrdMask {
+            key_points,
+            reversed: self.reversed,
+        })
+    }
 }
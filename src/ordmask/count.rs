@@ -0,0 +1,183 @@
+use super::OrdMask;
+
+/// The error returned by `OrdMask::boundary_between` when the bracket contains several key points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MultipleBoundaries {
+    /// The number of key points in the bracket, which is at least 2.
+    pub count: usize,
+}
+
+impl std::fmt::Display for MultipleBoundaries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The bracket contains {} key points instead of at most one.",
+            self.count
+        )
+    }
+}
+
+impl std::error::Error for MultipleBoundaries {}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Count the key points in the range `[start, end)`.
+    ///
+    /// Each key point is a transition between included and excluded,
+    /// so it measures the complexity of the mask in the window. It takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30];
+    /// assert_eq!(mask.boundary_count_in_range(&0, &30), 3);
+    /// assert_eq!(mask.boundary_count_in_range(&5, &25), 2);
+    /// assert_eq!(mask.boundary_count_in_range(&11, &19), 0);
+    /// assert_eq!(mask.boundary_count_in_range(&30, &0), 0);
+    /// ```
+    pub fn boundary_count_in_range(&self, start: &T, end: &T) -> usize {
+        if start >= end {
+            return 0;
+        }
+        self.key_points.partition_point(|x| x < end)
+            - self.key_points.partition_point(|x| x < start)
+    }
+
+    /// Count how many times the inclusion changes in the range `[start, end)`.
+    ///
+    /// It's an alias of [`boundary_count_in_range`](Self::boundary_count_in_range),
+    /// named for callers that think in state changes rather than key points,
+    /// since each key point in the window is one change between included and excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![_, 0, 10, 20];
+    /// assert_eq!(mask.count_state_changes_in_range(&-5, &15), 2);
+    /// ```
+    pub fn count_state_changes_in_range(&self, start: &T, end: &T) -> usize {
+        self.boundary_count_in_range(start, end)
+    }
+
+    /// Count the key points strictly between `a` and `b`, which is 0 if `a >= b`.
+    ///
+    /// Each key point is one change of the inclusion, so the inclusion changes that many times in `(a, b)`.
+    /// Since a key point at `b` changes the inclusion of `b` itself,
+    /// `a` and `b` have different inclusion if and only if the count plus `contains_key_point(b)` is odd.
+    /// It takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20];
+    /// assert_eq!(mask.count_boundary_crossings_between(&0, &20), 1);
+    /// assert_eq!(mask.count_boundary_crossings_between(&-1, &21), 3);
+    /// assert_eq!(mask.count_boundary_crossings_between(&5, &15), 1);
+    /// assert_eq!(mask.count_boundary_crossings_between(&15, &5), 0);
+    /// ```
+    pub fn count_boundary_crossings_between(&self, a: &T, b: &T) -> usize {
+        if a >= b {
+            return 0;
+        }
+        self.key_points.partition_point(|x| x < b) - self.key_points.partition_point(|x| x <= a)
+    }
+
+    /// Get the only key point where the inclusion changes between `lo` and `hi`.
+    ///
+    /// The bracket is `(lo, hi]` if `lo <= hi`, or `(hi, lo]` otherwise,
+    /// since the inclusion changes between two values at each key point in the half-open interval
+    /// from the smaller one exclusive to the greater one inclusive.
+    /// It's `Ok(None)` if there is no key point in the bracket,
+    /// `Ok(Some(point))` if there is exactly one,
+    /// and `Err` with the count if there are several, which means the bracket is too wide.
+    /// It takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{MultipleBoundaries, ordmask};
+    ///
+    /// let mask = ordmask![0, 10, 20];
+    /// assert_eq!(mask.boundary_between(&5, &15), Ok(Some(&10)));
+    /// assert_eq!(mask.boundary_between(&15, &5), Ok(Some(&10)));
+    /// assert_eq!(mask.boundary_between(&5, &10), Ok(Some(&10)));
+    /// assert_eq!(mask.boundary_between(&10, &15), Ok(None));
+    /// assert_eq!(mask.boundary_between(&-5, &15), Err(MultipleBoundaries { count: 2 }));
+    /// ```
+    pub fn boundary_between(&self, lo: &T, hi: &T) -> Result<Option<&T>, MultipleBoundaries> {
+        let (a, b) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+        let start = self.key_points.partition_point(|x| x <= a);
+        let end = self.key_points.partition_point(|x| x <= b);
+        match end - start {
+            0 => Ok(None),
+            1 => Ok(Some(&self.key_points[start])),
+            count => Err(MultipleBoundaries { count }),
+        }
+    }
+
+    /// Count the range starts at the key points of indices in `[lo, hi)`.
+    fn range_starts_between(&self, lo: usize, hi: usize) -> usize {
+        if lo >= hi {
+            return 0;
+        }
+        // the key point at index `i` starts a range if the state after it is included
+        if self.reversed {
+            hi / 2 - lo / 2
+        } else {
+            hi.div_ceil(2) - lo.div_ceil(2)
+        }
+    }
+
+    /// Count the included ranges that intersect the window `[start, end)`, including partial ones.
+    ///
+    /// It takes O(log n) without materializing the windowed mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30, 40, 50];
+    /// assert_eq!(mask.count_included_ranges_in_window(&5, &45), 3);
+    /// assert_eq!(mask.count_included_ranges_in_window(&10, &20), 0);
+    /// assert_eq!(ordmask![_, 0].count_included_ranges_in_window(&-5, &5), 1);
+    /// ```
+    pub fn count_included_ranges_in_window(&self, start: &T, end: &T) -> usize {
+        if start >= end {
+            return 0;
+        }
+        let lo = self.key_points.partition_point(|x| x <= start);
+        let hi = self.key_points.partition_point(|x| x < end);
+        let included_at_start = self.reversed ^ (lo % 2 == 1);
+        included_at_start as usize + self.range_starts_between(lo, hi)
+    }
+
+    /// Count the included ranges that are fully within the window `[start, end)`.
+    ///
+    /// The unbounded ranges are never fully within a window. It takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30, 40, 50];
+    /// assert_eq!(mask.count_complete_ranges_in_window(&5, &45), 1);
+    /// assert_eq!(mask.count_complete_ranges_in_window(&0, &50), 3);
+    /// assert_eq!(ordmask![_, 0].count_complete_ranges_in_window(&-5, &5), 0);
+    /// ```
+    pub fn count_complete_ranges_in_window(&self, start: &T, end: &T) -> usize {
+        if start >= end {
+            return 0;
+        }
+        let lo = self.key_points.partition_point(|x| x < start);
+        let hi = self.key_points.partition_point(|x| x <= end);
+        // a range starting at index `i` ends at index `i + 1`, which must be before `hi`
+        self.range_starts_between(lo, hi.saturating_sub(1))
+    }
+}
@@ -0,0 +1,92 @@
+use super::OrdMask;
+
+/// A forward-only cursor checking the inclusion of ascending values in an `OrdMask`.
+///
+/// It is created by [`OrdMask::cursor`] or [`MaskCursor::resume_at`].
+/// Checking `k` ascending values takes O(n + k) in total, instead of O(k log n) by `included`.
+///
+/// The state of the cursor is only the key point index returned by `position`,
+/// so it can be saved and resumed later by `resume_at`, e.g. across the batches of a stream.
+#[derive(Clone, Debug)]
+pub struct MaskCursor<'a, T> {
+    key_points: &'a [T],
+    reversed: bool,
+    position: usize,
+}
+
+impl<'a, T: Ord + Clone> MaskCursor<'a, T> {
+    /// Resume a cursor over `mask` at the key point index `position`,
+    /// which was returned by `position` of a previous cursor over the same mask.
+    ///
+    /// It returns `None` if `position` is greater than the number of key points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{MaskCursor, ordmask};
+    ///
+    /// let mask = ordmask![0, 10, 20, 30];
+    /// let mut cursor = mask.cursor();
+    /// assert!(cursor.advance_to(&5));
+    /// let position = cursor.position();
+    ///
+    /// let mut cursor = MaskCursor::resume_at(&mask, position).unwrap();
+    /// assert!(!cursor.advance_to(&15));
+    /// assert!(MaskCursor::resume_at(&mask, 5).is_none());
+    /// ```
+    pub fn resume_at(mask: &'a OrdMask<T>, position: usize) -> Option<Self> {
+        match position <= mask.key_points.len() {
+            true => Some(Self {
+                key_points: &mask.key_points,
+                reversed: mask.reversed,
+                position,
+            }),
+            false => None,
+        }
+    }
+
+    /// Get the number of key points not greater than the last value passed to `advance_to`.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Move the cursor forward to `value`, and check if `value` is included in the mask.
+    ///
+    /// The values must be passed in non-decreasing order.
+    /// Moving backwards is checked by a debug assertion, and gives wrong answers in release builds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30];
+    /// let mut cursor = mask.cursor();
+    /// let included: Vec<_> = [-5, 0, 9, 10, 25, 30].iter().map(|x| cursor.advance_to(x)).collect();
+    /// assert_eq!(included, vec![false, true, true, false, true, false]);
+    /// ```
+    pub fn advance_to(&mut self, value: &T) -> bool {
+        let key_points = self.key_points;
+        debug_assert!(
+            self.position == 0 || key_points[self.position - 1] <= *value,
+            "the cursor must not move backwards"
+        );
+        while self.position < key_points.len() && key_points[self.position] <= *value {
+            self.position += 1;
+        }
+        self.reversed ^ (self.position % 2 == 1)
+    }
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Create a cursor at the start of the mask, checking the inclusion of ascending values.
+    ///
+    /// See [`MaskCursor`] for details.
+    pub fn cursor(&self) -> MaskCursor<'_, T> {
+        MaskCursor {
+            key_points: &self.key_points,
+            reversed: self.reversed,
+            position: 0,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use super::{OrdMask, Workspace};
+
+/// The regions added and removed from one mask to another.
+///
+/// It is created by [`OrdMask::diff`] or [`OrdMask::diff_stream`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaskDiff<T: Ord + Clone> {
+    /// The values included in the newer mask but not in the older one.
+    pub added: OrdMask<T>,
+    /// The values included in the older mask but not in the newer one.
+    pub removed: OrdMask<T>,
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Get the regions added and removed from `self` to `newer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let diff = ordmask![0, 10].diff(&ordmask![5, 20]);
+    /// assert_eq!(diff.added, ordmask![10, 20]);
+    /// assert_eq!(diff.removed, ordmask![0, 5]);
+    /// ```
+    pub fn diff(&self, newer: &Self) -> MaskDiff<T> {
+        self.diff_with_workspace(newer, &mut Workspace::new())
+    }
+
+    fn diff_with_workspace(&self, newer: &Self, ws: &mut Workspace<T>) -> MaskDiff<T> {
+        MaskDiff {
+            added: Self::minus_with_workspace(newer, self, ws),
+            removed: Self::minus_with_workspace(self, newer, ws),
+        }
+    }
+
+    /// Get an iterator over the diffs between each pair of consecutive `versions`.
+    ///
+    /// The first item is the diff from the first version to the second one,
+    /// and no item is yielded for less than two versions.
+    /// A single workspace is reused across the whole history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use ordmask::OrdMask;
+    ///
+    /// let versions = [ordmask![0, 10], ordmask![0, 20], ordmask![10, 20]];
+    /// let diffs: Vec<_> = OrdMask::diff_stream(&versions).collect();
+    /// assert_eq!(diffs.len(), 2);
+    /// assert_eq!(diffs[0].added, ordmask![10, 20]);
+    /// assert_eq!(diffs[1].removed, ordmask![0, 10]);
+    /// ```
+    pub fn diff_stream<'a, I>(versions: I) -> DiffStream<'a, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'a OrdMask<T>>,
+    {
+        let mut versions = versions.into_iter();
+        DiffStream {
+            older: versions.next(),
+            versions,
+            ws: Workspace::new(),
+        }
+    }
+}
+
+/// An iterator over the diffs between consecutive mask versions.
+///
+/// It is created by [`OrdMask::diff_stream`].
+pub struct DiffStream<'a, T: Ord + Clone, I> {
+    versions: I,
+    older: Option<&'a OrdMask<T>>,
+    ws: Workspace<T>,
+}
+
+impl<'a, T: Ord + Clone, I: Iterator<Item = &'a OrdMask<T>>> Iterator for DiffStream<'a, T, I> {
+    type Item = MaskDiff<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let newer = self.versions.next()?;
+        let diff = self.older?.diff_with_workspace(newer, &mut self.ws);
+        self.older = Some(newer);
+        Some(diff)
+    }
+}
@@ -0,0 +1,116 @@
+/// A type with a minimum value.
+pub trait MinValue {
+    /// The minimum value of the type.
+    const MIN_VALUE: Self;
+}
+
+/// A type with a maximum value.
+pub trait MaxValue {
+    /// The maximum value of the type.
+    const MAX_VALUE: Self;
+}
+
+/// A type whose values have a next value in ascending order.
+///
+/// It can be implemented for custom discrete types to enable the inclusive-range methods,
+/// like `in_range_inclusive`, `point`, `greater_than` and `from_inclusive_ranges`.
+/// An implementation must follow these laws, which can be checked by
+/// [`check_successor_laws`](crate::testing::check_successor_laws):
+///
+/// - If `x.next_value()` is `Some(y)`, then `x < y`, and there is no value strictly between `x` and `y`.
+/// - If `x.next_value()` is `None`, then there is no value greater than `x`.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{OrdMask, Predecessor, Successor, ordmask};
+/// use ordmask::testing::{check_predecessor_laws, check_successor_laws};
+///
+/// /// An amount of money in cents, whose smallest increment is one cent.
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Cents(i64);
+///
+/// impl Successor for Cents {
+///     fn next_value(&self) -> Option<Self> {
+///         self.0.checked_add(1).map(Cents)
+///     }
+/// }
+///
+/// impl Predecessor for Cents {
+///     fn prev_value(&self) -> Option<Self> {
+///         self.0.checked_sub(1).map(Cents)
+///     }
+/// }
+///
+/// let samples = [Cents(i64::MIN), Cents(-1), Cents(0), Cents(1), Cents(i64::MAX)];
+/// check_successor_laws(&samples);
+/// check_predecessor_laws(&samples);
+///
+/// let mask = OrdMask::in_range_inclusive(Cents(100), Cents(199));
+/// assert_eq!(mask, ordmask![Cents(100), Cents(200)]);
+/// assert_eq!(OrdMask::point(Cents(5)), ordmask![Cents(5), Cents(6)]);
+/// assert_eq!(OrdMask::greater_than(Cents(0)), ordmask![Cents(1)]);
+/// ```
+pub trait Successor: Sized {
+    /// Get the smallest value greater than `self`, or `None` if `self` is the maximum value.
+    fn next_value(&self) -> Option<Self>;
+}
+
+/// A type whose values have a previous value in ascending order.
+///
+/// It's the mirror of [`Successor`], and must follow the mirrored laws,
+/// which can be checked by [`check_predecessor_laws`](crate::testing::check_predecessor_laws):
+///
+/// - If `x.prev_value()` is `Some(y)`, then `y < x`, and there is no value strictly between `y` and `x`.
+/// - If `x.prev_value()` is `None`, then there is no value less than `x`.
+pub trait Predecessor: Sized {
+    /// Get the greatest value less than `self`, or `None` if `self` is the minimum value.
+    fn prev_value(&self) -> Option<Self>;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {$(
+        impl MinValue for $t {
+            const MIN_VALUE: Self = <$t>::MIN;
+        }
+
+        impl MaxValue for $t {
+            const MAX_VALUE: Self = <$t>::MAX;
+        }
+
+        impl Successor for $t {
+            fn next_value(&self) -> Option<Self> {
+                self.checked_add(1)
+            }
+        }
+
+        impl Predecessor for $t {
+            fn prev_value(&self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+        }
+    )*};
+}
+
+impl_discrete!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The unit type has a single value, which is both the minimum and the maximum.
+impl MinValue for () {
+    const MIN_VALUE: Self = ();
+}
+
+impl MaxValue for () {
+    const MAX_VALUE: Self = ();
+}
+
+impl Successor for () {
+    fn next_value(&self) -> Option<Self> {
+        None
+    }
+}
+
+impl Predecessor for () {
+    fn prev_value(&self) -> Option<Self> {
+        None
+    }
+}
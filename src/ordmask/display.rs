@@ -0,0 +1,176 @@
+use super::OrdMask;
+use std::fmt;
+use std::ops::Bound;
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Write the mask in interval notation, formatting each key point with `key`.
+    fn write_intervals(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        key: impl Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "∅");
+        }
+        for (i, (start, end)) in self.ranges().enumerate() {
+            if i > 0 {
+                write!(f, " ∪ ")?;
+            }
+            match start {
+                Bound::Included(value) => {
+                    write!(f, "[")?;
+                    key(value, f)?;
+                    write!(f, ", ")?;
+                }
+                Bound::Excluded(value) => {
+                    write!(f, "(")?;
+                    key(value, f)?;
+                    write!(f, ", ")?;
+                }
+                Bound::Unbounded => write!(f, "(-∞, ")?,
+            }
+            match end {
+                Bound::Included(value) => {
+                    key(value, f)?;
+                    write!(f, "]")?;
+                }
+                Bound::Excluded(value) => {
+                    key(value, f)?;
+                    write!(f, ")")?;
+                }
+                Bound::Unbounded => write!(f, "+∞)")?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Get an adapter displaying the mask in interval notation like `Display`,
+    /// but formatting each key point with `key`.
+    ///
+    /// The empty set and the unbounded ends are written by the adapter itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::fmt;
+    ///
+    /// /// Format epoch milliseconds as an RFC 3339 timestamp in UTC.
+    /// fn rfc3339(millis: &i64, f: &mut fmt::Formatter) -> fmt::Result {
+    ///     let (days, millis) = (millis.div_euclid(86_400_000), millis.rem_euclid(86_400_000));
+    ///     // the civil date of the days since 1970-01-01
+    ///     let z = days + 719_468;
+    ///     let era = z.div_euclid(146_097);
+    ///     let doe = z.rem_euclid(146_097);
+    ///     let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    ///     let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    ///     let mp = (5 * doy + 2) / 153;
+    ///     let day = doy - (153 * mp + 2) / 5 + 1;
+    ///     let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    ///     let year = yoe + era * 400 + (month <= 2) as i64;
+    ///     let (hours, minutes, seconds) = (millis / 3_600_000, millis / 60_000 % 60, millis / 1000 % 60);
+    ///     write!(f, "{year:04}-{month:02}-{day:02}T{hours:02}:{minutes:02}:{seconds:02}.{:03}Z", millis % 1000)
+    /// }
+    ///
+    /// let mask = ordmask![1_700_000_000_000i64, 1_700_003_600_000];
+    /// assert_eq!(
+    ///     mask.display_with(rfc3339).to_string(),
+    ///     "[2023-11-14T22:13:20.000Z, 2023-11-14T23:13:20.000Z)"
+    /// );
+    /// assert_eq!(ordmask![_, 0i64].display_with(rfc3339).to_string(), "(-∞, 1970-01-01T00:00:00.000Z)");
+    /// ```
+    pub fn display_with<'a, F>(&'a self, key: F) -> impl fmt::Display + 'a
+    where
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result + 'a,
+    {
+        DisplayWith { mask: self, key }
+    }
+}
+
+/// The adapter returned by `OrdMask::display_with`.
+struct DisplayWith<'a, T: Ord + Clone, F> {
+    mask: &'a OrdMask<T>,
+    key: F,
+}
+
+impl<T, F> fmt::Display for DisplayWith<'_, T, F>
+where
+    T: Ord + Clone,
+    F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.mask.write_intervals(f, &self.key)
+    }
+}
+
+impl<T: Ord + Clone + fmt::Display> fmt::Display for OrdMask<T> {
+    /// Format the mask in interval notation.
+    ///
+    /// ```
+    /// use ordmask::{ordmask, OrdMask};
+    ///
+    /// assert_eq!(ordmask![0, 10, 20].to_string(), "[0, 10) ∪ [20, +∞)");
+    /// assert_eq!(ordmask![_, 0].to_string(), "(-∞, 0)");
+    /// assert_eq!(OrdMask::<i32>::universal().to_string(), "(-∞, +∞)");
+    /// assert_eq!(OrdMask::<i32>::empty().to_string(), "∅");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_intervals(f, |value, f| write!(f, "{}", value))
+    }
+}
+
+/// Assert that two `OrdMask`s are equal.
+///
+/// On failure, both masks and their symmetric difference are printed in interval notation,
+/// so the differing regions are immediately visible.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{assert_mask_eq, ordmask, OrdMask};
+///
+/// assert_mask_eq!(ordmask![0, 10] | ordmask![5, 20], ordmask![0, 20]);
+/// assert_mask_eq!(OrdMask::in_range(0, 10), ordmask![0, 10], "in_range should match");
+/// ```
+///
+/// ```should_panic
+/// use ordmask::{assert_mask_eq, ordmask};
+///
+/// // assertion `left == right` failed
+/// //   left: [0, 10)
+/// //  right: [0, 20)
+/// //   diff: [10, 20)
+/// assert_mask_eq!(ordmask![0, 10], ordmask![0, 20]);
+/// ```
+#[macro_export]
+macro_rules! assert_mask_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if left != right {
+                    panic!(
+                        "assertion `left == right` failed\n  left: {}\n right: {}\n  diff: {}",
+                        left,
+                        right,
+                        left.symmetric_difference(right),
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if left != right {
+                    panic!(
+                        "assertion `left == right` failed: {}\n  left: {}\n right: {}\n  diff: {}",
+                        format_args!($($arg)+),
+                        left,
+                        right,
+                        left.symmetric_difference(right),
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,87 @@
+use super::{Error, OrdMask};
+
+/// The error returned by [`OrdMask::edit_key_points`], after which the mask is rolled back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EditError<E> {
+    /// The closure returned an error.
+    Closure(E),
+    /// The edited key points are not non-decreasing.
+    Order(Error),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for EditError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Closure(error) => write!(f, "The edit of the key points failed: {}", error),
+            Self::Order(error) => std::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for EditError<E> {}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Edit the key points directly with `f`, and validate them afterwards.
+    ///
+    /// It's the safe escape hatch for edits not covered by other methods.
+    /// If `f` returns an error or the edited key points are not non-decreasing,
+    /// the mask is rolled back to the original. Otherwise, the mask is simplified.
+    /// The original key points are cloned for the rollback, so it takes O(n) extra time and memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{EditError, Error, OrdMask, ordmask};
+    ///
+    /// // snap the key points to multiples of 10
+    /// let mut mask = ordmask![3, 12, 18, 27];
+    /// mask.edit_key_points(|points| {
+    ///     points.iter_mut().for_each(|x| *x = (*x + 5) / 10 * 10);
+    ///     Ok::<(), ()>(())
+    /// }).unwrap();
+    /// assert_eq!(mask, ordmask![0, 10, 20, 30]);
+    ///
+    /// let result = mask.edit_key_points(|points| {
+    ///     points.push(5);
+    ///     Ok::<(), ()>(())
+    /// });
+    /// assert_eq!(result, Err(EditError::Order(Error::Falling { index: 4 })));
+    /// assert_eq!(mask, ordmask![0, 10, 20, 30]);
+    /// ```
+    pub fn edit_key_points<E>(
+        &mut self,
+        f: impl FnOnce(&mut Vec<T>) -> Result<(), E>,
+    ) -> Result<(), EditError<E>> {
+        let original = self.key_points.clone();
+        let result = match f(&mut self.key_points) {
+            Err(error) => Err(EditError::Closure(error)),
+            Ok(()) => match super::convert::get_first_falling_index(&self.key_points) {
+                0 => Ok(()),
+                index => Err(EditError::Order(Error::Falling { index })),
+            },
+        };
+        match result {
+            Ok(()) => self.simplify(),
+            Err(_) => self.key_points = original,
+        }
+        result
+    }
+
+    /// Retain only the key points satisfying `f`.
+    ///
+    /// Removing a key point flips the inclusion of the values after it, like `remove_key_point`.
+    /// The retained key points are still sorted, so it can't fail. It takes O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![0, 5, 10, 15, 20];
+    /// mask.retain_key_points(|x| x % 10 == 0);
+    /// assert_eq!(mask, ordmask![0, 10, 20]);
+    /// ```
+    pub fn retain_key_points(&mut self, f: impl FnMut(&T) -> bool) {
+        self.key_points.retain(f);
+    }
+}
@@ -0,0 +1,284 @@
+use super::{MaxValue, MinValue, OrdMask, Predecessor, Successor};
+use std::ops::{Bound, RangeInclusive, RangeToInclusive};
+
+impl<T: Ord + Clone + MinValue> OrdMask<T> {
+    /// Fold a key point at `T::MIN_VALUE` into the flag of including the minimum value.
+    ///
+    /// There is no value below `T::MIN_VALUE`, so a key point at it only flips the state,
+    /// and the folded mask includes the same values.
+    /// Masks of bounded types are only comparable by `==` and `is_subset_of` after folding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mut mask = ordmask![0u8, 10];
+    /// assert_ne!(mask, ordmask![_, 10]);
+    /// mask.fold_min_value();
+    /// assert_eq!(mask, ordmask![_, 10]);
+    /// ```
+    pub fn fold_min_value(&mut self) {
+        if self.key_points.first() == Some(&T::MIN_VALUE) {
+            self.key_points.remove(0);
+            self.reversed = !self.reversed;
+        }
+    }
+
+    /// Get the mask normalized and folded, which is unique for each set of values of a bounded type,
+    /// cloning it only if it's not already.
+    #[cfg(any(feature = "io", feature = "packed"))]
+    pub(crate) fn normalized_folded(&self) -> std::borrow::Cow<'_, Self> {
+        let mut mask = self.normalized();
+        if mask.key_points.first() == Some(&T::MIN_VALUE) {
+            mask.to_mut().fold_min_value();
+        }
+        mask
+    }
+}
+
+impl<T: Ord + Clone + MaxValue + Successor> OrdMask<T> {
+    /// Close an included unbounded tail at `T::MAX_VALUE`.
+    ///
+    /// A key point can't be after `T::MAX_VALUE`, so the tail `[start, ∞)` is the only way
+    /// a mask includes the maximum value. This writes it as `[start, T::MAX_VALUE)`
+    /// followed by the one-value tail `[T::MAX_VALUE, ∞)`,
+    /// so every range below the maximum value ends at a key point,
+    /// like a range explicitly ending after `T::MAX_VALUE`.
+    /// The mask includes the same values, but the two key points at `T::MAX_VALUE` are duplicates,
+    /// so it's not strictly increasing and not equal to the open form by `==`.
+    /// It does nothing if the maximum value is excluded or the tail already starts at it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![10u8, 20, 250];
+    /// mask.close_tail_at_max();
+    /// assert_eq!(mask.key_points(), &[10, 20, 250, 255, 255]);
+    /// assert!(mask.included(&254) && mask.included(&255));
+    /// assert_eq!(mask.to_inclusive_ranges(), vec![(10, 19), (250, 255)]);
+    /// ```
+    pub fn close_tail_at_max(&mut self) {
+        if !self.is_include_max_value() {
+            return;
+        }
+        match self.key_points.last() {
+            Some(start) if start.next_value().is_none() => {}
+            _ => self.key_points.extend([T::MAX_VALUE, T::MAX_VALUE]),
+        }
+    }
+
+    /// Open a tail closed by `close_tail_at_max` back to the unbounded tail.
+    ///
+    /// It removes the trailing pairs of key points at `T::MAX_VALUE`,
+    /// which bound an empty range, so the mask includes the same values.
+    /// The result is strictly increasing if it was before `close_tail_at_max`,
+    /// and equals the mask built by the other methods for the same values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mut mask = ordmask![10u8, 20, 250];
+    /// mask.close_tail_at_max();
+    /// mask.open_tail_from_max();
+    /// assert_eq!(mask, ordmask![10, 20, 250]);
+    ///
+    /// let mut universal = OrdMask::<u8>::universal();
+    /// universal.close_tail_at_max();
+    /// assert_eq!(universal.key_points(), &[255, 255]);
+    /// universal.open_tail_from_max();
+    /// assert!(universal.is_universal());
+    /// ```
+    pub fn open_tail_from_max(&mut self) {
+        while let [.., before_last, last] = &self.key_points[..] {
+            if *before_last != T::MAX_VALUE || *last != T::MAX_VALUE {
+                break;
+            }
+            self.key_points.truncate(self.key_points.len() - 2);
+        }
+    }
+}
+
+impl<T: Ord + Clone + MinValue + MaxValue + Predecessor> OrdMask<T> {
+    /// Get the included ranges as inclusive `(first, last)` pairs.
+    ///
+    /// The unbounded start is closed at `T::MIN_VALUE`, and the unbounded end is closed at `T::MAX_VALUE`,
+    /// so it works for any mask over a bounded discrete type, including masks that include the maximum value.
+    /// It accepts the tail either open or closed by `close_tail_at_max`,
+    /// since the touching ranges are merged, and returns the same pairs for both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![_, 10u8, 20, 250];
+    /// assert_eq!(mask.to_inclusive_ranges(), vec![(0, 9), (20, 249)]);
+    ///
+    /// let mask = ordmask![10u8, 20, 250];
+    /// assert_eq!(mask.to_inclusive_ranges(), vec![(10, 19), (250, 255)]);
+    /// ```
+    pub fn to_inclusive_ranges(&self) -> Vec<(T, T)> {
+        self.normalized()
+            .ranges()
+            .filter_map(|(start, end)| {
+                let first = match start {
+                    Bound::Included(start) => start.clone(),
+                    _ => T::MIN_VALUE,
+                };
+                let last = match end {
+                    Bound::Excluded(end) => end.prev_value()?,
+                    _ => T::MAX_VALUE,
+                };
+                Some((first, last))
+            })
+            .collect()
+    }
+}
+
+impl<T: Ord + Clone + Successor> OrdMask<T> {
+    /// Create a new OrdMask that includes all values in the inclusive range `[first, last]`.
+    ///
+    /// It's empty if `first > last`.
+    /// A range ending at the maximum value becomes the open unbounded tail, like `from_inclusive_ranges`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert_eq!(OrdMask::in_range_inclusive(1, 9), ordmask![1, 10]);
+    /// assert_eq!(OrdMask::in_range_inclusive(1u8, 255), ordmask![1]);
+    /// ```
+    pub fn in_range_inclusive(first: T, last: T) -> Self {
+        Self::from_inclusive_ranges([(first, last)])
+    }
+
+    /// Create a new OrdMask that includes only `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert_eq!(OrdMask::point(5), ordmask![5, 6]);
+    /// assert_eq!(OrdMask::point(255u8), ordmask![255]);
+    /// ```
+    pub fn point(value: T) -> Self {
+        match value.next_value() {
+            Some(end) => Self::in_range(value, end),
+            None => Self::not_less_than(value),
+        }
+    }
+
+    /// Create a new OrdMask that includes all values greater than `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert_eq!(OrdMask::greater_than(5), ordmask![6]);
+    /// assert_eq!(OrdMask::greater_than(255u8), ordmask![]);
+    /// ```
+    pub fn greater_than(value: T) -> Self {
+        match value.next_value() {
+            Some(start) => Self::not_less_than(start),
+            None => Self::empty(),
+        }
+    }
+
+    /// Create a new OrdMask that includes all values in any of the inclusive `ranges`.
+    ///
+    /// Each range `(first, last)` means `[first, last]`, and the ranges with `first > last` are ignored.
+    /// A range whose `last` is the maximum value is converted to the open unbounded tail,
+    /// so the result is strictly increasing; call `close_tail_at_max` to close it.
+    /// The maximum value can be given either as the end of a wider range like `(250, 255)`
+    /// or as its own range like `(250, 254), (255, 255)`, which build the same mask.
+    ///
+    /// It's the inverse of `to_inclusive_ranges`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask = OrdMask::from_inclusive_ranges([(10u8, 19), (250, 255)]);
+    /// assert_eq!(mask, ordmask![10, 20, 250]);
+    /// assert!(mask.included(&255));
+    /// ```
+    pub fn from_inclusive_ranges(ranges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut tail_start: Option<T> = None;
+        let mut bounded = Vec::new();
+        for (first, last) in ranges {
+            if first > last {
+                continue;
+            }
+            match last.next_value() {
+                Some(end) => bounded.push((first, end)),
+                None => match &tail_start {
+                    Some(start) if *start <= first => {}
+                    _ => tail_start = Some(first),
+                },
+            }
+        }
+        let mask = Self::from_ranges(bounded);
+        match tail_start {
+            Some(start) => mask | Self::not_less_than(start),
+            None => mask,
+        }
+    }
+}
+
+/// Convert `start..=end` to the mask of `[start, end]`, like `in_range_inclusive`.
+///
+/// An empty or exhausted range converts to the empty mask,
+/// and a range ending at the maximum value converts to the unbounded tail.
+/// `OrdMask::from` resolves to the inherent constructor from key points, so use `into` instead.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{OrdMask, ordmask};
+///
+/// let mask: OrdMask<u8> = (10..=19).into();
+/// assert_eq!(mask, ordmask![10, 20]);
+/// let mask: OrdMask<u8> = (10..=255).into();
+/// assert_eq!(mask, ordmask![10]);
+/// ```
+impl<T: Ord + Clone + Successor> From<RangeInclusive<T>> for OrdMask<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        if range.is_empty() {
+            return Self::empty();
+        }
+        let (first, last) = range.into_inner();
+        Self::in_range_inclusive(first, last)
+    }
+}
+
+/// Convert `..=end` to the mask of all values not greater than `end`.
+///
+/// It converts to the universal mask if `end` is the maximum value.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{OrdMask, ordmask};
+///
+/// let mask: OrdMask<u8> = (..=9).into();
+/// assert_eq!(mask, ordmask![_, 10]);
+/// let mask: OrdMask<u8> = (..=255).into();
+/// assert_eq!(mask, OrdMask::universal());
+/// ```
+impl<T: Ord + Clone + Successor> From<RangeToInclusive<T>> for OrdMask<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        match range.end.next_value() {
+            Some(end) => Self::less_than(end),
+            None => Self::universal(),
+        }
+    }
+}
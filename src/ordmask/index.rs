@@ -0,0 +1,107 @@
+use super::OrdMask;
+use std::ops::Bound;
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Translate the mask into an index mask against a sorted column of keys.
+    ///
+    /// The index `i` is included in the result if and only if `sorted_keys[i]` is included
+    /// in `self`. Each key point is binary-searched into `sorted_keys`,
+    /// so it takes O(k log n) where k is the number of key points.
+    ///
+    /// Indices out of `0..sorted_keys.len()` carry no meaning.
+    ///
+    /// `sorted_keys` must be non-decreasing, otherwise the result is meaningless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![10, 20, 30];
+    /// let column = [5, 10, 10, 15, 20, 25, 30, 30];
+    /// let index_mask = mask.to_index_mask(&column);
+    /// assert_eq!(index_mask, ordmask![1, 4, 6]);
+    ///
+    /// for (i, key) in column.iter().enumerate() {
+    ///     assert_eq!(index_mask.included(&i), mask.included(key));
+    /// }
+    /// ```
+    pub fn to_index_mask(&self, sorted_keys: &[T]) -> OrdMask<usize> {
+        let mut result = OrdMask {
+            key_points: self
+                .key_points
+                .iter()
+                .map(|point| sorted_keys.partition_point(|x| x < point))
+                .collect(),
+            reversed: self.reversed,
+        };
+        result.simplify();
+        result
+    }
+}
+
+impl OrdMask<usize> {
+    /// Get the included index ranges `[start, end)` clamped to `0..len`, skipping empty ones.
+    fn clamped_index_ranges(&self, len: usize) -> Vec<(usize, usize)> {
+        self.ranges()
+            .map(|(start, end)| {
+                let start = match start {
+                    Bound::Included(start) => (*start).min(len),
+                    _ => 0,
+                };
+                let end = match end {
+                    Bound::Excluded(end) => (*end).min(len),
+                    _ => len,
+                };
+                (start, end)
+            })
+            .filter(|(start, end)| start < end)
+            .collect()
+    }
+
+    /// Get an iterator over the elements of `slice` at the included indices.
+    ///
+    /// The elements are yielded by sub-slice per included range, which are clamped to the length of `slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let letters = ['a', 'b', 'c', 'd', 'e', 'f'];
+    /// let selected: String = ordmask![1, 3, 4].select(&letters).collect();
+    /// assert_eq!(selected, "bcef");
+    /// ```
+    pub fn select<'a, E>(&self, slice: &'a [E]) -> impl Iterator<Item = &'a E> {
+        self.clamped_index_ranges(slice.len())
+            .into_iter()
+            .flat_map(move |(start, end)| &slice[start..end])
+    }
+
+    /// Remove the elements at the included indices from `vec`, and return them in order.
+    ///
+    /// The order of the remaining elements is preserved.
+    /// The included ranges are clamped to the length of `vec`,
+    /// and drained from back to front so the indices stay valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut letters = vec!['a', 'b', 'c', 'd', 'e', 'f'];
+    /// let drained = ordmask![1, 3, 4].drain_from(&mut letters);
+    /// assert_eq!(drained, vec!['b', 'c', 'e', 'f']);
+    /// assert_eq!(letters, vec!['a', 'd']);
+    /// ```
+    pub fn drain_from<E>(&self, vec: &mut Vec<E>) -> Vec<E> {
+        let mut chunks: Vec<Vec<E>> = self
+            .clamped_index_ranges(vec.len())
+            .into_iter()
+            .rev()
+            .map(|(start, end)| vec.drain(start..end).collect())
+            .collect();
+        chunks.reverse();
+        chunks.into_iter().flatten().collect()
+    }
+}
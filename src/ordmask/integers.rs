@@ -0,0 +1,109 @@
+use super::OrdMask;
+use std::iter::FusedIterator;
+
+/// An iterator over the included integers of an `OrdMask<i64>` within a window.
+///
+/// It is created by [`OrdMask::enumerate_covered_integers_in_range`].
+#[derive(Clone, Debug)]
+pub struct CoveredIntegers<'a> {
+    key_points: &'a [i64],
+    next: i64,
+    end: i64,
+    included: bool,
+    remaining: u64,
+}
+
+impl CoveredIntegers<'_> {
+    /// Get the number of integers not yet yielded.
+    ///
+    /// It's a `u64` since the count in a window of `i64` may exceed `usize::MAX`.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+}
+
+impl Iterator for CoveredIntegers<'_> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        while self.next < self.end {
+            if let Some((&point, rest)) = self.key_points.split_first() {
+                if point <= self.next {
+                    self.key_points = rest;
+                    self.included = !self.included;
+                    continue;
+                }
+            }
+            if self.included {
+                let value = self.next;
+                self.next += 1;
+                self.remaining -= 1;
+                return Some(value);
+            }
+            self.next = self.key_points.first().map_or(self.end, |point| *point);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for CoveredIntegers<'_> {}
+
+impl OrdMask<i64> {
+    /// Get an iterator over the included integers in `[start, end)` in ascending order.
+    ///
+    /// The key points in the window are found by binary search and swept together with the values,
+    /// so the excluded gaps are skipped at once,
+    /// and it takes O(log n + k + m) where k is the number of key points in the window
+    /// and m is the number of included integers.
+    /// The count of included integers is computed when the iterator is created,
+    /// so `remaining` is exact, and so is `size_hint` unless the count exceeds `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![_, 0, 10, 12, 20];
+    /// let covered = mask.enumerate_covered_integers_in_range(-2, 15);
+    /// assert_eq!(covered.remaining(), 4);
+    /// assert_eq!(covered.collect::<Vec<_>>(), vec![-2, -1, 10, 11]);
+    /// ```
+    pub fn enumerate_covered_integers_in_range(&self, start: i64, end: i64) -> CoveredIntegers<'_> {
+        if start >= end {
+            return CoveredIntegers {
+                key_points: &[],
+                next: start,
+                end: start,
+                included: false,
+                remaining: 0,
+            };
+        }
+        let lo = self.key_points.partition_point(|x| *x <= start);
+        let hi = self.key_points.partition_point(|x| *x < end);
+        let key_points = &self.key_points[lo..hi];
+        let included = self.reversed ^ (lo % 2 == 1);
+
+        let mut remaining = 0;
+        let (mut from, mut state) = (start, included);
+        for point in key_points.iter().chain([&end]) {
+            if state {
+                remaining += point.abs_diff(from);
+            }
+            (from, state) = (*point, !state);
+        }
+        CoveredIntegers {
+            key_points,
+            next: start,
+            end,
+            included,
+            remaining,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::{Error, OrdMask};
+
+/// The error returned by [`OrdMask::<char>::from_char_inversion_list`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InversionListError {
+    /// The code point at `index` is greater than `0x110000`, the end of the code space.
+    OutOfRange { index: usize },
+    /// The code points are not strictly increasing.
+    Order(Error),
+}
+
+impl std::fmt::Display for InversionListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfRange { index } => write!(
+                f,
+                "The code point at index {} is out of the range of Unicode.",
+                index
+            ),
+            Self::Order(error) => std::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for InversionListError {}
+
+impl OrdMask<u32> {
+    /// Create an `OrdMask` from an inversion list, as used by Unicode tooling like ICU.
+    ///
+    /// An inversion list is a strictly increasing list of values,
+    /// where the ranges start at even indices and end at odd indices,
+    /// which is exactly the key points of a mask not including the minimum value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::OrdMask;
+    ///
+    /// // ASCII alphabetic
+    /// let mask = OrdMask::from_inversion_list(&[0x41, 0x5B, 0x61, 0x7B]).unwrap();
+    /// assert!(mask.included(&0x41) && mask.included(&0x7A));
+    /// assert!(mask.excluded(&0x5B) && mask.excluded(&0x7B));
+    /// ```
+    pub fn from_inversion_list(list: &[u32]) -> Result<Self, Error> {
+        Self::from_sorted_key_points_checking_strict(list.to_vec(), false)
+    }
+
+    /// Get the inversion list of the mask, as used by Unicode tooling like ICU.
+    ///
+    /// A mask including the minimum value starts with the range from `0`,
+    /// since `0` is the minimum value of `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert_eq!(ordmask![0x41u32, 0x5B].to_inversion_list(), vec![0x41, 0x5B]);
+    /// assert_eq!(ordmask![_, 0x41u32].to_inversion_list(), vec![0, 0x41]);
+    /// ```
+    pub fn to_inversion_list(&self) -> Vec<u32> {
+        let mut mask = self.clone();
+        mask.fold_min_value();
+        match mask.reversed {
+            true => [&[0][..], &mask.key_points].concat(),
+            false => mask.key_points,
+        }
+    }
+}
+
+impl OrdMask<char> {
+    /// Create an `OrdMask` from an inversion list of code points, as used by Unicode tooling like ICU.
+    ///
+    /// Code points in the surrogate gap `0xD800..0xE000` are not `char`s,
+    /// so a boundary in the gap is moved to `0xE000`, the next `char`.
+    /// A boundary at `0x110000`, the end of the code space, is dropped,
+    /// and the range before it extends to `char::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::OrdMask;
+    ///
+    /// let mask = OrdMask::from_char_inversion_list(&[0x41, 0x5B, 0xD000, 0xD900]).unwrap();
+    /// assert!(mask.included(&'A') && mask.included(&'Z'));
+    /// assert!(mask.excluded(&'['));
+    /// assert!(mask.included(&'\u{D7FF}') && mask.excluded(&'\u{E000}'));
+    ///
+    /// let mask = OrdMask::from_char_inversion_list(&[0x10000, 0x110000]).unwrap();
+    /// assert!(mask.included(&char::MAX));
+    /// ```
+    pub fn from_char_inversion_list(list: &[u32]) -> Result<Self, InversionListError> {
+        let mut key_points = Vec::with_capacity(list.len());
+        for (index, &code_point) in list.iter().enumerate() {
+            if index > 0 && list[index - 1] >= code_point {
+                return Err(InversionListError::Order(
+                    match list[index - 1] == code_point {
+                        true => Error::DuplicateKeyPoint { index },
+                        false => Error::Falling { index },
+                    },
+                ));
+            }
+            match code_point {
+                0..=0xD7FF | 0xE000..=0x10FFFF => {
+                    key_points.push(char::from_u32(code_point).unwrap())
+                }
+                0xD800..=0xDFFF => key_points.push('\u{E000}'),
+                0x110000 => {}
+                _ => return Err(InversionListError::OutOfRange { index }),
+            }
+        }
+        let mut mask = Self {
+            key_points,
+            reversed: false,
+        };
+        mask.simplify();
+        Ok(mask)
+    }
+
+    /// Get the inversion list of code points of the mask, as used by Unicode tooling like ICU.
+    ///
+    /// A mask including the minimum value starts with the range from `0`.
+    /// The surrogate gap is never a boundary, since there is no `char` in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert_eq!(ordmask!['A', '['].to_char_inversion_list(), vec![0x41, 0x5B]);
+    /// assert_eq!(ordmask![_, 'A'].to_char_inversion_list(), vec![0, 0x41]);
+    /// ```
+    pub fn to_char_inversion_list(&self) -> Vec<u32> {
+        let key_points = self.key_points.iter().map(|&c| c as u32);
+        match (self.reversed, self.key_points.first()) {
+            (true, Some('\0')) => key_points.skip(1).collect(),
+            (true, _) => std::iter::once(0).chain(key_points).collect(),
+            (false, _) => key_points.collect(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use super::OrdMask;
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Get the values of all `(mask, value)` pairs whose mask includes `value`, in the original order.
+    ///
+    /// It's useful for rule engines, where each rule has an applicability mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let rules = [
+    ///     (ordmask![0, 18], "minor"),
+    ///     (ordmask![18], "adult"),
+    ///     (ordmask![65], "senior"),
+    /// ];
+    /// assert_eq!(OrdMask::apply_mask_union(&rules, &70), vec![&"adult", &"senior"]);
+    /// assert_eq!(OrdMask::apply_mask_union(&rules, &-1), Vec::<&&str>::new());
+    /// ```
+    pub fn apply_mask_union<'a, V>(masks: &'a [(OrdMask<T>, V)], value: &T) -> Vec<&'a V> {
+        masks
+            .iter()
+            .filter(|(mask, _)| mask.included(value))
+            .map(|(_, v)| v)
+            .collect()
+    }
+}
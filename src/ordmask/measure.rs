@@ -0,0 +1,68 @@
+use super::OrdMask;
+use std::ops::{Add, Sub};
+
+impl<T, D> OrdMask<T>
+where
+    T: Ord + Clone + Sub<Output = D>,
+    D: Add<Output = D> + Default,
+{
+    /// Sum the lengths of the regions in `[start, end)` whose inclusion equals `included`.
+    fn length_in_range(&self, start: &T, end: &T, included: bool) -> D {
+        let mut total = D::default();
+        if start >= end {
+            return total;
+        }
+        let lo = self.key_points.partition_point(|x| x <= start);
+        let hi = self.key_points.partition_point(|x| x < end);
+        let mut state = self.reversed ^ (lo % 2 == 1);
+        let mut prev = start;
+        for point in &self.key_points[lo..hi] {
+            if state == included {
+                total = total + (point.clone() - prev.clone());
+            }
+            state = !state;
+            prev = point;
+        }
+        if state == included {
+            total = total + (end.clone() - prev.clone());
+        }
+        total
+    }
+
+    /// Get the total length of the included values in `[start, end)`.
+    ///
+    /// The length of a range `[a, b)` is `b - a`, and `D::default()` is used as zero.
+    /// It takes O(log n + k) where k is the number of key points in the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30];
+    /// assert_eq!(mask.measure_in_range(&5, &25), 10);
+    /// assert_eq!(mask.measure_in_range(&-100, &100), 20);
+    /// ```
+    pub fn measure_in_range(&self, start: &T, end: &T) -> D {
+        self.length_in_range(start, end, true)
+    }
+
+    /// Get the total length of the excluded values in `[start, end)`.
+    ///
+    /// It's the measure of the complement within the window,
+    /// so it equals `(end - start) - self.measure_in_range(start, end)` if `start < end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// // dead time in a recording
+    /// let recording = ordmask![0, 10, 20, 30];
+    /// assert_eq!(recording.total_excluded_length_in_range(&5, &25), 10);
+    /// assert_eq!(recording.total_excluded_length_in_range(&-100, &100), 180);
+    /// ```
+    pub fn total_excluded_length_in_range(&self, start: &T, end: &T) -> D {
+        self.length_in_range(start, end, false)
+    }
+}
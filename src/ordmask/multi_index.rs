@@ -0,0 +1,88 @@
+use super::OrdMask;
+use std::ops::Bound;
+
+/// An index answering which of many masks include a value, in O(log n) per query.
+///
+/// All key points of the masks are flattened into sorted boundaries,
+/// which split the domain into elementary regions,
+/// and the Ids of the masks including each region are stored contiguously.
+///
+/// Building it takes O(n log n + r) time and O(n + r) memory, where n is the total number of key points
+/// and r is the sum over regions of the number of matching masks, which is O(n * m) in the worst case for m masks.
+/// It's immutable, so rebuild it to reflect changes of the masks.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{MultiMaskIndex, ordmask};
+///
+/// let alice = ordmask![0, 10];
+/// let bob = ordmask![5, 20];
+/// let carol = ordmask![_, 3];
+/// let index = MultiMaskIndex::new([("alice", &alice), ("bob", &bob), ("carol", &carol)]);
+/// assert_eq!(index.matching(&7), &["alice", "bob"]);
+/// assert_eq!(index.matching(&-1), &["carol"]);
+/// assert!(index.matching(&20).is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiMaskIndex<T, Id> {
+    boundaries: Vec<T>,
+    offsets: Vec<usize>,
+    ids: Vec<Id>,
+}
+
+impl<T: Ord + Clone, Id: Clone> MultiMaskIndex<T, Id> {
+    /// Build the index from `(Id, mask)` pairs.
+    ///
+    /// The Ids matching a value are returned in the order of the pairs.
+    pub fn new<'a>(masks: impl IntoIterator<Item = (Id, &'a OrdMask<T>)>) -> Self
+    where
+        T: 'a,
+    {
+        let masks: Vec<_> = masks.into_iter().collect();
+        let mut boundaries: Vec<T> = masks
+            .iter()
+            .flat_map(|(_, mask)| mask.key_points.iter().cloned())
+            .collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        // The region `0` is below the first boundary, and the region `i + 1` starts at the boundary `i`
+        let region_of = |point: &T| boundaries.partition_point(|x| x <= point);
+        let mut regions: Vec<Vec<Id>> = vec![Vec::new(); boundaries.len() + 1];
+        for (id, mask) in &masks {
+            for (start, end) in mask.ranges() {
+                let start = match start {
+                    Bound::Included(start) => region_of(start),
+                    _ => 0,
+                };
+                let end = match end {
+                    Bound::Excluded(end) => region_of(end),
+                    _ => regions.len(),
+                };
+                for region in &mut regions[start..end] {
+                    region.push(id.clone());
+                }
+            }
+        }
+
+        let mut offsets = Vec::with_capacity(regions.len() + 1);
+        offsets.push(0);
+        let mut ids = Vec::with_capacity(regions.iter().map(Vec::len).sum());
+        for region in regions {
+            ids.extend(region);
+            offsets.push(ids.len());
+        }
+        Self {
+            boundaries,
+            offsets,
+            ids,
+        }
+    }
+
+    /// Get the Ids of the masks including `value`. It takes O(log n).
+    pub fn matching(&self, value: &T) -> &[Id] {
+        let region = self.boundaries.partition_point(|x| x <= value);
+        &self.ids[self.offsets[region]..self.offsets[region + 1]]
+    }
+}
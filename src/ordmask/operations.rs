@@ -1,4 +1,8 @@
+use super::window::{half_open_bounds, RangeBoundError};
 use super::OrdMask;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::RangeBounds;
 
 impl<T: Ord + Clone> OrdMask<T> {
     /// Create a new OrdMask representing the union of the `masks`.
@@ -17,6 +21,7 @@ impl<T: Ord + Clone> OrdMask<T> {
     /// assert_eq!(union, ordmask![0, 10, 20, 30]);
     /// ```
     pub fn union(masks: &[&OrdMask<T>]) -> Self {
+        debug_assert!(masks.iter().all(|mask| mask.consistency_check().is_ok()));
         Self::from_key_points_set(
             Self::get_key_points_set(masks),
             |x| masks.iter().any(|item| item.included(x)),
@@ -24,6 +29,116 @@ impl<T: Ord + Clone> OrdMask<T> {
         )
     }
 
+    /// Create a new OrdMask representing the union of the `masks`,
+    /// pre-allocating `initial_capacity` key points for the result.
+    ///
+    /// The result is the same as `union`, but it's computed by a k-way merge
+    /// over the key points instead of collecting them into a `BTreeSet`,
+    /// which takes O(N log k) where N is the total number of key points and k is the number of masks.
+    /// It reduces reallocations if the caller knows the approximate size of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask1 = ordmask![0, 6];
+    /// let mask2 = ordmask![5, 10];
+    /// let mask3 = ordmask![20, 30];
+    /// let union = OrdMask::merge_with_capacity_growth(&[&mask1, &mask2, &mask3], 4);
+    /// assert_eq!(union, ordmask![0, 10, 20, 30]);
+    /// ```
+    pub fn merge_with_capacity_growth(masks: &[&OrdMask<T>], initial_capacity: usize) -> Self {
+        Self::merge_union(masks, initial_capacity, usize::MAX)
+    }
+
+    /// Create a new OrdMask approximating the union of the `masks` with at most `max_key_points` key points.
+    ///
+    /// The union is computed by the k-way merge of `merge_with_capacity_growth`,
+    /// which stops once the union needs more than `max_key_points` key points,
+    /// so it takes O(M log k) where M is the number of key points merged before stopping.
+    /// Then the ranges after the last kept key point are covered to infinity:
+    /// if the last kept key point ends a range, it's dropped so that range extends to infinity.
+    /// So the result is a superset of the union, equal to it if it fits in `max_key_points`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask1 = ordmask![0, 10, 20, 30];
+    /// let mask2 = ordmask![40, 50];
+    /// let masks = [&mask1, &mask2];
+    /// assert_eq!(OrdMask::union_with_key_point_limit(&masks, 6), ordmask![0, 10, 20, 30, 40, 50]);
+    /// assert_eq!(OrdMask::union_with_key_point_limit(&masks, 5), ordmask![0, 10, 20, 30, 40]);
+    /// assert_eq!(OrdMask::union_with_key_point_limit(&masks, 4), ordmask![0, 10, 20]);
+    /// assert_eq!(OrdMask::union_with_key_point_limit(&masks, 0), ordmask![_]);
+    /// ```
+    pub fn union_with_key_point_limit(masks: &[&OrdMask<T>], max_key_points: usize) -> Self {
+        let total = masks
+            .iter()
+            .map(|mask| mask.key_points.len())
+            .sum::<usize>();
+        let capacity = total.min(max_key_points.saturating_add(1));
+        let mut result = Self::merge_union(masks, capacity, max_key_points);
+        if result.key_points.len() > max_key_points {
+            result.key_points.truncate(max_key_points);
+            if result.reversed == max_key_points.is_multiple_of(2) {
+                return result;
+            }
+            match result.key_points.pop() {
+                Some(_) => {}
+                None => result.reversed = true,
+            }
+        }
+        result
+    }
+
+    /// Merge the `masks` into their union by a k-way merge,
+    /// stopping once it has more than `max_key_points` key points.
+    fn merge_union(masks: &[&OrdMask<T>], initial_capacity: usize, max_key_points: usize) -> Self {
+        let mut covered = masks.iter().filter(|mask| mask.reversed).count();
+        let reversed = covered > 0;
+        let mut positions = vec![0usize; masks.len()];
+        let mut heap = BinaryHeap::with_capacity(masks.len());
+        for (i, mask) in masks.iter().enumerate() {
+            if let Some(point) = mask.key_points.first() {
+                heap.push(Reverse((point, i)));
+            }
+        }
+
+        let mut key_points = Vec::with_capacity(initial_capacity);
+        while let Some(&Reverse((point, _))) = heap.peek() {
+            let was_covered = covered > 0;
+            while let Some(&Reverse((next, i))) = heap.peek() {
+                if next != point {
+                    break;
+                }
+                heap.pop();
+                let mask = masks[i];
+                let index = positions[i];
+                positions[i] += 1;
+                match mask.reversed ^ index.is_multiple_of(2) {
+                    true => covered += 1,
+                    false => covered -= 1,
+                }
+                if let Some(next) = mask.key_points.get(index + 1) {
+                    heap.push(Reverse((next, i)));
+                }
+            }
+            if was_covered != (covered > 0) {
+                if key_points.len() > max_key_points {
+                    break;
+                }
+                key_points.push(point.clone());
+            }
+        }
+        Self {
+            key_points,
+            reversed,
+        }
+    }
+
     /// Create a new OrdMask representing the intersection of the `masks`.
     ///
     /// Values included in the intersection must be included in all of the `masks`.
@@ -40,6 +155,7 @@ impl<T: Ord + Clone> OrdMask<T> {
     /// assert_eq!(intersection, ordmask![5, 6]);
     /// ```
     pub fn intersection(masks: &[&OrdMask<T>]) -> Self {
+        debug_assert!(masks.iter().all(|mask| mask.consistency_check().is_ok()));
         Self::from_key_points_set(
             Self::get_key_points_set(masks),
             |x| masks.iter().all(|item| item.included(x)),
@@ -47,6 +163,238 @@ impl<T: Ord + Clone> OrdMask<T> {
         )
     }
 
+    /// Create a new OrdMask representing the union of the complements of the `masks`.
+    ///
+    /// Values included in the result must be excluded in at least one of the `masks`.
+    ///
+    /// By De Morgan's law, `!A | !B | ...` equals `!(A & B & ...)`,
+    /// so it takes the intersection of the `masks` and complements the result in O(1),
+    /// instead of complementing each mask before the union.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask1 = ordmask![0, 10];
+    /// let mask2 = ordmask![5, 20];
+    /// let result = OrdMask::union_of_complements(&[&mask1, &mask2]);
+    /// assert_eq!(result, ordmask![_, 5, 10]);
+    /// assert_eq!(result, &mask1.new_complement() | &mask2.new_complement());
+    /// ```
+    pub fn union_of_complements(masks: &[&OrdMask<T>]) -> Self {
+        Self::intersection(masks).complement()
+    }
+
+    /// Check if the union of the `masks` covers the whole `range`.
+    ///
+    /// It's the same as `OrdMask::uncovered_within(masks, range).is_empty()`.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the start of `range` is excluded or the end of `range` is included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let shard1 = ordmask![_, 100];
+    /// let shard2 = ordmask![100, 200];
+    /// let shard3 = ordmask![200];
+    /// assert_eq!(OrdMask::cover_range(&[&shard1, &shard2, &shard3], ..), Ok(true));
+    /// assert_eq!(OrdMask::cover_range(&[&shard2, &shard3], 100..), Ok(true));
+    /// assert_eq!(OrdMask::cover_range(&[&shard2, &shard3], 99..), Ok(false));
+    /// ```
+    pub fn cover_range(
+        masks: &[&OrdMask<T>],
+        range: impl RangeBounds<T>,
+    ) -> Result<bool, RangeBoundError> {
+        Ok(Self::uncovered_within(masks, range)?.is_empty())
+    }
+
+    /// Create a new OrdMask representing the values in `range` excluded by all of the `masks`.
+    ///
+    /// It sweeps over the key points of the `masks` within `range` once,
+    /// without materializing the union of the `masks`.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the start of `range` is excluded or the end of `range` is included,
+    /// since the result can't represent them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, RangeBoundError, ordmask};
+    ///
+    /// let shard1 = ordmask![0, 5];
+    /// let shard2 = ordmask![9, 20];
+    /// let uncovered = OrdMask::uncovered_within(&[&shard1, &shard2], 0..30);
+    /// assert_eq!(uncovered, Ok(ordmask![5, 9, 20, 30]));
+    /// assert_eq!(
+    ///     OrdMask::uncovered_within(&[&shard1, &shard2], 0..=30),
+    ///     Err(RangeBoundError::IncludedEnd)
+    /// );
+    /// ```
+    pub fn uncovered_within(
+        masks: &[&OrdMask<T>],
+        range: impl RangeBounds<T>,
+    ) -> Result<Self, RangeBoundError> {
+        let (start, end) = half_open_bounds(&range)?;
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                return Ok(Self::empty());
+            }
+        }
+
+        let mut covered = 0;
+        let mut events = Vec::new();
+        for mask in masks {
+            let key_points = &mask.key_points;
+            let lo = start.map_or(0, |start| key_points.partition_point(|x| x <= start));
+            let hi = end.map_or(key_points.len(), |end| {
+                key_points.partition_point(|x| x < end)
+            });
+            if mask.reversed ^ (lo % 2 == 1) {
+                covered += 1;
+            }
+            events.extend((lo..hi).map(|i| (&key_points[i], mask.reversed ^ (i % 2 == 0))));
+        }
+        events.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        let reversed = start.is_none() && covered == 0;
+        let mut key_points = Vec::new();
+        if let Some(start) = start {
+            if covered == 0 {
+                key_points.push(start.clone());
+            }
+        }
+        let mut events = events.into_iter().peekable();
+        while let Some((point, becomes_included)) = events.next() {
+            let was_uncovered = covered == 0;
+            match becomes_included {
+                true => covered += 1,
+                false => covered -= 1,
+            }
+            while let Some((_, becomes_included)) = events.next_if(|(next, _)| *next == point) {
+                match becomes_included {
+                    true => covered += 1,
+                    false => covered -= 1,
+                }
+            }
+            if was_uncovered != (covered == 0) {
+                key_points.push(point.clone());
+            }
+        }
+        if let Some(end) = end {
+            if covered == 0 {
+                key_points.push(end.clone());
+            }
+        }
+        Ok(Self {
+            key_points,
+            reversed,
+        })
+    }
+
+    /// Sweep over the key points of `masks` once,
+    /// keeping the points where the result of `op` on the states of the `masks` changes.
+    ///
+    /// It takes O(N * (n_1 + ... + n_N)), so it's meant for a small fixed `N`.
+    pub(crate) fn combine<const N: usize>(
+        masks: [&OrdMask<T>; N],
+        op: impl Fn([bool; N]) -> bool,
+    ) -> Self {
+        let mut states = masks.map(|mask| mask.reversed);
+        let mut positions = [0; N];
+        let reversed = op(states);
+        let mut state = reversed;
+        let mut key_points = Vec::new();
+        while let Some(point) = (0..N)
+            .filter_map(|i| masks[i].key_points.get(positions[i]))
+            .min()
+        {
+            for i in 0..N {
+                if masks[i].key_points.get(positions[i]) == Some(point) {
+                    positions[i] += 1;
+                    states[i] = !states[i];
+                }
+            }
+            if op(states) != state {
+                state = !state;
+                key_points.push(point.clone());
+            }
+        }
+        Self {
+            key_points,
+            reversed,
+        }
+    }
+
+    /// Create a new OrdMask by overlaying `override_values` on `self` within `override_domain`.
+    ///
+    /// Values in `override_domain` are included if they are included in `override_values`,
+    /// and other values are included if they are included in `self`.
+    ///
+    /// It's the same as `(override_values & override_domain) | (self - override_domain)`,
+    /// but computed in a single sweep without intermediate masks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let default = ordmask![0, 100];
+    /// let override_values = ordmask![40, 60];
+    /// let override_domain = ordmask![30, 70];
+    /// let mask = default.overlay(&override_values, &override_domain);
+    /// assert_eq!(mask, ordmask![0, 30, 40, 60, 70, 100]);
+    /// ```
+    pub fn overlay(&self, override_values: &Self, override_domain: &Self) -> Self {
+        Self::combine(
+            [self, override_values, override_domain],
+            |[default, value, in_domain]| match in_domain {
+                true => value,
+                false => default,
+            },
+        )
+    }
+
+    /// Create a new OrdMask that follows `lower` below `boundary` and `upper` at or above `boundary`.
+    ///
+    /// It's the same as `(lower & OrdMask::less_than(boundary)) | (upper & OrdMask::not_less_than(boundary))`,
+    /// but the key points are concatenated directly in O(n + m) without sorting or intermediate masks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let weekdays = ordmask![9, 18, 33, 42];
+    /// let weekend = ordmask![_, 20, 30];
+    /// let mask = OrdMask::concatenate_at(24, weekdays, weekend);
+    /// assert_eq!(mask, ordmask![9, 18, 30]);
+    /// ```
+    pub fn concatenate_at(boundary: T, lower: Self, upper: Self) -> Self {
+        let lower_len = lower.key_points.partition_point(|x| *x < boundary);
+        let upper_start = upper.key_points.partition_point(|x| *x <= boundary);
+        let lower_state = lower.reversed ^ (lower_len % 2 == 1);
+        let upper_state = upper.reversed ^ (upper_start % 2 == 1);
+
+        let mut key_points = lower.key_points;
+        key_points.truncate(lower_len);
+        key_points.reserve(upper.key_points.len() - upper_start + 1);
+        if lower_state != upper_state {
+            key_points.push(boundary);
+        }
+        key_points.extend(upper.key_points.into_iter().skip(upper_start));
+        Self {
+            key_points,
+            reversed: lower.reversed,
+        }
+    }
+
     /// Create a new OrdMask representing the difference of the `self` and `others`.
     ///
     /// Values included in the difference must be included in `self`
@@ -64,6 +412,8 @@ impl<T: Ord + Clone> OrdMask<T> {
     /// assert_eq!(complement, ordmask![0, 5, 20, 30]);
     /// ```
     pub fn minus(&self, others: &[&OrdMask<T>]) -> Self {
+        debug_assert!(self.consistency_check().is_ok());
+        debug_assert!(others.iter().all(|mask| mask.consistency_check().is_ok()));
         Self::from_key_points_set(
             Self::get_key_points_set(&[&[self], others].concat()),
             |x| self.included(x) && others.iter().all(|item| item.excluded(x)),
@@ -71,6 +421,94 @@ impl<T: Ord + Clone> OrdMask<T> {
         )
     }
 
+    /// Create a new OrdMask representing the union of `self` and `other`, which must be disjoint.
+    ///
+    /// The key points are merged in O(n + m) without tracking the states,
+    /// and a key point in both masks, where a range of one ends and a range of the other starts, is dropped.
+    ///
+    /// # Safety
+    ///
+    /// No value may be included in both `self` and `other`, otherwise the result is meaningless.
+    /// It's checked by a debug assertion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let union = unsafe { ordmask![0, 10, 30, 40].union_assuming_disjoint(ordmask![10, 20]) };
+    /// assert_eq!(union, ordmask![0, 20, 30, 40]);
+    /// ```
+    pub unsafe fn union_assuming_disjoint(self, other: Self) -> Self {
+        debug_assert!(
+            self.regions(&other)
+                .all(|(_, in_self, in_other)| !(in_self && in_other)),
+            "the masks must be disjoint"
+        );
+        let reversed = self.reversed || other.reversed;
+        let mut key_points = Vec::with_capacity(self.key_points.len() + other.key_points.len());
+        let mut a = self.key_points.into_iter().peekable();
+        let mut b = other.key_points.into_iter().peekable();
+        loop {
+            match (a.peek(), b.peek()) {
+                (None, None) => break,
+                (Some(x), Some(y)) if x == y => {
+                    a.next();
+                    b.next();
+                }
+                (Some(x), Some(y)) if x < y => key_points.push(a.next().unwrap()),
+                (Some(_), None) => key_points.push(a.next().unwrap()),
+                (_, Some(_)) => key_points.push(b.next().unwrap()),
+            }
+        }
+        Self {
+            key_points,
+            reversed,
+        }
+    }
+
+    /// Split `self` into the part overlapping with `other` and the rest, as `(self & other, self - other)`.
+    ///
+    /// The two parts are disjoint and their union is `self`.
+    /// Both are computed in a single sweep over the key points, which takes O(n + m).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let job = ordmask![0, 100];
+    /// let maintenance = ordmask![20, 30, 90, 120];
+    /// let (blocked, free) = job.carve_out(&maintenance);
+    /// assert_eq!(blocked, ordmask![20, 30, 90, 100]);
+    /// assert_eq!(free, ordmask![0, 20, 30, 90]);
+    /// ```
+    pub fn carve_out(&self, other: &Self) -> (Self, Self) {
+        let mut regions = self.regions(other);
+        let (_, in_self, in_other) = regions.next().unwrap();
+        let mut overlap = Self {
+            key_points: Vec::new(),
+            reversed: in_self && in_other,
+        };
+        let mut rest = Self {
+            key_points: Vec::new(),
+            reversed: in_self && !in_other,
+        };
+        let (mut in_overlap, mut in_rest) = (overlap.reversed, rest.reversed);
+        for (point, in_self, in_other) in regions {
+            let point = point.unwrap();
+            if (in_self && in_other) != in_overlap {
+                in_overlap = !in_overlap;
+                overlap.key_points.push(point.clone());
+            }
+            if (in_self && !in_other) != in_rest {
+                in_rest = !in_rest;
+                rest.key_points.push(point.clone());
+            }
+        }
+        (overlap, rest)
+    }
+
     /// Create a new OrdMask representing the symmetric difference of the `self` and `other`.
     ///
     /// Values included in the symmetric difference
@@ -86,6 +524,8 @@ impl<T: Ord + Clone> OrdMask<T> {
     /// let symmetric_difference = mask1.symmetric_difference(&mask2);
     /// assert_eq!(symmetric_difference, ordmask![0, 5, 10, 20]);
     pub fn symmetric_difference(&self, other: &Self) -> Self {
+        debug_assert!(self.consistency_check().is_ok());
+        debug_assert!(other.consistency_check().is_ok());
         Self::from_key_points_set(
             Self::get_key_points_set(&[self, other]),
             |x| self.included(x) != other.included(x),
@@ -93,6 +533,184 @@ impl<T: Ord + Clone> OrdMask<T> {
         )
     }
 
+    /// Modify `self` to become the symmetric difference of `self` and `other`.
+    ///
+    /// The key points of the symmetric difference are the key points of either mask but not both,
+    /// so each key point of `other` is toggled in `self` without recomputing the others.
+    /// If `other` has at most 16 key points, each one is found by binary search and inserted or removed in place,
+    /// which takes O(m log n) comparisons and no allocation unless `self` grows,
+    /// though each insertion or removal shifts the key points after it.
+    /// Otherwise, the key points are merged in a single pass of O(n + m) like `symmetric_difference`,
+    /// which rebuilds the whole mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![0, 10, 20, 30];
+    /// mask.symmetric_difference_in_place(&ordmask![_, 10, 25]);
+    /// assert_eq!(mask, ordmask![_, 0, 20, 25, 30]);
+    /// ```
+    pub fn symmetric_difference_in_place(&mut self, other: &Self) {
+        const SMALL_OTHER: usize = 16;
+        debug_assert!(self.consistency_check().is_ok());
+        debug_assert!(other.consistency_check().is_ok());
+        self.reversed ^= other.reversed;
+        if other.key_points.len() <= SMALL_OTHER {
+            for point in &other.key_points {
+                match self.key_points.binary_search(point) {
+                    Ok(index) => {
+                        self.key_points.remove(index);
+                    }
+                    Err(index) => self.key_points.insert(index, point.clone()),
+                }
+            }
+            return;
+        }
+        let mut key_points = Vec::with_capacity(self.key_points.len() + other.key_points.len());
+        let mut others = other.key_points.iter().peekable();
+        for point in std::mem::take(&mut self.key_points) {
+            while let Some(other_point) = others.next_if(|x| **x < point) {
+                key_points.push(other_point.clone());
+            }
+            if others.next_if(|x| **x == point).is_none() {
+                key_points.push(point);
+            }
+        }
+        key_points.extend(others.cloned());
+        self.key_points = key_points;
+    }
+
+    /// Toggle the values in the range `[start, end)` in place.
+    ///
+    /// It's the symmetric difference with `OrdMask::in_range(start, end)`:
+    /// `start` and `end` are found by binary search,
+    /// and each is inserted as a key point, or removed if it's already one.
+    /// It takes O(log n) comparisons and no allocation unless `self` grows,
+    /// though the insertions and removals shift the key points after them.
+    /// It does nothing if `start >= end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![0, 10, 20, 30];
+    /// mask.xor_with_single_range(10, 25);
+    /// assert_eq!(mask, ordmask![0, 20, 25, 30]);
+    /// mask.xor_with_single_range(10, 25);
+    /// assert_eq!(mask, ordmask![0, 10, 20, 30]);
+    /// ```
+    pub fn xor_with_single_range(&mut self, start: T, end: T) {
+        debug_assert!(self.consistency_check().is_ok());
+        if start >= end {
+            return;
+        }
+        for point in [start, end] {
+            match self.key_points.binary_search(&point) {
+                Ok(index) => {
+                    self.key_points.remove(index);
+                }
+                Err(index) => self.key_points.insert(index, point),
+            }
+        }
+    }
+
+    /// Modify `self` to become the intersection of `self` and `other`.
+    ///
+    /// The key points of `self` are merged with the key points of `other` within the same buffer:
+    /// they are moved behind room for the key points of `other`,
+    /// and the result is written from the front, which never overtakes the unread key points.
+    /// It takes O(n + m) and allocates only if the buffer has no room for `m` more key points,
+    /// so it's cheaper than `self = &self & other` when `other` has few key points,
+    /// like a nearly universal mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![0, 10, 20, 30];
+    /// mask.intersection_in_place(&ordmask![_, 5, 25]);
+    /// assert_eq!(mask, ordmask![0, 5, 25, 30]);
+    /// ```
+    pub fn intersection_in_place(&mut self, other: &Self) {
+        self.merge_in_place(other, |a, b| a && b);
+    }
+
+    /// Modify `self` to become the union of `self` and `other`.
+    ///
+    /// It merges the key points within the buffer of `self` like `intersection_in_place`,
+    /// so it takes O(n + m) and reuses the capacity of `self`,
+    /// allocating only if there is no room for `m` more key points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![0, 10, 20, 30];
+    /// mask.union_in_place(&ordmask![5, 25, 40]);
+    /// assert_eq!(mask, ordmask![0, 30, 40]);
+    /// ```
+    pub fn union_in_place(&mut self, other: &Self) {
+        self.merge_in_place(other, |a, b| a || b);
+    }
+
+    /// Merge the key points of `other` into the buffer of `self`, keeping the regions where `op` is true.
+    ///
+    /// The key points of `self` are moved behind room for the key points of `other`,
+    /// and the result is written from the front, which never overtakes the unread key points.
+    fn merge_in_place(&mut self, other: &Self, op: impl Fn(bool, bool) -> bool) {
+        debug_assert!(self.consistency_check().is_ok());
+        debug_assert!(other.consistency_check().is_ok());
+        let (n, m) = (self.key_points.len(), other.key_points.len());
+        let buffer = &mut self.key_points;
+        // Make room for `m` key points in front of the key points of `self`
+        buffer.extend_from_slice(&other.key_points);
+        buffer.rotate_right(m);
+
+        let (mut in_self, mut in_other) = (self.reversed, other.reversed);
+        let reversed = op(in_self, in_other);
+        let mut state = reversed;
+        let (mut i, mut j, mut written) = (0, 0, 0);
+        loop {
+            let from_self = match (i < n, other.key_points.get(j)) {
+                (false, None) => break,
+                (true, Some(y)) if buffer[m + i] == *y => {
+                    j += 1;
+                    in_other = !in_other;
+                    true
+                }
+                (true, Some(y)) => buffer[m + i] < *y,
+                (true, None) => true,
+                (false, Some(_)) => false,
+            };
+            if from_self {
+                in_self = !in_self;
+            } else {
+                in_other = !in_other;
+            }
+            if op(in_self, in_other) != state {
+                state = !state;
+                if from_self {
+                    buffer.swap(written, m + i);
+                } else {
+                    buffer[written] = other.key_points[j].clone();
+                }
+                written += 1;
+            }
+            if from_self {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        buffer.truncate(written);
+        self.reversed = reversed;
+    }
+
     /// Consume the `self` and return a new OrdMask that represents the complement of the `self`.
     ///
     /// Values included in the complement must be excluded in the `self`, and vice versa.
@@ -1,4 +1,4 @@
-use super::OrdMask;
+use super::{OrdMask, Workspace};
 
 macro_rules! impl_bitor {
     ($lt:ty, $rt:ty) => {
@@ -10,7 +10,7 @@ macro_rules! impl_bitor {
             /// Values included in the union must be included in
             /// at least one of the `self` or `rhs`.
             fn bitor(self, rhs: $rt) -> Self::Output {
-                OrdMask::union(&[&self, &rhs])
+                OrdMask::union_with_workspace(&self, &rhs, &mut Workspace::new())
             }
         }
     };
@@ -30,7 +30,7 @@ macro_rules! impl_bitand {
             ///
             /// Values included in the intersection must be included in all of the `self` and `rhs`.
             fn bitand(self, rhs: $rt) -> Self::Output {
-                OrdMask::intersection(&[&self, &rhs])
+                OrdMask::intersection_with_workspace(&self, &rhs, &mut Workspace::new())
             }
         }
     };
@@ -51,7 +51,7 @@ macro_rules! impl_bitxor {
             /// Values included in the symmetric difference must be included in
             /// one of the `self` or `rhs`, but not both.
             fn bitxor(self, rhs: $rt) -> Self::Output {
-                self.symmetric_difference(&rhs)
+                OrdMask::symmetric_difference_with_workspace(&self, &rhs, &mut Workspace::new())
             }
         }
     };
@@ -71,7 +71,7 @@ macro_rules! impl_sub {
             ///
             /// Values included in the difference must be included in `self` and excluded in `rhs`.
             fn sub(self, rhs: $rt) -> Self::Output {
-                self.minus(&[&rhs])
+                OrdMask::minus_with_workspace(&self, &rhs, &mut Workspace::new())
             }
         }
     };
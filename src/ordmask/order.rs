@@ -0,0 +1,66 @@
+use super::{OrdMask, Predecessor, Successor};
+use std::cmp::Reverse;
+
+impl<T: Ord + Clone + Predecessor> OrdMask<T> {
+    /// Convert the mask to a mask over `Reverse<T>` including the same values.
+    ///
+    /// For every `x`, `inverted.included(&Reverse(x)) == self.included(&x)`.
+    ///
+    /// The half-open range `[a, b)` includes `[a, b - 1]`,
+    /// which is `[Reverse(b - 1), Reverse(a - 1))` in the reversed order,
+    /// so the predecessors of the key points are used.
+    /// A key point at the minimum value has no predecessor, so it's dropped,
+    /// which doesn't change the included values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    /// use std::cmp::Reverse;
+    ///
+    /// let mask = ordmask![0, 10, 20];
+    /// let inverted = mask.clone().invert_order();
+    /// assert_eq!(inverted, ordmask![_, Reverse(19), Reverse(9), Reverse(-1)]);
+    /// for x in -5..25 {
+    ///     assert_eq!(inverted.included(&Reverse(x)), mask.included(&x));
+    /// }
+    /// assert_eq!(OrdMask::from_reversed_order(inverted), mask);
+    /// ```
+    pub fn invert_order(self) -> OrdMask<Reverse<T>> {
+        let reversed = self.is_include_max_value();
+        let mut key_points: Vec<Reverse<T>> = self
+            .key_points
+            .iter()
+            .filter_map(|x| x.prev_value().map(Reverse))
+            .collect();
+        key_points.reverse();
+        OrdMask {
+            key_points,
+            reversed,
+        }
+    }
+}
+
+impl<T: Ord + Clone + Successor> OrdMask<T> {
+    /// Convert a mask over `Reverse<T>` to a mask over `T` including the same values.
+    ///
+    /// It's the inverse of `invert_order`,
+    /// so the successors of the key points are used.
+    /// A key point at the maximum value has no successor, so it's dropped,
+    /// which doesn't change the included values.
+    ///
+    /// For every `x`, `result.included(&x) == mask.included(&Reverse(x))`.
+    pub fn from_reversed_order(mask: OrdMask<Reverse<T>>) -> Self {
+        let reversed = mask.is_include_max_value();
+        let mut key_points: Vec<T> = mask
+            .key_points
+            .iter()
+            .filter_map(|Reverse(x)| x.next_value())
+            .collect();
+        key_points.reverse();
+        Self {
+            key_points,
+            reversed,
+        }
+    }
+}
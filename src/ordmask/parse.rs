@@ -0,0 +1,93 @@
+use super::{Error, OrdMask};
+use std::str::FromStr;
+
+impl<T: Ord + Clone + FromStr> OrdMask<T> {
+    /// Parse an `OrdMask` from key points separated by `sep`.
+    ///
+    /// A leading `_` token means the mask includes the minimum value, like in `ordmask!`.
+    /// Whitespace around each token is ignored, and an empty string is an empty mask.
+    /// Equal consecutive key points are simplified as `try_from` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert_eq!(OrdMask::try_from_str_key_points("0,10,20", ','), Ok(ordmask![0, 10, 20]));
+    /// assert_eq!(OrdMask::try_from_str_key_points("_; 5; 15", ';'), Ok(ordmask![_, 5, 15]));
+    /// assert!(OrdMask::<i32>::try_from_str_key_points("0,x", ',').is_err());
+    /// assert!(OrdMask::<i32>::try_from_str_key_points("10,0", ',').is_err());
+    /// ```
+    pub fn try_from_str_key_points(s: &str, sep: char) -> Result<Self, ParseError<T::Err>> {
+        let mut tokens = s.split(sep).map(str::trim).peekable();
+        let reversed = tokens.next_if_eq(&"_").is_some();
+        if s.trim().is_empty() {
+            return Ok(Self::empty());
+        }
+        let offset = reversed as usize;
+        let key_points = tokens
+            .enumerate()
+            .map(|(index, token)| {
+                token.parse().map_err(|error| ParseError::KeyPoint {
+                    index: index + offset,
+                    error,
+                })
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+        Self::try_new(key_points, reversed).map_err(ParseError::Order)
+    }
+}
+
+impl<T: Ord + Clone + std::fmt::Display> OrdMask<T> {
+    /// Format the key points separated by `sep`, with a leading `_` if the mask includes the minimum value.
+    ///
+    /// It's the inverse of `try_from_str_key_points`,
+    /// as long as the formatted key points don't contain `sep` and can be parsed back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert_eq!(ordmask![0, 10, 20].to_str_key_points(','), "0,10,20");
+    /// assert_eq!(ordmask![_, 5, 15].to_str_key_points(';'), "_;5;15");
+    /// assert_eq!(OrdMask::<i32>::empty().to_str_key_points(','), "");
+    /// ```
+    pub fn to_str_key_points(&self, sep: char) -> String {
+        let mask = self.normalized();
+        let mask = mask.as_ref();
+        let mut s = String::new();
+        if mask.reversed {
+            s.push('_');
+        }
+        for (i, point) in mask.key_points.iter().enumerate() {
+            if mask.reversed || i > 0 {
+                s.push(sep);
+            }
+            s.push_str(&point.to_string());
+        }
+        s
+    }
+}
+
+/// The error returned by [`OrdMask::try_from_str_key_points`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError<E> {
+    /// The token at `index` can't be parsed as a key point.
+    KeyPoint { index: usize, error: E },
+    /// The parsed key points can't be converted to an `OrdMask`.
+    Order(Error),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::KeyPoint { index, error } => {
+                write!(f, "Can't parse the token at index {}: {}", index, error)
+            }
+            Self::Order(error) => std::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for ParseError<E> {}
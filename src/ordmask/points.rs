@@ -0,0 +1,229 @@
+use super::{OrdMask, Successor};
+
+impl<T: Ord + Clone + Successor> OrdMask<T> {
+    /// Check if every included range contains exactly one value.
+    ///
+    /// The unbounded tail counts as a single point if it starts at the maximum value.
+    /// The duplicate key points are removed first,
+    /// so a tail closed by `close_tail_at_max` is checked like the open one.
+    /// A mask including the minimum value is never a point set, since its unbounded start
+    /// can't be checked without `MinValue`. Fold it by `fold_min_value` first if needed.
+    ///
+    /// Note that consecutive points are merged into one wider range,
+    /// so a mask built by `from_points` is not a point set if any two points are consecutive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert!(ordmask![1, 2, 5, 6].is_point_set());
+    /// assert!(ordmask![1, 2, 255u8].is_point_set());
+    /// assert!(!ordmask![1, 3].is_point_set());
+    /// assert!(!OrdMask::from_points([1, 2]).is_point_set());
+    /// ```
+    pub fn is_point_set(&self) -> bool {
+        let mask = self.normalized();
+        !mask.reversed
+            && mask.key_points.chunks(2).all(|pair| match pair {
+                [start, end] => start.next_value().as_ref() == Some(end),
+                [start] => start.next_value().is_none(),
+                _ => unreachable!(),
+            })
+    }
+
+    /// Get the included values if the mask is a point set, or `None` otherwise.
+    ///
+    /// See `is_point_set` for the meaning of a point set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert_eq!(ordmask![1, 2, 5, 6].to_point_set(), Some(vec![1, 5]));
+    /// assert_eq!(ordmask![1, 2, 255u8].to_point_set(), Some(vec![1, 255]));
+    /// assert_eq!(ordmask![1, 3].to_point_set(), None);
+    /// ```
+    pub fn to_point_set(&self) -> Option<Vec<T>> {
+        match self.is_point_set() {
+            true => Some(
+                self.normalized()
+                    .key_points
+                    .iter()
+                    .step_by(2)
+                    .cloned()
+                    .collect(),
+            ),
+            false => None,
+        }
+    }
+
+    /// Create a new OrdMask that includes exactly the given `points`.
+    ///
+    /// The points can be unsorted and duplicated.
+    /// Each point `v` becomes the range `[v, v + 1)`, and consecutive points are merged into one range.
+    /// The maximum value becomes the open unbounded tail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert_eq!(OrdMask::from_points([5, 1, 5]), ordmask![1, 2, 5, 6]);
+    /// assert_eq!(OrdMask::from_points([3, 1, 2]), ordmask![1, 4]);
+    /// assert_eq!(OrdMask::from_points([255u8, 0]), ordmask![0, 1, 255]);
+    /// ```
+    pub fn from_points(points: impl IntoIterator<Item = T>) -> Self {
+        let mut points: Vec<T> = points.into_iter().collect();
+        points.sort_unstable();
+        points.dedup();
+        let mut key_points: Vec<T> = Vec::with_capacity(points.len() * 2);
+        for point in points {
+            if key_points.last() == Some(&point) {
+                key_points.pop();
+            } else {
+                key_points.push(point.clone());
+            }
+            if let Some(end) = point.next_value() {
+                key_points.push(end);
+            }
+        }
+        Self {
+            key_points,
+            reversed: false,
+        }
+    }
+
+    /// Create a new OrdMask extended minimally to include `value`.
+    ///
+    /// If `value` is already included, it's the same as `clone`.
+    /// Otherwise, it's the same as `self | OrdMask::from_points([value])`,
+    /// so the range `[value, value + 1)` is added and merged with the adjacent ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20];
+    /// assert_eq!(mask.extend_to_cover(&5), mask);
+    /// assert_eq!(mask.extend_to_cover(&10), ordmask![0, 11, 20]);
+    /// assert_eq!(mask.extend_to_cover(&15), ordmask![0, 10, 15, 16, 20]);
+    /// assert_eq!(mask.extend_to_cover(&19), ordmask![0, 10, 19]);
+    /// ```
+    pub fn extend_to_cover(&self, value: &T) -> Self {
+        let mut mask = self.clone();
+        mask.extend_to_cover_in_place(value);
+        mask
+    }
+
+    /// Extend the mask minimally to include `value` in place.
+    ///
+    /// See `extend_to_cover` for details.
+    pub fn extend_to_cover_in_place(&mut self, value: &T) {
+        if self.included(value) {
+            return;
+        }
+        let index = self.key_points.partition_point(|x| x <= value);
+        let end = value.next_value();
+        // Merge with the range ending at `value`, or start a new range
+        let merges_before = index > 0 && self.key_points[index - 1] == *value;
+        // Merge with the range starting at `value + 1`, or end the new range
+        let merges_after = match &end {
+            Some(end) => self.key_points.get(index) == Some(end),
+            None => true,
+        };
+        match (merges_before, merges_after) {
+            (true, true) => {
+                self.key_points
+                    .drain(index - 1..(index + 1).min(self.key_points.len()));
+            }
+            (true, false) => self.key_points[index - 1] = end.unwrap(),
+            (false, true) => match end {
+                Some(_) => self.key_points[index] = value.clone(),
+                None => self.key_points.insert(index, value.clone()),
+            },
+            (false, false) => {
+                self.key_points
+                    .splice(index..index, [value.clone(), end.unwrap()]);
+            }
+        }
+    }
+
+    /// Get the values included in the mask but missing from the sorted `observed` values.
+    ///
+    /// It's the same as `self.minus(&[&OrdMask::from_points(observed)])`,
+    /// but it walks the key points and the observed values together without building the points mask,
+    /// and the consecutive observed values are merged by `next_value`.
+    /// Duplicated observed values are allowed. It takes O(n + k).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let expected = ordmask![0, 10, 20, 25];
+    /// let observed = [0, 1, 2, 2, 5, 9, 20, 21, 22, 23, 24];
+    /// assert_eq!(expected.missing_from_sorted(&observed), ordmask![3, 5, 6, 9]);
+    /// assert_eq!(ordmask![0, 3].missing_from_sorted(&[0, 1, 2]), ordmask![]);
+    /// ```
+    pub fn missing_from_sorted(&self, observed: &[T]) -> Self {
+        debug_assert!(
+            observed.windows(2).all(|pair| pair[0] <= pair[1]),
+            "the observed values must be sorted"
+        );
+        // the key points of the observed values, merging the consecutive ones
+        let mut index = 0;
+        let mut pending_end: Option<T> = None;
+        let mut holes = std::iter::from_fn(|| {
+            if let Some(end) = pending_end.take() {
+                return Some(end);
+            }
+            let start = observed.get(index)?.clone();
+            let mut last = start.clone();
+            loop {
+                while index < observed.len() && observed[index] <= last {
+                    index += 1;
+                }
+                match last.next_value() {
+                    Some(next) if observed.get(index) == Some(&next) => last = next,
+                    Some(next) => {
+                        pending_end = Some(next);
+                        break;
+                    }
+                    None => break,
+                }
+            }
+            Some(start)
+        })
+        .peekable();
+
+        let mut key_points = Vec::with_capacity(self.key_points.len());
+        let (mut in_self, mut in_holes) = (self.reversed, false);
+        let mut i = 0;
+        loop {
+            let point = match (self.key_points.get(i), holes.peek()) {
+                (None, None) => break,
+                (Some(x), Some(y)) => x.min(y).clone(),
+                (Some(x), None) => x.clone(),
+                (None, Some(y)) => y.clone(),
+            };
+            while self.key_points.get(i) == Some(&point) {
+                in_self = !in_self;
+                i += 1;
+            }
+            if holes.next_if_eq(&point).is_some() {
+                in_holes = !in_holes;
+            }
+            let included = self.reversed ^ (key_points.len() % 2 == 1);
+            if included != (in_self && !in_holes) {
+                key_points.push(point);
+            }
+        }
+        Self {
+            key_points,
+            reversed: self.reversed,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use super::OrdMask;
+use std::ops::{Add, Sub};
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Create a random subset of the mask, keeping roughly `keep_fraction` of its measure.
+    ///
+    /// Each bounded included range is tiled into granules of width `granularity` from its start,
+    /// where the last granule is clipped to the end of the range,
+    /// and each granule is kept if `random()` is less than `keep_fraction`.
+    /// The surviving adjacent granules are merged.
+    /// The unbounded ranges are skipped, so they are not included in the result;
+    /// intersect the mask with a bounded range first to sample them.
+    ///
+    /// `random` should return uniformly distributed values in `[0, 1)`,
+    /// like `|| rng.gen::<f64>()` with the `rand` crate.
+    /// The result is always a subset of the mask, which is useful for chaos testing.
+    ///
+    /// The last granule of a range is found by comparing `end - granule_start` with `granularity`,
+    /// so no value past the end of a range is computed, and a range ending at the maximum value is fine.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if adding `granularity` doesn't increase a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20, 25];
+    /// let mut coin = [0.1, 0.9, 0.2, 0.3, 0.8].into_iter().cycle();
+    /// let submask = mask.random_submask(|| coin.next().unwrap(), 0.5, &3);
+    /// // granules [0, 3), [3, 6), [6, 9), [9, 10), [20, 23) and [23, 25)
+    /// assert_eq!(submask, ordmask![0, 3, 6, 10, 23, 25]);
+    /// ```
+    pub fn random_submask<D: Clone + PartialOrd>(
+        &self,
+        mut random: impl FnMut() -> f64,
+        keep_fraction: f64,
+        granularity: &D,
+    ) -> Self
+    where
+        T: Add<D, Output = T> + Sub<Output = D>,
+    {
+        let mut key_points: Vec<T> = Vec::new();
+        let skip = (self.reversed as usize).min(self.key_points.len());
+        for range in self.key_points[skip..].chunks_exact(2) {
+            let (start, end) = (&range[0], &range[1]);
+            let mut granule_start = start.clone();
+            while granule_start < *end {
+                let granule_end = if end.clone() - granule_start.clone() <= *granularity {
+                    end.clone()
+                } else {
+                    let next = granule_start.clone() + granularity.clone();
+                    assert!(next > granule_start, "the granularity must be positive");
+                    next
+                };
+                if random() < keep_fraction {
+                    if key_points.last() == Some(&granule_start) {
+                        key_points.pop();
+                    } else {
+                        key_points.push(granule_start);
+                    }
+                    key_points.push(granule_end.clone());
+                }
+                granule_start = granule_end;
+            }
+        }
+        Self {
+            key_points,
+            reversed: false,
+        }
+    }
+}
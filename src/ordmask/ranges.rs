@@ -0,0 +1,419 @@
+use super::OrdMask;
+use std::ops::Bound;
+
+/// An iterator over the included ranges of an `OrdMask`.
+///
+/// It is created by [`OrdMask::ranges`].
+#[derive(Clone, Debug)]
+pub struct Ranges<'a, T> {
+    key_points: &'a [T],
+    unbounded_start: bool,
+}
+
+impl<'a, T> Ranges<'a, T> {
+    pub(crate) fn new(key_points: &'a [T], unbounded_start: bool) -> Self {
+        Self {
+            key_points,
+            unbounded_start,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Ranges<'a, T> {
+    type Item = (Bound<&'a T>, Bound<&'a T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.unbounded_start {
+            self.unbounded_start = false;
+            return match self.key_points.split_first() {
+                None => Some((Bound::Unbounded, Bound::Unbounded)),
+                Some((end, rest)) => {
+                    self.key_points = rest;
+                    Some((Bound::Unbounded, Bound::Excluded(end)))
+                }
+            };
+        }
+        match self.key_points {
+            [] => None,
+            [start] => {
+                self.key_points = &[];
+                Some((Bound::Included(start), Bound::Unbounded))
+            }
+            [start, end, rest @ ..] => {
+                self.key_points = rest;
+                Some((Bound::Included(start), Bound::Excluded(end)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.key_points.len() + self.unbounded_start as usize).div_ceil(2);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Ranges<'_, T> {}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Get an iterator over the included ranges in ascending order.
+    ///
+    /// Each range is a pair of bounds. A start bound is either `Included` or `Unbounded`,
+    /// and an end bound is either `Excluded` or `Unbounded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mask = ordmask![_, 0, 10, 20];
+    /// let ranges: Vec<_> = mask.ranges().collect();
+    /// assert_eq!(ranges, vec![(Unbounded, Excluded(&0)), (Included(&10), Excluded(&20))]);
+    ///
+    /// let mask = ordmask![0, 10, 20];
+    /// let ranges: Vec<_> = mask.ranges().collect();
+    /// assert_eq!(ranges, vec![(Included(&0), Excluded(&10)), (Included(&20), Unbounded)]);
+    /// ```
+    pub fn ranges(&self) -> Ranges<'_, T> {
+        Ranges {
+            key_points: &self.key_points,
+            unbounded_start: self.reversed,
+        }
+    }
+
+    /// Get an iterator over the ranges of the mask with the inclusion `reversed` flipped,
+    /// starting from the range containing `start_at` or the next one.
+    fn ranges_from_with(&self, start_at: &T, reversed: bool) -> Ranges<'_, T> {
+        let index = self.key_points.partition_point(|x| x <= start_at);
+        match (reversed ^ (index % 2 == 1), index) {
+            (true, 0) => Ranges {
+                key_points: &self.key_points,
+                unbounded_start: true,
+            },
+            (true, _) => Ranges {
+                key_points: &self.key_points[index - 1..],
+                unbounded_start: false,
+            },
+            (false, _) => Ranges {
+                key_points: &self.key_points[index..],
+                unbounded_start: false,
+            },
+        }
+    }
+
+    /// Get an iterator over the included ranges,
+    /// starting from the range containing `start_at`, or the next one if `start_at` is excluded.
+    ///
+    /// It seeks by binary search, which takes O(log n),
+    /// so it can be used with `take` to paginate the ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30, 40];
+    /// let page: Vec<_> = mask.ranges_from(&5).take(2).collect();
+    /// assert_eq!(page, vec![(Included(&0), Excluded(&10)), (Included(&20), Excluded(&30))]);
+    ///
+    /// // The end of a range is excluded, so the next range is the first one
+    /// let page: Vec<_> = mask.ranges_from(&30).take(2).collect();
+    /// assert_eq!(page, vec![(Included(&40), Unbounded)]);
+    /// ```
+    pub fn ranges_from(&self, start_at: &T) -> Ranges<'_, T> {
+        self.ranges_from_with(start_at, self.reversed)
+    }
+
+    /// Get an iterator over the excluded ranges,
+    /// starting from the range containing `start_at`, or the next one if `start_at` is included.
+    ///
+    /// It's the same as `ranges_from` of the complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30, 40];
+    /// let gaps: Vec<_> = mask.gaps_from(&5).collect();
+    /// assert_eq!(gaps, vec![(Included(&10), Excluded(&20)), (Included(&30), Excluded(&40))]);
+    /// ```
+    pub fn gaps_from(&self, start_at: &T) -> Ranges<'_, T> {
+        self.ranges_from_with(start_at, !self.reversed)
+    }
+
+    /// Get the first range of the mask with the inclusion `reversed` flipped, which starts at or after `value`.
+    fn first_range_after_with(&self, value: &T, reversed: bool) -> Option<(Bound<T>, Bound<T>)> {
+        let index = self.key_points.partition_point(|x| x < value);
+        // a range starts at the key point `i` if the values right after it are included
+        let start = (index..index + 2).find(|i| reversed ^ (i % 2 == 0))?;
+        let end = match self.key_points.get(start + 1) {
+            Some(end) => Bound::Excluded(end.clone()),
+            None => Bound::Unbounded,
+        };
+        Some((Bound::Included(self.key_points.get(start)?.clone()), end))
+    }
+
+    /// Get the first included range which starts at or after `value`.
+    ///
+    /// A range containing `value` but starting before it is skipped, use `ranges_from` to get it.
+    /// It seeks by binary search, which takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30, 40];
+    /// assert_eq!(mask.first_included_range_after(&5), Some((Included(20), Excluded(30))));
+    /// assert_eq!(mask.first_included_range_after(&20), Some((Included(20), Excluded(30))));
+    /// assert_eq!(mask.first_included_range_after(&35), Some((Included(40), Unbounded)));
+    /// assert_eq!(mask.first_included_range_after(&45), None);
+    /// ```
+    pub fn first_included_range_after(&self, value: &T) -> Option<(Bound<T>, Bound<T>)> {
+        self.first_range_after_with(value, self.reversed)
+    }
+
+    /// Get the first excluded range which starts at or after `value`.
+    ///
+    /// It's the same as `first_included_range_after` of the complement,
+    /// like the next blocked slot at or after a time.
+    /// A gap containing `value` but starting before it is skipped, use `gaps_from` to get it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30];
+    /// assert_eq!(mask.first_excluded_gap_after(&5), Some((Included(10), Excluded(20))));
+    /// assert_eq!(mask.first_excluded_gap_after(&15), Some((Included(30), Unbounded)));
+    /// assert_eq!(mask.first_excluded_gap_after(&35), None);
+    /// ```
+    pub fn first_excluded_gap_after(&self, value: &T) -> Option<(Bound<T>, Bound<T>)> {
+        self.first_range_after_with(value, !self.reversed)
+    }
+
+    /// Get the last range of the mask with the inclusion `reversed` flipped, which ends at or before `value`.
+    fn last_range_before_with(&self, value: &T, reversed: bool) -> Option<(Bound<T>, Bound<T>)> {
+        let index = self.key_points.partition_point(|x| x <= value);
+        // a range ends at the key point `i` if the values right after it are excluded
+        let end = (index.saturating_sub(2)..index)
+            .rev()
+            .find(|i| reversed ^ (i % 2 == 1))?;
+        let start = match end {
+            0 => Bound::Unbounded,
+            _ => Bound::Included(self.key_points[end - 1].clone()),
+        };
+        Some((start, Bound::Excluded(self.key_points[end].clone())))
+    }
+
+    /// Get the last included range which ends at or before `value`.
+    ///
+    /// The end of a range is excluded, so a range ending at `value` doesn't contain it.
+    /// A range containing `value` is skipped.
+    /// It seeks by binary search, which takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mask = ordmask![_, 0, 10, 20, 30];
+    /// assert_eq!(mask.last_included_range_before(&25), Some((Included(10), Excluded(20))));
+    /// assert_eq!(mask.last_included_range_before(&20), Some((Included(10), Excluded(20))));
+    /// assert_eq!(mask.last_included_range_before(&5), Some((Unbounded, Excluded(0))));
+    /// assert_eq!(mask.last_included_range_before(&-5), None);
+    /// ```
+    pub fn last_included_range_before(&self, value: &T) -> Option<(Bound<T>, Bound<T>)> {
+        self.last_range_before_with(value, self.reversed)
+    }
+
+    /// Get the last excluded range which ends at or before `value`.
+    ///
+    /// It's the same as `last_included_range_before` of the complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::*;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30];
+    /// assert_eq!(mask.last_excluded_gap_before(&25), Some((Included(10), Excluded(20))));
+    /// assert_eq!(mask.last_excluded_gap_before(&5), Some((Unbounded, Excluded(0))));
+    /// assert_eq!(mask.last_excluded_gap_before(&-5), None);
+    /// ```
+    pub fn last_excluded_gap_before(&self, value: &T) -> Option<(Bound<T>, Bound<T>)> {
+        self.last_range_before_with(value, !self.reversed)
+    }
+
+    /// Get an iterator over the included ranges as half-open `(start, end)` pairs.
+    ///
+    /// The pairs borrow from the key points without copying.
+    /// The `start` is `None` if the range extends to negative infinity,
+    /// which is the leading range of a mask including the minimum value,
+    /// and the `end` is `None` if the range extends to positive infinity.
+    /// The pairs follow the key points as they are,
+    /// so a tail closed by `close_tail_at_max` is yielded as `(start, T::MAX_VALUE)` and `(T::MAX_VALUE, None)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20];
+    /// let pairs: Vec<_> = mask.to_half_open_pairs().collect();
+    /// assert_eq!(pairs, vec![(Some(&0), Some(&10)), (Some(&20), None)]);
+    ///
+    /// let mask = ordmask![_, 0, 10, 20];
+    /// let pairs: Vec<_> = mask.to_half_open_pairs().collect();
+    /// assert_eq!(pairs, vec![(None, Some(&0)), (Some(&10), Some(&20))]);
+    /// ```
+    pub fn to_half_open_pairs(&self) -> impl Iterator<Item = (Option<&T>, Option<&T>)> {
+        let (head, key_points) = match self.reversed {
+            true => (
+                Some((None, self.key_points.first())),
+                self.key_points.get(1..).unwrap_or_default(),
+            ),
+            false => (None, &self.key_points[..]),
+        };
+        head.into_iter().chain(
+            key_points
+                .chunks(2)
+                .map(|pair| (Some(&pair[0]), pair.get(1))),
+        )
+    }
+
+    /// Create a new OrdMask containing every other included range,
+    /// starting at the range with index `start_at`.
+    ///
+    /// The ranges are indexed in ascending order from 0, as yielded by `ranges`.
+    /// For `start_at = 0` it keeps the ranges 0, 2, 4, ...,
+    /// and for `start_at = 1` it keeps the ranges 1, 3, 5, ....
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 1, 2, 3, 4, 5, 6];
+    /// assert_eq!(mask.every_other_range(0), ordmask![0, 1, 4, 5]);
+    /// assert_eq!(mask.every_other_range(1), ordmask![2, 3, 6]);
+    /// ```
+    pub fn every_other_range(&self, start_at: usize) -> Self {
+        let offset = self.reversed as usize;
+        let mut key_points = Vec::with_capacity(self.key_points.len() / 2 + 1);
+        for i in (start_at..self.ranges().len()).step_by(2) {
+            let start = 2 * i;
+            if start >= offset {
+                key_points.push(self.key_points[start - offset].clone());
+            }
+            if let Some(end) = self.key_points.get(start + 1 - offset) {
+                key_points.push(end.clone());
+            }
+        }
+        Self {
+            key_points,
+            reversed: self.reversed && start_at == 0,
+        }
+    }
+
+    /// Find the first included range satisfying the predicate `f`.
+    ///
+    /// It's the same as `ranges().find(...)`, returning owned bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::{self, *};
+    ///
+    /// let slots = ordmask![0, 1, 5, 8, 10, 13];
+    /// let longer_than_2 = |start: Bound<&i32>, end: Bound<&i32>| match (start, end) {
+    ///     (Included(start), Excluded(end)) => end - start > 2,
+    ///     _ => true,
+    /// };
+    /// assert_eq!(slots.find_range_by_predicate(longer_than_2), Some((Included(5), Excluded(8))));
+    /// ```
+    pub fn find_range_by_predicate(
+        &self,
+        f: impl Fn(Bound<&T>, Bound<&T>) -> bool,
+    ) -> Option<(Bound<T>, Bound<T>)> {
+        self.ranges()
+            .find(|(start, end)| f(*start, *end))
+            .map(|(start, end)| (start.cloned(), end.cloned()))
+    }
+
+    /// Collect all included ranges satisfying the predicate `f`.
+    ///
+    /// It's the same as `ranges().filter(...).collect()`, returning owned bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    /// use std::ops::Bound::{self, *};
+    ///
+    /// let slots = ordmask![0, 1, 5, 8, 10, 13];
+    /// let longer_than_2 = |start: Bound<&i32>, end: Bound<&i32>| match (start, end) {
+    ///     (Included(start), Excluded(end)) => end - start > 2,
+    ///     _ => true,
+    /// };
+    /// assert_eq!(
+    ///     slots.filter_ranges_collect(longer_than_2),
+    ///     vec![(Included(5), Excluded(8)), (Included(10), Excluded(13))]
+    /// );
+    /// ```
+    pub fn filter_ranges_collect(
+        &self,
+        f: impl Fn(Bound<&T>, Bound<&T>) -> bool,
+    ) -> Vec<(Bound<T>, Bound<T>)> {
+        self.ranges()
+            .filter(|(start, end)| f(*start, *end))
+            .map(|(start, end)| (start.cloned(), end.cloned()))
+            .collect()
+    }
+
+    /// Create a new OrdMask containing only the included range touching `value`.
+    ///
+    /// A range touches `value` if it contains `value`, or if its end is `value`.
+    /// Since the ranges never touch each other, at most one range is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![0, 10, 20, 30, 40];
+    /// assert_eq!(mask.remove_ranges_not_touching(&25), ordmask![20, 30]);
+    /// assert_eq!(mask.remove_ranges_not_touching(&30), ordmask![20, 30]);
+    /// assert_eq!(mask.remove_ranges_not_touching(&45), ordmask![40]);
+    /// assert_eq!(mask.remove_ranges_not_touching(&35), ordmask![]);
+    /// ```
+    pub fn remove_ranges_not_touching(&self, value: &T) -> Self {
+        let index = self.key_points.partition_point(|x| x <= value);
+        let end = match self.reversed ^ (index % 2 == 1) {
+            true => index,
+            false if index > 0 && self.key_points[index - 1] == *value => index - 1,
+            false => return Self::empty(),
+        };
+        // The range ends at the key point `end`, and starts at the key point `end - 1` if any
+        let stop = (end + 1).min(self.key_points.len());
+        match end.checked_sub(1) {
+            Some(start) => Self {
+                key_points: self.key_points[start..stop].to_vec(),
+                reversed: false,
+            },
+            None => Self {
+                key_points: self.key_points[..stop].to_vec(),
+                reversed: true,
+            },
+        }
+    }
+}
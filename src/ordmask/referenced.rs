@@ -0,0 +1,118 @@
+use super::{OrdMask, Ranges};
+
+/// The read-only queries shared by `OrdMask` and `ReferencedMask`.
+///
+/// Functions accepting `impl MaskLike<T>` can be called with either of them without cloning.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{MaskLike, ordmask};
+///
+/// fn count_included(mask: &impl MaskLike<i32>, values: &[i32]) -> usize {
+///     values.iter().filter(|x| mask.included(x)).count()
+/// }
+///
+/// let mask = ordmask![0, 10];
+/// assert_eq!(count_included(&mask, &[-1, 0, 5, 10]), 2);
+/// assert_eq!(count_included(&mask.as_referenced_mask(), &[-1, 0, 5, 10]), 2);
+/// ```
+pub trait MaskLike<T: Ord> {
+    /// Get the key points in ascending order.
+    fn key_points(&self) -> &[T];
+
+    /// Check if the mask includes the minimum value.
+    fn is_include_min_value(&self) -> bool;
+
+    /// Check if the mask includes the maximum value.
+    fn is_include_max_value(&self) -> bool {
+        self.is_include_min_value() ^ (self.key_points().len() % 2 == 1)
+    }
+
+    /// Check if a value is included in this mask.
+    fn included(&self, value: &T) -> bool {
+        self.is_include_min_value() ^ (self.key_points().partition_point(|x| x <= value) % 2 == 1)
+    }
+
+    /// Check if a value is excluded in this mask.
+    fn excluded(&self, value: &T) -> bool {
+        !self.included(value)
+    }
+
+    /// Check if the mask includes no value.
+    fn is_empty(&self) -> bool {
+        !self.is_include_min_value() && self.key_points().is_empty()
+    }
+
+    /// Check if the mask includes all values.
+    fn is_universal(&self) -> bool {
+        self.is_include_min_value() && self.key_points().is_empty()
+    }
+
+    /// Get an iterator over the included ranges in ascending order.
+    fn ranges(&self) -> Ranges<'_, T> {
+        Ranges::new(self.key_points(), self.is_include_min_value())
+    }
+}
+
+impl<T: Ord + Clone> MaskLike<T> for OrdMask<T> {
+    fn key_points(&self) -> &[T] {
+        &self.key_points
+    }
+
+    fn is_include_min_value(&self) -> bool {
+        self.reversed
+    }
+}
+
+/// A borrowed view of an `OrdMask`, which can be queried without owning the key points.
+///
+/// It is created by [`OrdMask::as_referenced_mask`], and the queries are provided by [`MaskLike`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReferencedMask<'a, T> {
+    key_points: &'a [T],
+    reversed: bool,
+}
+
+impl<T: Ord> MaskLike<T> for ReferencedMask<'_, T> {
+    fn key_points(&self) -> &[T] {
+        self.key_points
+    }
+
+    fn is_include_min_value(&self) -> bool {
+        self.reversed
+    }
+}
+
+impl<T: Ord + Clone> ReferencedMask<'_, T> {
+    /// Create an owned `OrdMask` by cloning the key points.
+    pub fn to_mask(&self) -> OrdMask<T> {
+        OrdMask {
+            key_points: self.key_points.to_vec(),
+            reversed: self.reversed,
+        }
+    }
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Get a borrowed view of the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{MaskLike, ordmask};
+    ///
+    /// let mask = ordmask![_, 0, 10];
+    /// let referenced = mask.as_referenced_mask();
+    /// assert!(referenced.included(&-1));
+    /// assert!(referenced.excluded(&0));
+    /// assert_eq!(referenced.ranges().count(), 2);
+    /// assert_eq!(referenced.to_mask(), mask);
+    /// ```
+    pub fn as_referenced_mask(&self) -> ReferencedMask<'_, T> {
+        ReferencedMask {
+            key_points: &self.key_points,
+            reversed: self.reversed,
+        }
+    }
+}
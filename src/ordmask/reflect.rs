@@ -0,0 +1,58 @@
+use super::OrdMask;
+use std::ops::{Add, Sub};
+
+impl<T: Ord + Clone + Add<Output = T> + Sub<Output = T>> OrdMask<T> {
+    /// Create a new OrdMask by reflecting `self` through `center`.
+    ///
+    /// Each key point `x` is mapped to `2 * center - x`, and the order of the key points is reversed,
+    /// since reflecting an ascending sequence produces a descending one.
+    ///
+    /// A reflected range `[a, b)` becomes `[2 * center - b, 2 * center - a)`,
+    /// so the inclusion of the boundaries is kept half-open rather than mirrored exactly.
+    /// It is exact for continuous keys except at the key points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert_eq!(ordmask![2, 3, 8].reflect_around(5), ordmask![_, 2, 7, 8]);
+    /// assert_eq!(ordmask![_, 0].reflect_around(0), ordmask![0]);
+    /// ```
+    pub fn reflect_around(&self, center: T) -> Self {
+        let key_points = self
+            .key_points
+            .iter()
+            .rev()
+            .map(|x| center.clone() + center.clone() - x.clone())
+            .collect();
+        Self {
+            key_points,
+            reversed: self.reversed ^ (self.key_points.len() % 2 == 1),
+        }
+    }
+
+    /// Check if the mask is symmetric around `center`.
+    ///
+    /// It's the same as `self == self.reflect_around(center)`,
+    /// but it checks the key points in symmetric pairs without creating the reflected mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert!(ordmask![-3, -1, 1, 3].is_symmetric_around(0));
+    /// assert!(ordmask![_, 2, 8].is_symmetric_around(5));
+    /// assert!(!ordmask![2, 3, 8].is_symmetric_around(5));
+    /// assert!(!ordmask![0].is_symmetric_around(0));
+    /// ```
+    pub fn is_symmetric_around(&self, center: T) -> bool {
+        let len = self.key_points.len();
+        len.is_multiple_of(2)
+            && (0..len / 2).all(|i| {
+                self.key_points[i].clone() + self.key_points[len - 1 - i].clone()
+                    == center.clone() + center.clone()
+            })
+    }
+}
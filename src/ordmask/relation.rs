@@ -0,0 +1,187 @@
+use super::{MinValue, OrdMask};
+use std::ops::{Bound, RangeBounds};
+
+/// An iterator over the regions split by the key points of two masks.
+///
+/// Each item is the start of the region (`None` for the leading unbounded region)
+/// and whether the region is included in each mask.
+pub(crate) struct Regions<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+    in_a: bool,
+    in_b: bool,
+    started: bool,
+}
+
+impl<'a, T: Ord> Iterator for Regions<'a, T> {
+    type Item = (Option<&'a T>, bool, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some((None, self.in_a, self.in_b));
+        }
+        let point = match (self.a.split_first(), self.b.split_first()) {
+            (None, None) => return None,
+            (Some((x, a)), Some((y, b))) if x == y => {
+                (self.a, self.b) = (a, b);
+                (self.in_a, self.in_b) = (!self.in_a, !self.in_b);
+                x
+            }
+            (Some((x, a)), Some((y, _))) if x < y => {
+                self.a = a;
+                self.in_a = !self.in_a;
+                x
+            }
+            (Some((x, a)), None) => {
+                self.a = a;
+                self.in_a = !self.in_a;
+                x
+            }
+            (_, Some((y, b))) => {
+                self.b = b;
+                self.in_b = !self.in_b;
+                y
+            }
+        };
+        Some((Some(point), self.in_a, self.in_b))
+    }
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Get an iterator over the regions split by the key points of `self` and `other`.
+    pub(crate) fn regions<'a>(&'a self, other: &'a Self) -> Regions<'a, T> {
+        Regions {
+            a: &self.key_points,
+            b: &other.key_points,
+            in_a: self.reversed,
+            in_b: other.reversed,
+            started: false,
+        }
+    }
+
+    /// Check if all values included in `self` are also included in `other`.
+    ///
+    /// It takes O(n + m).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert!(ordmask![5, 10].is_subset_of(&ordmask![0, 10]));
+    /// assert!(ordmask![0, 10].is_subset_of(&ordmask![0, 10]));
+    /// assert!(!ordmask![5, 11].is_subset_of(&ordmask![0, 10]));
+    /// assert!(ordmask![5, 10].is_subset_of(&ordmask![_]));
+    /// ```
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.regions(other)
+            .all(|(_, in_self, in_other)| !in_self || in_other)
+    }
+
+    /// Check if all values included in the mask are within `range`, which is vacuously true for an empty mask.
+    ///
+    /// It only compares the first and the last key points with the bounds, so it takes O(1).
+    /// A mask including the minimum or the maximum value is only within a range unbounded on that side.
+    ///
+    /// The last included range `[a, b)` is within an included end `..=hi` only if `b <= hi`,
+    /// since there may be values between `hi` and `b` for types like floats.
+    /// For discrete types, use the excluded end `..hi + 1` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![10, 20, 30, 40];
+    /// assert!(mask.is_within(10..40));
+    /// assert!(mask.is_within(0..));
+    /// assert!(!mask.is_within(15..40));
+    /// assert!(!mask.is_within(10..39));
+    /// assert!(ordmask![_, 10].is_within(..10));
+    /// assert!(!ordmask![_, 10].is_within(0..10));
+    /// assert!(ordmask![].is_within(5..5));
+    /// ```
+    pub fn is_within(&self, range: impl RangeBounds<T>) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let start_within = match (range.start_bound(), self.reversed) {
+            (Bound::Unbounded, _) => true,
+            (_, true) => false,
+            (Bound::Included(lo), false) => self.key_points[0] >= *lo,
+            (Bound::Excluded(lo), false) => self.key_points[0] > *lo,
+        };
+        let end_within = match (range.end_bound(), self.is_include_max_value()) {
+            (Bound::Unbounded, _) => true,
+            (_, true) => false,
+            (Bound::Included(hi) | Bound::Excluded(hi), false) => {
+                self.key_points.last() <= Some(hi)
+            }
+        };
+        start_within && end_within
+    }
+
+    /// Check if `self` is a strict superset of `other`.
+    ///
+    /// It's the same as `other.is_subset_of(self) && self != other`,
+    /// but checked in a single pass of O(n + m).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// assert!(ordmask![0, 10].strictly_contains(&ordmask![5, 10]));
+    /// assert!(!ordmask![0, 10].strictly_contains(&ordmask![0, 10]));
+    /// assert!(!ordmask![0, 10].strictly_contains(&ordmask![5, 11]));
+    /// assert!(ordmask![_].strictly_contains(&ordmask![5, 10]));
+    /// ```
+    pub fn strictly_contains(&self, other: &Self) -> bool {
+        let mut strict = false;
+        for (_, in_self, in_other) in self.regions(other) {
+            if in_other && !in_self {
+                return false;
+            }
+            strict |= in_self && !in_other;
+        }
+        strict
+    }
+}
+
+impl<T: Ord + Clone + MinValue> OrdMask<T> {
+    /// Find a value included in both `self` and `other`, or `None` if they are disjoint.
+    ///
+    /// The witness is the start of the first overlapping region,
+    /// which is the maximum of the starts of the overlapping ranges of the two masks,
+    /// or `T::MIN_VALUE` if both masks include the unbounded start.
+    /// It sweeps the key points of both masks and stops at the first overlap, so it takes O(n + m) at most.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let a = ordmask![0, 10, 4200, 4300];
+    /// let b = ordmask![4217, 5000];
+    /// assert_eq!(a.find_common_value(&b), Some(4217));
+    /// assert_eq!(a.find_common_value(&ordmask![10, 4200]), None);
+    /// assert_eq!(ordmask![_, 10].find_common_value(&ordmask![_, 5]), Some(i32::MIN));
+    /// ```
+    pub fn find_common_value(&self, other: &Self) -> Option<T> {
+        let mut regions = self.regions(other).peekable();
+        while let Some((start, in_self, in_other)) = regions.next() {
+            if !(in_self && in_other) {
+                continue;
+            }
+            let witness = start.cloned().unwrap_or(T::MIN_VALUE);
+            // the region is empty if the next one starts at the same value,
+            // like the region between duplicate key points or before a key point at `T::MIN_VALUE`
+            match regions.peek() {
+                Some((Some(next), _, _)) if **next <= witness => continue,
+                _ => return Some(witness),
+            }
+        }
+        None
+    }
+}
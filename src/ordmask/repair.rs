@@ -0,0 +1,90 @@
+use super::OrdMask;
+
+/// The policy of `OrdMask::repair` to handle key points that are not non-decreasing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepairPolicy {
+    /// Sort all the key points, then simplify them.
+    SortAndSimplify,
+    /// Drop each key point less than the last kept one, then simplify the rest.
+    DropOffending,
+}
+
+/// The changes made by `OrdMask::repair`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// The number of key points not at their original index after sorting.
+    pub moved: usize,
+    /// The number of key points removed, either as offending ones or as duplicates by simplifying.
+    pub dropped: usize,
+}
+
+impl RepairReport {
+    /// Check if the key points were kept as they are.
+    pub fn is_clean(&self) -> bool {
+        self.moved == 0 && self.dropped == 0
+    }
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Create an `OrdMask` from possibly corrupted key points, salvaging what it can by `policy`.
+    ///
+    /// It never fails, and reports how many key points were moved or dropped.
+    /// It's meant for data from untrusted storage, so use `try_from` or `from` in normal code
+    /// to not silently accept bad data.
+    ///
+    /// Valid key points, which are non-decreasing, are only simplified by both policies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, RepairPolicy, RepairReport, ordmask};
+    ///
+    /// let corrupted = vec![0, 20, 10, 30];
+    ///
+    /// let (mask, report) = OrdMask::repair(corrupted.clone(), false, RepairPolicy::SortAndSimplify);
+    /// assert_eq!(mask, ordmask![0, 10, 20, 30]);
+    /// assert_eq!(report, RepairReport { moved: 2, dropped: 0 });
+    ///
+    /// let (mask, report) = OrdMask::repair(corrupted, false, RepairPolicy::DropOffending);
+    /// assert_eq!(mask, ordmask![0, 20, 30]);
+    /// assert_eq!(report, RepairReport { moved: 0, dropped: 1 });
+    /// ```
+    pub fn repair(
+        mut key_points: Vec<T>,
+        reversed: bool,
+        policy: RepairPolicy,
+    ) -> (Self, RepairReport) {
+        let mut report = RepairReport::default();
+        match policy {
+            RepairPolicy::SortAndSimplify => {
+                let original = key_points.clone();
+                key_points.sort();
+                report.moved = original
+                    .iter()
+                    .zip(&key_points)
+                    .filter(|(a, b)| a != b)
+                    .count();
+            }
+            RepairPolicy::DropOffending => {
+                let len = key_points.len();
+                let mut kept = 0;
+                for index in 0..len {
+                    if kept == 0 || key_points[index] >= key_points[kept - 1] {
+                        key_points.swap(kept, index);
+                        kept += 1;
+                    }
+                }
+                key_points.truncate(kept);
+                report.dropped = len - kept;
+            }
+        }
+        let len = key_points.len();
+        let mut mask = Self {
+            key_points,
+            reversed,
+        };
+        mask.simplify();
+        report.dropped += len - mask.key_points.len();
+        (mask, report)
+    }
+}
@@ -0,0 +1,42 @@
+use super::{MinValue, OrdMask};
+
+impl<T: Ord + Clone + MinValue> OrdMask<T> {
+    /// Encode the mask as `(start, end, included)` runs covering `[T::MIN_VALUE, max_value)`.
+    ///
+    /// Each run is a maximal half-open range `[start, end)` where the inclusion is constant,
+    /// so the runs alternate between included and excluded, and each run starts where the previous one ends.
+    /// Unlike `ranges`, both included and excluded runs are emitted, which is a dense representation of the mask.
+    /// Key points not less than `max_value` are ignored, and it's empty if `max_value` is `T::MIN_VALUE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![10u8, 20, 30];
+    /// assert_eq!(
+    ///     mask.encode_as_run_pairs(100),
+    ///     vec![(0, 10, false), (10, 20, true), (20, 30, false), (30, 100, true)]
+    /// );
+    /// assert_eq!(mask.encode_as_run_pairs(15), vec![(0, 10, false), (10, 15, true)]);
+    /// ```
+    pub fn encode_as_run_pairs(&self, max_value: T) -> Vec<(T, T, bool)> {
+        let mut runs = Vec::with_capacity(self.key_points.len() + 1);
+        let mut start = T::MIN_VALUE;
+        let mut included = self.reversed;
+        for point in &self.key_points {
+            if *point >= max_value {
+                break;
+            }
+            if *point > start {
+                runs.push((start, point.clone(), included));
+                start = point.clone();
+            }
+            included = !included;
+        }
+        if start < max_value {
+            runs.push((start, max_value, included));
+        }
+        runs
+    }
+}
@@ -0,0 +1,33 @@
+use super::OrdMask;
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Scan the key points with a state machine, like `Iterator::scan` over the boundary events.
+    ///
+    /// At each key point, `f` is called with the current state and whether an included range is entered,
+    /// or `false` if one is left, and returns the new state.
+    /// It returns the state after each key point, so the length is the number of key points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mask = ordmask![_, 0, 10, 20, 30];
+    /// let entered = mask.scan(0, |count, entering| count + entering as usize);
+    /// assert_eq!(entered, vec![0, 1, 1, 2]);
+    ///
+    /// let events = mask.scan(String::new(), |s, entering| s + if entering { "+" } else { "-" });
+    /// assert_eq!(events.last().unwrap(), "-+-+");
+    /// ```
+    pub fn scan<S: Clone, F: FnMut(S, bool) -> S>(&self, init: S, mut f: F) -> Vec<S> {
+        let mut states = Vec::with_capacity(self.key_points.len());
+        let mut state = init;
+        let mut entering = !self.reversed;
+        for _ in &self.key_points {
+            state = f(state, entering);
+            states.push(state.clone());
+            entering = !entering;
+        }
+        states
+    }
+}
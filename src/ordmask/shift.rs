@@ -0,0 +1,138 @@
+use super::OrdMask;
+
+macro_rules! impl_shift {
+    ($($t:ty),*) => {$(
+        impl OrdMask<$t> {
+            /// Shift all key points by `offset` towards the maximum value.
+            ///
+            /// Returns `None` if any key point overflows.
+            pub fn checked_shift_right(&self, offset: $t) -> Option<Self> {
+                let key_points = self
+                    .key_points
+                    .iter()
+                    .map(|x| x.checked_add(offset))
+                    .collect::<Option<_>>()?;
+                Some(Self {
+                    key_points,
+                    reversed: self.reversed,
+                })
+            }
+
+            /// Shift all key points by `offset` towards the minimum value.
+            ///
+            /// Returns `None` if any key point overflows.
+            pub fn checked_shift_left(&self, offset: $t) -> Option<Self> {
+                let key_points = self
+                    .key_points
+                    .iter()
+                    .map(|x| x.checked_sub(offset))
+                    .collect::<Option<_>>()?;
+                Some(Self {
+                    key_points,
+                    reversed: self.reversed,
+                })
+            }
+
+            /// Shift all key points by `offset` towards the maximum value.
+            ///
+            /// The key points that overflow are dropped,
+            /// so a range crossing the maximum value runs to the end of the domain,
+            /// and the ranges shifted out of the domain entirely are dropped.
+            pub fn saturating_shift_right(&self, offset: $t) -> Self {
+                Self {
+                    key_points: self.key_points.iter().filter_map(|x| x.checked_add(offset)).collect(),
+                    reversed: self.reversed,
+                }
+            }
+
+            /// Shift all key points by `offset` towards the minimum value.
+            ///
+            /// The key points that overflow are clamped to the minimum value,
+            /// so the ranges shifted out of the domain are dropped,
+            /// and a key point left at the minimum value is folded by `fold_min_value`.
+            pub fn saturating_shift_left(&self, offset: $t) -> Self {
+                let mut result = Self {
+                    key_points: self.key_points.iter().map(|x| x.saturating_sub(offset)).collect(),
+                    reversed: self.reversed,
+                };
+                result.simplify();
+                result.fold_min_value();
+                result
+            }
+        }
+
+        impl std::ops::Add<$t> for OrdMask<$t> {
+            type Output = OrdMask<$t>;
+
+            /// Shift the mask by `rhs` towards the maximum value.
+            ///
+            /// # Panics
+            ///
+            /// It will panic if any key point overflows.
+            /// Use `checked_shift_right` or `saturating_shift_right` to handle it.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ordmask::{OrdMask, ordmask};
+            ///
+            /// // A daily window from 9:00 to 17:00 in hours of UTC
+            #[doc = concat!("let window: OrdMask<", stringify!($t), "> = ordmask![9, 17];")]
+            /// // The same local window is one hour later in UTC after the clocks go back for DST,
+            /// // and one hour earlier again after they go forward
+            /// let winter = window.clone() + 1;
+            /// assert_eq!(winter, ordmask![10, 18]);
+            /// assert_eq!(winter - 1, window);
+            /// ```
+            fn add(self, rhs: $t) -> Self::Output {
+                (&self).add(rhs)
+            }
+        }
+
+        impl std::ops::Add<$t> for &OrdMask<$t> {
+            type Output = OrdMask<$t>;
+
+            /// Shift the mask by `rhs` towards the maximum value.
+            ///
+            /// # Panics
+            ///
+            /// It will panic if any key point overflows.
+            /// Use `checked_shift_right` or `saturating_shift_right` to handle it.
+            fn add(self, rhs: $t) -> Self::Output {
+                self.checked_shift_right(rhs)
+                    .expect("attempt to shift mask with overflow")
+            }
+        }
+
+        impl std::ops::Sub<$t> for OrdMask<$t> {
+            type Output = OrdMask<$t>;
+
+            /// Shift the mask by `rhs` towards the minimum value.
+            ///
+            /// # Panics
+            ///
+            /// It will panic if any key point overflows.
+            /// Use `checked_shift_left` or `saturating_shift_left` to handle it.
+            fn sub(self, rhs: $t) -> Self::Output {
+                (&self).sub(rhs)
+            }
+        }
+
+        impl std::ops::Sub<$t> for &OrdMask<$t> {
+            type Output = OrdMask<$t>;
+
+            /// Shift the mask by `rhs` towards the minimum value.
+            ///
+            /// # Panics
+            ///
+            /// It will panic if any key point overflows.
+            /// Use `checked_shift_left` or `saturating_shift_left` to handle it.
+            fn sub(self, rhs: $t) -> Self::Output {
+                self.checked_shift_left(rhs)
+                    .expect("attempt to shift mask with overflow")
+            }
+        }
+    )*};
+}
+
+impl_shift!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
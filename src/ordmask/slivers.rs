@@ -0,0 +1,134 @@
+use super::OrdMask;
+use std::ops::Sub;
+
+impl<T, D> OrdMask<T>
+where
+    T: Ord + Clone + Sub<Output = D>,
+    D: PartialOrd,
+{
+    /// Remove the bounded regions whose inclusion equals `included` and whose width is less than `min_width`.
+    fn remove_narrow_regions(&mut self, min_width: &D, included: bool) {
+        let len = self.key_points.len();
+        let mut key_points = Vec::with_capacity(len);
+        let mut i = 0;
+        while i < len {
+            // The region between the key points `i` and `i + 1`
+            let state = self.reversed ^ (i % 2 == 0);
+            if state == included
+                && i + 1 < len
+                && self.key_points[i + 1].clone() - self.key_points[i].clone() < *min_width
+            {
+                i += 2;
+            } else {
+                key_points.push(self.key_points[i].clone());
+                i += 1;
+            }
+        }
+        self.key_points = key_points;
+    }
+
+    /// Remove the included ranges narrower than `min_width`.
+    ///
+    /// A range `[start, end)` is removed if `end - start < min_width`,
+    /// so a range of exactly `min_width` is kept. Unbounded ranges are never removed.
+    ///
+    /// It's designed for masks over floating-point keys (wrapped in a type implementing `Ord`),
+    /// where rounding may produce ranges of negligible width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![_, 0, 10, 11, 20, 22, 30];
+    /// mask.drop_slivers(&2);
+    /// assert_eq!(mask, ordmask![_, 0, 20, 22, 30]);
+    /// ```
+    pub fn drop_slivers(&mut self, min_width: &D) {
+        self.remove_narrow_regions(min_width, true);
+    }
+
+    /// Fill the excluded gaps narrower than `min_width`.
+    ///
+    /// It's the dual of `drop_slivers`: a gap `[start, end)` is filled if `end - start < min_width`,
+    /// and unbounded gaps are never filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut mask = ordmask![0, 10, 11, 20, 22, 30];
+    /// mask.fill_slivers(&2);
+    /// assert_eq!(mask, ordmask![0, 20, 22, 30]);
+    /// ```
+    pub fn fill_slivers(&mut self, min_width: &D) {
+        self.remove_narrow_regions(min_width, false);
+    }
+
+    /// Create a new OrdMask that includes all values in the range `[start, end)`,
+    /// or an empty one if `end - start < min_width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// assert_eq!(OrdMask::in_range_min_width(0, 10, &10), ordmask![0, 10]);
+    /// assert_eq!(OrdMask::in_range_min_width(0, 10, &11), ordmask![]);
+    /// ```
+    pub fn in_range_min_width(start: T, end: T, min_width: &D) -> Self {
+        match start < end && end.clone() - start.clone() >= *min_width {
+            true => Self::in_range(start, end),
+            false => Self::empty(),
+        }
+    }
+
+    /// Clip the mask to the window `[start, end)`, and remove the included ranges narrower than `min_width`.
+    ///
+    /// The width of a range is measured after clipping, so a range mostly outside the window is removed
+    /// if its part inside the window is too narrow.
+    /// It's the same as `intersection_with_range` followed by `drop_slivers`,
+    /// but done in a single pass over the key points in the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// // within [0, 100), discard the slots shorter than 15
+    /// let slots = ordmask![_, 5, 10, 30, 40, 45, 80, 120];
+    /// assert_eq!(slots.trim_to_significant_ranges(&15, (0, 100)), ordmask![10, 30, 80, 100]);
+    /// assert_eq!(slots.trim_to_significant_ranges(&15, (0, 8)), ordmask![]);
+    /// ```
+    pub fn trim_to_significant_ranges(&self, min_width: &D, window: (T, T)) -> Self {
+        let (start, end) = window;
+        let mut key_points = Vec::new();
+        if start < end {
+            let lo = self.key_points.partition_point(|x| x <= &start);
+            let hi = self.key_points.partition_point(|x| x < &end);
+            let mut range_start = (self.reversed ^ (lo % 2 == 1)).then(|| start.clone());
+            for point in &self.key_points[lo..hi] {
+                match range_start.take() {
+                    Some(range_start) => {
+                        if point.clone() - range_start.clone() >= *min_width {
+                            key_points.push(range_start);
+                            key_points.push(point.clone());
+                        }
+                    }
+                    None => range_start = Some(point.clone()),
+                }
+            }
+            if let Some(range_start) = range_start {
+                if end.clone() - range_start.clone() >= *min_width {
+                    key_points.push(range_start);
+                    key_points.push(end);
+                }
+            }
+        }
+        Self {
+            key_points,
+            reversed: false,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use super::OrdMask;
+use std::ops::{Bound, RangeBounds};
+
+/// The error returned when a range can't be converted to the half-open `[start, end)` convention.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeBoundError {
+    /// The start bound of the range is excluded.
+    ExcludedStart,
+    /// The end bound of the range is included.
+    IncludedEnd,
+}
+
+impl std::fmt::Display for RangeBoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExcludedStart => write!(f, "The start bound of the range is excluded."),
+            Self::IncludedEnd => write!(f, "The end bound of the range is included."),
+        }
+    }
+}
+
+impl std::error::Error for RangeBoundError {}
+
+/// Convert `range` to the half-open `[start, end)` convention, where `None` means unbounded.
+///
+/// An excluded start or an included end can't be represented by a mask without a successor,
+/// so it returns an error for them.
+pub(crate) fn half_open_bounds<T>(
+    range: &impl RangeBounds<T>,
+) -> Result<(Option<&T>, Option<&T>), RangeBoundError> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => Some(start),
+        Bound::Unbounded => None,
+        Bound::Excluded(_) => return Err(RangeBoundError::ExcludedStart),
+    };
+    let end = match range.end_bound() {
+        Bound::Excluded(end) => Some(end),
+        Bound::Unbounded => None,
+        Bound::Included(_) => return Err(RangeBoundError::IncludedEnd),
+    };
+    Ok((start, end))
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Get the intersection of the mask and the range `[start, end)`.
+    ///
+    /// It equals `self & OrdMask::in_range(start, end)`,
+    /// but it only copies the key points inside the range instead of merging two masks,
+    /// so it takes O(log n + k) where k is the number of key points in the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::{OrdMask, ordmask};
+    ///
+    /// let mask = ordmask![0, 10, 20, 30];
+    /// assert_eq!(mask.intersection_with_range(5, 25), ordmask![5, 10, 20, 25]);
+    /// assert_eq!(ordmask![_, 10].intersection_with_range(0, 5), ordmask![0, 5]);
+    /// assert_eq!(mask.intersection_with_range(25, 5), ordmask![]);
+    /// ```
+    pub fn intersection_with_range(&self, start: T, end: T) -> Self {
+        self.restrict(Some(&start), Some(&end))
+    }
+
+    /// Move the values of `self` in the range `[start, end)` into `dest`, and return the moved region.
+    ///
+    /// The moved region is `self.intersection_with_range(start, end)`,
+    /// which is removed from `self` and added to `dest`,
+    /// so `self | dest` is unchanged, and `self & dest` only loses the moved region.
+    /// The values of `dest` in the range that were not in `self` are kept.
+    /// It takes O(log n + k) to find the region where k is the number of key points in the window,
+    /// plus an in-place merge into `dest`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordmask::ordmask;
+    ///
+    /// let mut free = ordmask![0, 100];
+    /// let mut reserved = ordmask![150, 200];
+    /// let moved = free.transfer_range(&mut reserved, &80, &150);
+    /// assert_eq!(moved, ordmask![80, 100]);
+    /// assert_eq!(free, ordmask![0, 80]);
+    /// assert_eq!(reserved, ordmask![80, 100, 150, 200]);
+    /// ```
+    pub fn transfer_range(&mut self, dest: &mut Self, start: &T, end: &T) -> Self {
+        let moved = self.restrict(Some(start), Some(end));
+        // the moved region is a subset of `self`, so toggling its key points removes it
+        self.symmetric_difference_in_place(&moved);
+        dest.union_in_place(&moved);
+        moved
+    }
+
+    /// Restrict the mask to `[start, end)`, where `None` means unbounded.
+    ///
+    /// It takes O(log n + k) where k is the number of key points in the window.
+    pub(crate) fn restrict(&self, start: Option<&T>, end: Option<&T>) -> Self {
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                return Self::empty();
+            }
+        }
+        let lo = start.map_or(0, |start| self.key_points.partition_point(|x| x <= start));
+        let hi = end.map_or(self.key_points.len(), |end| {
+            self.key_points.partition_point(|x| x < end)
+        });
+
+        let mut key_points = Vec::with_capacity(hi - lo + 2);
+        if let Some(start) = start {
+            if self.reversed ^ (lo % 2 == 1) {
+                key_points.push(start.clone());
+            }
+        }
+        key_points.extend_from_slice(&self.key_points[lo..hi]);
+        if let Some(end) = end {
+            if self.reversed ^ (hi % 2 == 1) {
+                key_points.push(end.clone());
+            }
+        }
+        Self {
+            key_points,
+            reversed: start.is_none() && self.reversed,
+        }
+    }
+}
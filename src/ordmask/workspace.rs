@@ -0,0 +1,141 @@
+use super::OrdMask;
+
+/// A scratch space that can be reused by the set operations to avoid allocations.
+///
+/// The `*_with_workspace` operations merge the key points into the buffer of the workspace,
+/// and only allocate the final exactly-sized output.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::{OrdMask, Workspace, ordmask};
+///
+/// let mut ws = Workspace::new();
+/// let mut mask = ordmask![0, 1];
+/// for i in 1..10 {
+///     mask = OrdMask::union_with_workspace(&mask, &ordmask![i * 10, i * 10 + 1], &mut ws);
+/// }
+/// assert_eq!(mask.key_points().len(), 20);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Workspace<T> {
+    buffer: Vec<T>,
+}
+
+impl<T> Workspace<T> {
+    /// Create an empty workspace.
+    pub fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    /// Create a workspace that can hold `capacity` key points without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+impl<T> Default for Workspace<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> OrdMask<T> {
+    /// Sweep over the key points of `a` and `b`, pushing the key points where the result of `op` changes into `out`,
+    /// and return whether the result includes the minimum value.
+    pub(crate) fn merge_into(
+        a: &Self,
+        b: &Self,
+        op: impl Fn(bool, bool) -> bool,
+        out: &mut Vec<T>,
+    ) -> bool {
+        debug_assert!(a.consistency_check().is_ok());
+        debug_assert!(b.consistency_check().is_ok());
+        let (mut in_a, mut in_b) = (a.reversed, b.reversed);
+        let reversed = op(in_a, in_b);
+        let mut state = reversed;
+        let (mut i, mut j) = (0, 0);
+        loop {
+            let point = match (a.key_points.get(i), b.key_points.get(j)) {
+                (None, None) => break,
+                (Some(x), Some(y)) if x == y => {
+                    (i, j) = (i + 1, j + 1);
+                    (in_a, in_b) = (!in_a, !in_b);
+                    x
+                }
+                (Some(x), Some(y)) if x < y => {
+                    i += 1;
+                    in_a = !in_a;
+                    x
+                }
+                (Some(x), None) => {
+                    i += 1;
+                    in_a = !in_a;
+                    x
+                }
+                (_, Some(y)) => {
+                    j += 1;
+                    in_b = !in_b;
+                    y
+                }
+            };
+            if op(in_a, in_b) != state {
+                state = !state;
+                out.push(point.clone());
+            }
+        }
+        reversed
+    }
+
+    /// Merge the key points of `a` and `b` into the buffer of `ws`,
+    /// keeping the points where the result of `op` changes.
+    ///
+    /// It takes O(n + m) and returns the result mask.
+    pub(crate) fn merge_with_workspace(
+        a: &Self,
+        b: &Self,
+        op: impl Fn(bool, bool) -> bool,
+        ws: &mut Workspace<T>,
+    ) -> Self {
+        ws.buffer.clear();
+        let reversed = Self::merge_into(a, b, op, &mut ws.buffer);
+        Self {
+            key_points: ws.buffer.to_vec(),
+            reversed,
+        }
+    }
+
+    /// Create a new OrdMask representing the union of `a` and `b`,
+    /// reusing the buffer of `ws`.
+    ///
+    /// The result is the same as `a | b`.
+    pub fn union_with_workspace(a: &Self, b: &Self, ws: &mut Workspace<T>) -> Self {
+        Self::merge_with_workspace(a, b, |a, b| a || b, ws)
+    }
+
+    /// Create a new OrdMask representing the intersection of `a` and `b`,
+    /// reusing the buffer of `ws`.
+    ///
+    /// The result is the same as `a & b`.
+    pub fn intersection_with_workspace(a: &Self, b: &Self, ws: &mut Workspace<T>) -> Self {
+        Self::merge_with_workspace(a, b, |a, b| a && b, ws)
+    }
+
+    /// Create a new OrdMask representing the difference of `a` and `b`,
+    /// reusing the buffer of `ws`.
+    ///
+    /// The result is the same as `a - b`.
+    pub fn minus_with_workspace(a: &Self, b: &Self, ws: &mut Workspace<T>) -> Self {
+        Self::merge_with_workspace(a, b, |a, b| a && !b, ws)
+    }
+
+    /// Create a new OrdMask representing the symmetric difference of `a` and `b`,
+    /// reusing the buffer of `ws`.
+    ///
+    /// The result is the same as `a ^ b`.
+    pub fn symmetric_difference_with_workspace(a: &Self, b: &Self, ws: &mut Workspace<T>) -> Self {
+        Self::merge_with_workspace(a, b, |a, b| a != b, ws)
+    }
+}
@@ -0,0 +1,327 @@
+//! A compact representation of `OrdMask<u32>` for persisting many small masks.
+//!
+//! Enabled by the `packed` feature.
+
+use crate::OrdMask;
+
+/// The number of deltas between two checkpoints of a `PackedMask`.
+const CHECKPOINT_INTERVAL: usize = 64;
+
+/// The error returned when a mask or bytes can't be converted to a `PackedMask`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PackError {
+    /// The difference between the key points at `index - 1` and `index` exceeds `u16::MAX`.
+    ///
+    /// The indices are of the key points after normalizing and folding the minimum value.
+    DeltaOverflow { index: usize },
+    /// The bytes are not produced by `PackedMask::as_bytes`.
+    InvalidBytes,
+}
+
+impl std::fmt::Display for PackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DeltaOverflow { index } => write!(
+                f,
+                "The difference between the key points at index {} and {} exceeds u16::MAX.",
+                index - 1,
+                index
+            ),
+            Self::InvalidBytes => write!(f, "The bytes are not a valid packed mask."),
+        }
+    }
+}
+
+impl std::error::Error for PackError {}
+
+/// An `OrdMask<u32>` packed as the first key point and `u16` deltas between the key points.
+///
+/// It takes 2 bytes per key point, plus a `u32` checkpoint every 64 key points,
+/// so `included` can be checked directly on the packed form in O(log n + 64) without unpacking.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::ordmask;
+/// use ordmask::packed::PackedMask;
+///
+/// let mask = ordmask![1000u32, 1010, 2000];
+/// let packed = PackedMask::from_mask(&mask).unwrap();
+/// assert!(packed.included(1005) && !packed.included(1010) && packed.included(u32::MAX));
+///
+/// let bytes = packed.as_bytes();
+/// assert_eq!(PackedMask::from_bytes(&bytes).unwrap().to_mask(), mask);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackedMask {
+    reversed: bool,
+    first: u32,
+    deltas: Vec<u16>,
+    /// The key point at index `i * CHECKPOINT_INTERVAL` for each `i`.
+    checkpoints: Vec<u32>,
+}
+
+impl PackedMask {
+    fn new(reversed: bool, first: u32, deltas: Vec<u16>) -> Self {
+        let mut checkpoints = Vec::with_capacity(deltas.len() / CHECKPOINT_INTERVAL + 1);
+        let mut point = first;
+        checkpoints.push(point);
+        for (i, delta) in deltas.iter().enumerate() {
+            point += *delta as u32;
+            if (i + 1).is_multiple_of(CHECKPOINT_INTERVAL) {
+                checkpoints.push(point);
+            }
+        }
+        Self {
+            reversed,
+            first,
+            deltas,
+            checkpoints,
+        }
+    }
+
+    /// Get the number of key points.
+    fn len(&self) -> usize {
+        match self.checkpoints.is_empty() {
+            true => 0,
+            false => self.deltas.len() + 1,
+        }
+    }
+
+    /// Pack `mask`, or return an error if the difference between any two adjacent key points exceeds `u16::MAX`.
+    ///
+    /// The mask is normalized and a key point at 0 is folded, so equal sets are packed the same way.
+    pub fn from_mask(mask: &OrdMask<u32>) -> Result<Self, PackError> {
+        let mask = mask.normalized_folded();
+        let key_points = mask.key_points();
+        let Some(&first) = key_points.first() else {
+            return Ok(Self {
+                reversed: mask.is_include_min_value(),
+                first: 0,
+                deltas: Vec::new(),
+                checkpoints: Vec::new(),
+            });
+        };
+        let mut deltas = Vec::with_capacity(key_points.len() - 1);
+        for index in 1..key_points.len() {
+            let delta = key_points[index] - key_points[index - 1];
+            deltas.push(u16::try_from(delta).map_err(|_| PackError::DeltaOverflow { index })?);
+        }
+        Ok(Self::new(mask.is_include_min_value(), first, deltas))
+    }
+
+    /// Unpack the mask.
+    pub fn to_mask(&self) -> OrdMask<u32> {
+        let mut key_points = Vec::with_capacity(self.len());
+        if self.len() > 0 {
+            let mut point = self.first;
+            key_points.push(point);
+            for delta in &self.deltas {
+                point += *delta as u32;
+                key_points.push(point);
+            }
+        }
+        // the deltas are positive and don't overflow, as checked by `from_mask` and `from_bytes`
+        OrdMask::from_parts_unchecked(key_points, self.reversed)
+    }
+
+    /// Check if `value` is included, without unpacking.
+    ///
+    /// It binary-searches the checkpoints, then sums at most 64 deltas.
+    pub fn included(&self, value: u32) -> bool {
+        let block = self.checkpoints.partition_point(|x| *x <= value);
+        if block == 0 {
+            return self.reversed;
+        }
+        let start = (block - 1) * CHECKPOINT_INTERVAL;
+        let end = (start + CHECKPOINT_INTERVAL).min(self.deltas.len());
+        let mut point = self.checkpoints[block - 1];
+        // The number of key points not greater than `value`
+        let mut count = start + 1;
+        for delta in &self.deltas[start..end] {
+            point += *delta as u32;
+            if point > value {
+                break;
+            }
+            count += 1;
+        }
+        self.reversed ^ (count % 2 == 1)
+    }
+
+    /// Encode the packed mask as bytes.
+    ///
+    /// The layout is a flag byte for including the minimum value, the number of key points as `u32`,
+    /// the first key point as `u32` if any, and the deltas as `u16`, all in little endian.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let len = self.len();
+        let mut bytes = Vec::with_capacity(9 + self.deltas.len() * 2);
+        bytes.push(self.reversed as u8);
+        bytes.extend_from_slice(&(len as u32).to_le_bytes());
+        if len > 0 {
+            bytes.extend_from_slice(&self.first.to_le_bytes());
+        }
+        for delta in &self.deltas {
+            bytes.extend_from_slice(&delta.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode the bytes produced by `as_bytes`.
+    ///
+    /// A first key point at 0 is rejected, since `from_mask` folds it into the flag,
+    /// so the decoded packed masks of equal sets are always equal.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PackError> {
+        let lazy = LazyMask::new(bytes)?;
+        let Some(first) = lazy.first_key_point() else {
+            return Ok(Self {
+                reversed: lazy.reversed,
+                first: 0,
+                deltas: Vec::new(),
+                checkpoints: Vec::new(),
+            });
+        };
+        if first == 0 {
+            return Err(PackError::InvalidBytes);
+        }
+        let deltas: Vec<u16> = lazy
+            .deltas
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        let mut point = first;
+        for delta in &deltas {
+            if *delta == 0 {
+                return Err(PackError::InvalidBytes);
+            }
+            point = point
+                .checked_add(*delta as u32)
+                .ok_or(PackError::InvalidBytes)?;
+        }
+        Ok(Self::new(lazy.reversed, first, deltas))
+    }
+}
+
+/// A view of the bytes produced by `PackedMask::as_bytes`, which decodes the deltas only as far as a query needs.
+///
+/// Creating it only checks the header and the length of the bytes in O(1).
+/// `included` sums the deltas from the first key point until one exceeds the value,
+/// so it takes O(k) where k is the number of key points not greater than the value,
+/// which is cheaper than decoding all the key points for a single query with a small value.
+/// The deltas after the answer is determined are not validated,
+/// so invalid bytes may give an answer instead of an error.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::ordmask;
+/// use ordmask::packed::{LazyMask, PackedMask};
+///
+/// let bytes = PackedMask::from_mask(&ordmask![1000u32, 1010, 2000]).unwrap().as_bytes();
+/// let lazy = LazyMask::new(&bytes).unwrap();
+/// assert_eq!(lazy.first_key_point(), Some(1000));
+/// assert_eq!(lazy.range_count(), 2);
+/// assert_eq!(lazy.included(1005), Ok(true));
+/// assert_eq!(lazy.included(1500), Ok(false));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LazyMask<'a> {
+    reversed: bool,
+    len: usize,
+    first: u32,
+    /// The deltas as little-endian `u16`.
+    deltas: &'a [u8],
+}
+
+impl<'a> LazyMask<'a> {
+    /// Check the header of the `bytes` produced by `PackedMask::as_bytes`.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, PackError> {
+        let (&flag, rest) = bytes.split_first().ok_or(PackError::InvalidBytes)?;
+        let reversed = match flag {
+            0 => false,
+            1 => true,
+            _ => return Err(PackError::InvalidBytes),
+        };
+        let (len, rest) = rest
+            .split_first_chunk::<4>()
+            .ok_or(PackError::InvalidBytes)?;
+        let len = u32::from_le_bytes(*len) as usize;
+        if len == 0 {
+            return match rest.is_empty() {
+                true => Ok(Self {
+                    reversed,
+                    len,
+                    first: 0,
+                    deltas: &[],
+                }),
+                false => Err(PackError::InvalidBytes),
+            };
+        }
+        let (first, rest) = rest
+            .split_first_chunk::<4>()
+            .ok_or(PackError::InvalidBytes)?;
+        if rest.len() != (len - 1) * 2 {
+            return Err(PackError::InvalidBytes);
+        }
+        Ok(Self {
+            reversed,
+            len,
+            first: u32::from_le_bytes(*first),
+            deltas: rest,
+        })
+    }
+
+    /// Get the first key point, or `None` if the mask is empty or universal.
+    pub fn first_key_point(&self) -> Option<u32> {
+        (self.len > 0).then_some(self.first)
+    }
+
+    /// Get the number of included ranges, in O(1).
+    pub fn range_count(&self) -> usize {
+        (self.len + self.reversed as usize).div_ceil(2)
+    }
+
+    /// Check if `value` is included, decoding the deltas until a key point exceeds it.
+    ///
+    /// It returns an error if a decoded delta is zero or overflows `u32`.
+    pub fn included(&self, value: u32) -> Result<bool, PackError> {
+        if self.len == 0 || value < self.first {
+            return Ok(self.reversed);
+        }
+        let mut point = self.first;
+        // The number of key points not greater than `value`
+        let mut count = 1;
+        for pair in self.deltas.chunks_exact(2) {
+            let delta = u16::from_le_bytes([pair[0], pair[1]]);
+            if delta == 0 {
+                return Err(PackError::InvalidBytes);
+            }
+            point = point
+                .checked_add(delta as u32)
+                .ok_or(PackError::InvalidBytes)?;
+            if point > value {
+                break;
+            }
+            count += 1;
+        }
+        Ok(self.reversed ^ (count % 2 == 1))
+    }
+}
+
+/// Check if `value` is included in the mask encoded by `PackedMask::as_bytes`, without decoding all of it.
+///
+/// It's the same as `LazyMask::new(bytes)?.included(value)`.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::ordmask;
+/// use ordmask::packed::{query_included, PackedMask};
+///
+/// let bytes = PackedMask::from_mask(&ordmask![_, 10u32, 20]).unwrap().as_bytes();
+/// assert_eq!(query_included(&bytes, 5), Ok(true));
+/// assert_eq!(query_included(&bytes, 15), Ok(false));
+/// assert!(query_included(&bytes[..3], 5).is_err());
+/// ```
+pub fn query_included(bytes: &[u8], value: u32) -> Result<bool, PackError> {
+    LazyMask::new(bytes)?.included(value)
+}
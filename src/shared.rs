@@ -0,0 +1,95 @@
+//! A handle to a mask which is replaced while other threads query it.
+//!
+//! Enabled by the `shared` feature.
+
+use crate::OrdMask;
+use std::sync::{Arc, RwLock};
+
+/// A thread-safe handle to the current version of a mask, replaced as a whole.
+///
+/// Readers take a snapshot by `load`, which clones the `Arc` of the current mask,
+/// so a reader always sees a whole version, and a snapshot is never changed by later stores.
+/// The lock is only held to clone or swap the `Arc`, never while a new version is built,
+/// so rebuilding a big mask doesn't stall the readers,
+/// and the replaced version is dropped after the lock is released.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::shared::SharedMask;
+/// use ordmask::ordmask;
+///
+/// let allowlist = SharedMask::new(ordmask![10u64, 20]);
+/// let snapshot = allowlist.load();
+/// allowlist.rcu(|mask| mask | &ordmask![30, 40]);
+///
+/// assert!(!snapshot.included(&35));
+/// assert!(allowlist.included(&35));
+/// ```
+#[derive(Debug)]
+pub struct SharedMask<T: Ord + Clone> {
+    current: RwLock<Arc<OrdMask<T>>>,
+}
+
+impl<T: Ord + Clone> SharedMask<T> {
+    /// Create a handle to `mask`.
+    pub fn new(mask: OrdMask<T>) -> Self {
+        Self {
+            current: RwLock::new(Arc::new(mask)),
+        }
+    }
+
+    /// Get a snapshot of the current mask.
+    pub fn load(&self) -> Arc<OrdMask<T>> {
+        self.current.read().unwrap().clone()
+    }
+
+    /// Replace the current mask with `mask`, and return the replaced one.
+    pub fn swap(&self, mask: OrdMask<T>) -> Arc<OrdMask<T>> {
+        let mask = Arc::new(mask);
+        std::mem::replace(&mut *self.current.write().unwrap(), mask)
+    }
+
+    /// Replace the current mask with `mask`.
+    pub fn store(&self, mask: OrdMask<T>) {
+        self.swap(mask);
+    }
+
+    /// Replace the current mask with `f` of it by read-copy-update, and return the replaced one.
+    ///
+    /// `f` is called without holding the lock, so it may be called again
+    /// if another thread replaces the mask meanwhile, and it should have no side effects.
+    pub fn rcu(&self, f: impl Fn(&OrdMask<T>) -> OrdMask<T>) -> Arc<OrdMask<T>> {
+        let mut current = self.load();
+        loop {
+            let new = Arc::new(f(&current));
+            let mut guard = self.current.write().unwrap();
+            if Arc::ptr_eq(&guard, &current) {
+                return std::mem::replace(&mut *guard, new);
+            }
+            current = guard.clone();
+        }
+    }
+
+    /// Check if `value` is included in the current mask.
+    pub fn included(&self, value: &T) -> bool {
+        self.load().included(value)
+    }
+
+    /// Check if `value` is excluded from the current mask.
+    pub fn excluded(&self, value: &T) -> bool {
+        self.load().excluded(value)
+    }
+}
+
+impl<T: Ord + Clone> Default for SharedMask<T> {
+    fn default() -> Self {
+        Self::new(OrdMask::empty())
+    }
+}
+
+impl<T: Ord + Clone> From<OrdMask<T>> for SharedMask<T> {
+    fn from(mask: OrdMask<T>) -> Self {
+        Self::new(mask)
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers for checking implementations of the traits of this crate.
+//!
+//! They are meant to be called from the tests of a crate implementing the traits for its own types.
+
+use crate::{Predecessor, Successor};
+use std::fmt::Debug;
+
+/// Check that the [`Successor`] implementation of `T` follows its laws on the `samples`.
+///
+/// For every sample `x`:
+///
+/// - If `x.next_value()` is `Some(y)`, then `x < y`, and no sample is strictly between `x` and `y`.
+/// - If `x.next_value()` is `None`, then no sample is greater than `x`.
+///
+/// Only the samples are checked, so include the extreme values and the neighbours of any special value.
+///
+/// # Panics
+///
+/// Panics with a message describing the violation if a law is broken.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::testing::check_successor_laws;
+///
+/// check_successor_laws(&[i8::MIN, -1, 0, 1, i8::MAX]);
+/// ```
+pub fn check_successor_laws<T: Ord + Successor + Debug>(samples: &[T]) {
+    for x in samples {
+        match x.next_value() {
+            Some(y) => {
+                assert!(
+                    *x < y,
+                    "{x:?}.next_value() is {y:?}, which is not greater than {x:?}"
+                );
+                if let Some(z) = samples.iter().find(|z| *x < **z && **z < y) {
+                    panic!("{z:?} is between {x:?} and {x:?}.next_value() = {y:?}");
+                }
+            }
+            None => {
+                if let Some(z) = samples.iter().find(|z| *z > x) {
+                    panic!("{x:?}.next_value() is None, but {z:?} is greater than {x:?}");
+                }
+            }
+        }
+    }
+}
+
+/// Check that the [`Predecessor`] implementation of `T` follows its laws on the `samples`.
+///
+/// It's the mirror of [`check_successor_laws`].
+///
+/// # Panics
+///
+/// Panics with a message describing the violation if a law is broken.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::testing::check_predecessor_laws;
+///
+/// check_predecessor_laws(&[u16::MIN, 1, 255, 256, u16::MAX]);
+/// ```
+pub fn check_predecessor_laws<T: Ord + Predecessor + Debug>(samples: &[T]) {
+    for x in samples {
+        match x.prev_value() {
+            Some(y) => {
+                assert!(
+                    y < *x,
+                    "{x:?}.prev_value() is {y:?}, which is not less than {x:?}"
+                );
+                if let Some(z) = samples.iter().find(|z| y < **z && **z < *x) {
+                    panic!("{z:?} is between {x:?}.prev_value() = {y:?} and {x:?}");
+                }
+            }
+            None => {
+                if let Some(z) = samples.iter().find(|z| *z < x) {
+                    panic!("{x:?}.prev_value() is None, but {z:?} is less than {x:?}");
+                }
+            }
+        }
+    }
+}
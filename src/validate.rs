@@ -0,0 +1,96 @@
+//! Checking the fields of a value against masks, collecting all violations.
+//!
+//! Enabled by the `validate` feature.
+
+/// A field whose value is not included in its mask.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaskViolation {
+    /// The name of the field.
+    pub field: &'static str,
+    /// The offending value, formatted by `Debug`.
+    pub value: String,
+}
+
+/// The violations found by `validate_fields!`, in the order of the checked fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaskValidateErrors {
+    pub violations: Vec<MaskViolation>,
+}
+
+impl std::fmt::Display for MaskValidateErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(
+                f,
+                "The field {} is {}, which is not included in its mask.",
+                violation.field, violation.value
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MaskValidateErrors {}
+
+/// Check the fields of a value against masks,
+/// returning `Result<(), MaskValidateErrors>` with a violation for each field not included in its mask.
+///
+/// Each `field in mask` checks `mask.included(&value.field)`,
+/// where `mask` is any expression of an `OrdMask`, like a static initialized by `LazyLock`.
+///
+/// # Examples
+///
+/// ```
+/// use ordmask::validate::MaskViolation;
+/// use ordmask::{OrdMask, ordmask, validate_fields};
+/// use std::sync::LazyLock;
+///
+/// static UNPRIVILEGED_PORTS: LazyLock<OrdMask<u16>> = LazyLock::new(|| ordmask![1024]);
+///
+/// struct Config {
+///     port: u16,
+///     sample_rate: u32,
+/// }
+///
+/// impl Config {
+///     fn validate(&self) -> Result<(), ordmask::validate::MaskValidateErrors> {
+///         validate_fields!(self, {
+///             port in UNPRIVILEGED_PORTS,
+///             sample_rate in OrdMask::from_points([44_100, 48_000]),
+///         })
+///     }
+/// }
+///
+/// assert!(Config { port: 8080, sample_rate: 48_000 }.validate().is_ok());
+///
+/// let errors = Config { port: 80, sample_rate: 22_050 }.validate().unwrap_err();
+/// assert_eq!(
+///     errors.violations,
+///     vec![
+///         MaskViolation { field: "port", value: "80".into() },
+///         MaskViolation { field: "sample_rate", value: "22050".into() },
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! validate_fields {
+    ($value:expr, { $($field:ident in $mask:expr),+ $(,)? }) => {{
+        let value = &$value;
+        let mut violations = Vec::new();
+        $(
+            if !$mask.included(&value.$field) {
+                violations.push($crate::validate::MaskViolation {
+                    field: stringify!($field),
+                    value: format!("{:?}", value.$field),
+                });
+            }
+        )+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err($crate::validate::MaskValidateErrors { violations }),
+        }
+    }};
+}
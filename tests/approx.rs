@@ -0,0 +1,50 @@
+use ordmask::{ordmask, OrdMask};
+use std::ops::Sub;
+
+#[derive(Clone, Copy, Debug)]
+struct Float(f64);
+
+impl PartialEq for Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0).is_eq()
+    }
+}
+
+impl Eq for Float {}
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Float {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Sub for Float {
+    type Output = Float;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Float(self.0 - rhs.0)
+    }
+}
+
+#[test]
+fn is_approximately_complement_of() {
+    let mask = ordmask![Float(0.1 + 0.2), Float(1.0)];
+    let complement = ordmask![_, Float(0.3), Float(1.0)];
+    assert_ne!(mask.new_complement(), complement);
+    assert!(mask.is_approximately_complement_of(&complement, Float(1e-9)));
+    assert!(complement.is_approximately_complement_of(&mask, Float(1e-9)));
+    assert!(!mask.is_approximately_complement_of(&complement, Float(0.0)));
+
+    let other = ordmask![_, Float(0.3)];
+    assert!(!mask.is_approximately_complement_of(&other, Float(1.0)));
+
+    let empty: OrdMask<Float> = ordmask![];
+    assert!(empty.is_approximately_complement_of(&ordmask![_], Float(0.0)));
+    assert!(!empty.is_approximately_complement_of(&ordmask![], Float(0.0)));
+}
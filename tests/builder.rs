@@ -0,0 +1,105 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask, RangeBoundError};
+use std::ops::Bound;
+
+#[test]
+fn builder() {
+    let mask = OrdMask::builder()
+        .include(0..10)
+        .exclude(3..5)
+        .include_from(100)
+        .build()
+        .unwrap();
+    assert_eq!(mask, ordmask![0, 3, 5, 10, 100]);
+
+    let include_then_exclude = OrdMask::builder()
+        .include(0..10)
+        .exclude(5..15)
+        .build()
+        .unwrap();
+    let exclude_then_include = OrdMask::builder()
+        .exclude(5..15)
+        .include(0..10)
+        .build()
+        .unwrap();
+    assert_eq!(include_then_exclude, ordmask![0, 5]);
+    assert_eq!(exclude_then_include, ordmask![0, 10]);
+}
+
+#[test]
+fn builder_degenerate() {
+    assert_eq!(OrdMask::<i32>::builder().build().unwrap(), OrdMask::empty());
+    assert_eq!(
+        OrdMask::<i32>::builder().include_all().build().unwrap(),
+        ordmask![_]
+    );
+    assert_eq!(
+        OrdMask::builder()
+            .include_all()
+            .exclude(0..10)
+            .build()
+            .unwrap(),
+        ordmask![_, 0, 10]
+    );
+    assert_eq!(
+        OrdMask::builder()
+            .include(0..10)
+            .exclude_all()
+            .build()
+            .unwrap(),
+        ordmask![]
+    );
+    assert_eq!(
+        OrdMask::builder()
+            .include_all()
+            .exclude_from(5)
+            .include(7..7)
+            .build()
+            .unwrap(),
+        ordmask![_, 5]
+    );
+    assert_eq!(
+        OrdMask::builder()
+            .include(..5)
+            .include(5..)
+            .build()
+            .unwrap(),
+        ordmask![_]
+    );
+}
+
+#[test]
+fn builder_matches_operations() {
+    let masks = all_masks(4);
+    for a in &masks {
+        for b in &masks {
+            // Layering the ranges of `b` as exclusions over `a` is `a - b`
+            let mut builder = OrdMask::builder();
+            for (start, end) in a.ranges() {
+                builder = builder.include((start.cloned(), end.cloned()));
+            }
+            for (start, end) in b.ranges() {
+                builder = builder.exclude((start.cloned(), end.cloned()));
+            }
+            assert_eq!(builder.build().unwrap(), a - b, "{} - {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn builder_unrepresentable_bounds() {
+    assert_eq!(
+        OrdMask::builder().include(0..=10).build(),
+        Err(RangeBoundError::IncludedEnd)
+    );
+    assert_eq!(
+        OrdMask::builder()
+            .include(0..10)
+            .exclude((Bound::Excluded(3), Bound::Excluded(5)))
+            .include(5..=7)
+            .build(),
+        Err(RangeBoundError::ExcludedStart)
+    );
+}
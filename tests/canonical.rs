@@ -0,0 +1,142 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, CanonicalOrd, OrdMask};
+use std::collections::{BTreeMap, HashSet};
+
+#[test]
+fn btree_map_keys() {
+    let masks = all_masks(5);
+    let mut map = BTreeMap::new();
+    for (i, mask) in masks.iter().enumerate() {
+        map.insert(CanonicalOrd::new(mask.clone()), i);
+    }
+    assert_eq!(map.len(), masks.len());
+
+    for (i, mask) in masks.iter().enumerate() {
+        // the same mask built by other methods
+        let rebuilt = mask.new_complement().complement();
+        let through_ranges = OrdMask::from_ranges(
+            mask.key_points()
+                .chunks(2)
+                .filter(|_| !mask.is_include_min_value())
+                .filter(|pair| pair.len() == 2)
+                .map(|pair| (pair[0], pair[1])),
+        );
+        let with_duplicates = unsafe {
+            let mut key_points = vec![-3, -3];
+            key_points.extend(mask.key_points());
+            OrdMask::with_unchecked(key_points, mask.is_include_min_value())
+        };
+        assert_eq!(map.insert(CanonicalOrd::new(rebuilt), i), Some(i));
+        assert_eq!(map.insert(CanonicalOrd::new(with_duplicates), i), Some(i));
+        if !mask.is_include_min_value() && !mask.is_include_max_value() {
+            assert_eq!(map.insert(through_ranges.into(), i), Some(i));
+        }
+    }
+    assert_eq!(map.len(), masks.len());
+}
+
+#[test]
+fn lexicographic_order() {
+    let masks: Vec<CanonicalOrd<i32>> = all_masks(5).into_iter().map(CanonicalOrd::new).collect();
+    for a in &masks {
+        for b in &masks {
+            let expected = (a.is_include_min_value(), a.key_points())
+                .cmp(&(b.is_include_min_value(), b.key_points()));
+            assert_eq!(a.cmp(b), expected, "{} {}", **a, **b);
+        }
+    }
+    assert!(CanonicalOrd::<i32>::new(ordmask![]) < CanonicalOrd::new(ordmask![_]));
+}
+
+#[test]
+fn hash_set_keys() {
+    let mut set = HashSet::new();
+    set.insert(CanonicalOrd::new(ordmask![0, 10]));
+    set.insert(CanonicalOrd::new(
+        OrdMask::in_range(0, 5) | OrdMask::in_range(5, 10),
+    ));
+    set.insert(CanonicalOrd::new(ordmask![_, 0, 10]));
+    assert_eq!(set.len(), 2);
+    assert_eq!(
+        set.into_iter()
+            .map(CanonicalOrd::into_inner)
+            .filter(|mask| mask.is_include_min_value())
+            .count(),
+        1
+    );
+}
+
+/// Every construction path of the set of `points` in the domain `0..6`.
+fn constructions(points: &[i32]) -> Vec<OrdMask<i32>> {
+    let from_points = OrdMask::from_points(points.iter().copied());
+    let complement: Vec<i32> = (0..6).filter(|x| !points.contains(x)).collect();
+    let mut xor = OrdMask::empty();
+    for x in points {
+        xor.symmetric_difference_in_place(&OrdMask::in_range(*x, x + 1));
+    }
+    let mut builder = OrdMask::builder();
+    for x in points {
+        builder = builder.include(*x..x + 1);
+    }
+    let mut with_duplicates = Vec::new();
+    for x in from_points.key_points() {
+        with_duplicates.extend([*x, *x, *x]);
+    }
+    vec![
+        from_points.clone(),
+        from_points.new_complement().complement(),
+        OrdMask::from_ranges(points.iter().map(|x| (*x, x + 1))),
+        points.iter().fold(OrdMask::empty(), |mask, x| {
+            mask | OrdMask::in_range(*x, x + 1)
+        }),
+        OrdMask::from_inclusive_ranges(points.iter().map(|x| (*x, *x))),
+        OrdMask::from_key_points_set((0..=6).collect(), |x| points.contains(x), false),
+        OrdMask::in_range(0, 6) - OrdMask::from_points(complement),
+        OrdMask::from_alternating_starts_ends(
+            points,
+            &points.iter().map(|x| x + 1).collect::<Vec<_>>(),
+            false,
+        )
+        .unwrap(),
+        xor,
+        builder.build().unwrap(),
+        unsafe { OrdMask::with_unchecked(with_duplicates, false) },
+    ]
+}
+
+#[test]
+fn serialization_is_canonical() {
+    let mut serialized_sets = HashSet::new();
+    for bits in 0..(1 << 6) {
+        let points: Vec<i32> = (0..6).filter(|i| bits & (1 << i) != 0).collect();
+        let serialized: HashSet<(String, Vec<i32>, bool)> = constructions(&points)
+            .into_iter()
+            .map(|mask| {
+                let text = mask.to_str_key_points(',');
+                let (key_points, reversed) = mask.into();
+                (text, key_points, reversed)
+            })
+            .collect();
+        assert_eq!(serialized.len(), 1, "{:?} {:?}", points, serialized);
+        serialized_sets.extend(serialized);
+    }
+    assert_eq!(serialized_sets.len(), 1 << 6);
+}
+
+#[test]
+fn normalize() {
+    for mask in all_masks(5) {
+        let mut with_duplicates = Vec::new();
+        for x in mask.key_points() {
+            with_duplicates.extend([*x, *x, *x]);
+        }
+        let mut unnormalized =
+            unsafe { OrdMask::with_unchecked(with_duplicates, mask.is_include_min_value()) };
+        assert_eq!(unnormalized.is_normalized(), mask.key_points().is_empty());
+        unnormalized.normalize();
+        assert!(unnormalized.is_normalized());
+        assert_eq!(unnormalized, mask);
+    }
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn operations_with_capacity() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            let n = a.key_points().len() + b.key_points().len();
+            let results = [
+                (OrdMask::union_with_capacity(a, b, None), a | b),
+                (OrdMask::intersection_with_capacity(a, b, None), a & b),
+                (OrdMask::minus_with_capacity(a, b, None), a - b),
+                (
+                    OrdMask::symmetric_difference_with_capacity(a, b, None),
+                    a ^ b,
+                ),
+            ];
+            for (result, expected) in results {
+                assert_eq!(result, expected, "{} and {}", a, b);
+                assert!(result.capacity() >= n, "{} and {}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn capacity_hint() {
+    let a = ordmask![0, 10];
+    let b = ordmask![5, 20];
+    let union = OrdMask::union_with_capacity(&a, &b, Some(100));
+    assert_eq!(union, ordmask![0, 20]);
+    assert!(union.capacity() >= 100);
+
+    // The hint never reserves less than the sum of the lengths
+    let intersection = OrdMask::intersection_with_capacity(&ordmask![0, 2, 4, 6], &a, Some(1));
+    assert_eq!(intersection, ordmask![0, 2, 4, 6]);
+    assert!(intersection.capacity() >= 6);
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "io")]
+
+mod common;
+
+use common::{masks_over, min_value_constructions, Lcg};
+use ordmask::chunked::ChunkedMaskReader;
+use ordmask::{ordmask, OrdMask};
+use std::collections::HashSet;
+use std::io::{Cursor, ErrorKind};
+use std::ops::Bound;
+
+fn write(mask: &OrdMask<u64>, chunk_points: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    mask.write_chunked(&mut bytes, chunk_points).unwrap();
+    bytes
+}
+
+fn check(mask: &OrdMask<u64>, chunk_points: usize, probes: &[u64]) {
+    let reader = ChunkedMaskReader::new(Cursor::new(write(mask, chunk_points))).unwrap();
+    // the written mask is folded
+    let mut folded = mask.clone();
+    folded.fold_min_value();
+    let mask = &folded;
+    assert_eq!(reader.len(), mask.key_points().len());
+    assert_eq!(
+        reader.load_window(..).unwrap(),
+        *mask,
+        "{} by {}",
+        mask,
+        chunk_points
+    );
+    for x in probes {
+        assert_eq!(
+            reader.included(x).unwrap(),
+            mask.included(x),
+            "{} by {} at {}",
+            mask,
+            chunk_points,
+            x
+        );
+    }
+    for start in probes {
+        for end in probes {
+            assert_eq!(
+                reader.load_window(*start..*end).unwrap(),
+                mask.intersection_with_range(*start, *end),
+                "{} by {} in [{}, {})",
+                mask,
+                chunk_points,
+                start,
+                end
+            );
+        }
+        assert_eq!(
+            reader.load_window(*start..).unwrap(),
+            mask.clone() & OrdMask::not_less_than(*start),
+            "{} by {} from {}",
+            mask,
+            chunk_points,
+            start
+        );
+        assert_eq!(
+            reader.load_window(..*start).unwrap(),
+            mask.clone() & OrdMask::less_than(*start),
+            "{} by {} to {}",
+            mask,
+            chunk_points,
+            start
+        );
+    }
+}
+
+#[test]
+fn chunk_boundaries() {
+    let probes: Vec<u64> = (0..=12).collect();
+    for mask in masks_over(&[1u64, 3, 5, 7, 9, 11]) {
+        for chunk_points in 1..=7 {
+            check(&mask, chunk_points, &probes);
+        }
+    }
+}
+
+#[test]
+fn load_window_converts_bounds() {
+    let mask = ordmask![0u64, 10, 20, u64::MAX];
+    let reader = ChunkedMaskReader::new(Cursor::new(write(&mask, 1))).unwrap();
+    assert_eq!(reader.load_window(5..=15).unwrap(), ordmask![5, 10]);
+    assert_eq!(
+        reader
+            .load_window((Bound::Excluded(5), Bound::Included(25)))
+            .unwrap(),
+        ordmask![6, 10, 20, 26]
+    );
+    assert_eq!(
+        reader.load_window(18..=u64::MAX).unwrap(),
+        ordmask![20, u64::MAX]
+    );
+    assert_eq!(
+        reader
+            .load_window((Bound::Excluded(u64::MAX), Bound::Unbounded))
+            .unwrap(),
+        ordmask![]
+    );
+}
+
+#[test]
+fn chunked_pseudo_random() {
+    let mut lcg = Lcg::new(11);
+    let mut next = || lcg.next_u32() as u64;
+    for len in [0, 1, 63, 64, 65, 500] {
+        let mut point = next() % 1000;
+        let mut key_points = Vec::with_capacity(len);
+        for _ in 0..len {
+            key_points.push(point);
+            point += 1 + next() % 1000;
+        }
+        let reversed = next() % 2 == 0;
+        let mask = OrdMask::from_sorted_key_points_checking_strict(key_points, reversed).unwrap();
+        let probes: Vec<u64> = (0..40)
+            .map(|_| next() % (point + 10))
+            .chain(mask.key_points().iter().step_by(16).copied())
+            .collect();
+        for chunk_points in [1, 16, 64] {
+            check(&mask, chunk_points, &probes);
+        }
+    }
+}
+
+#[test]
+fn chunked_extreme_values() {
+    let mask = ordmask![_, 0u64, 1, u64::MAX];
+    check(&mask, 2, &[0, 1, 2, u64::MAX - 1, u64::MAX]);
+}
+
+#[test]
+fn invalid_chunked_data() {
+    let bytes = write(&ordmask![1u64, 10, 20], 2);
+    for invalid in [
+        Vec::new(),
+        bytes[1..].to_vec(),
+        bytes[..bytes.len() - 1].to_vec(),
+        [bytes.clone(), vec![0]].concat(),
+    ] {
+        let error = ChunkedMaskReader::new(Cursor::new(invalid)).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    // the key points in a chunk are not increasing
+    let mut bytes = bytes;
+    bytes[8..16].copy_from_slice(&0u64.to_le_bytes());
+    let reader = ChunkedMaskReader::new(Cursor::new(bytes)).unwrap();
+    assert_eq!(
+        reader.included(&5).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn serialization_is_canonical() {
+    let mut serialized_sets = HashSet::new();
+    for bits in 0..(1 << 7) {
+        let serialized: HashSet<Vec<u8>> = min_value_constructions(bits)
+            .iter()
+            .map(|mask| write(mask, 2))
+            .collect();
+        assert_eq!(serialized.len(), 1, "{:07b}", bits);
+        serialized_sets.extend(serialized);
+    }
+    assert_eq!(serialized_sets.len(), 1 << 7);
+}
@@ -0,0 +1,70 @@
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn split_into_chunks() {
+    let mask = ordmask![0, 5, 12, 30];
+    let chunks = mask.split_into_chunks(10);
+    assert_eq!(
+        chunks,
+        vec![ordmask![0, 5], ordmask![12, 20], ordmask![20, 30]]
+    );
+
+    assert_eq!(ordmask![0, 10].split_into_chunks(10), vec![ordmask![0, 10]]);
+    assert_eq!(ordmask![0, 11].split_into_chunks(10).len(), 2);
+    assert_eq!(ordmask![0, 100].split_into_chunks(1).len(), 100);
+
+    let mask: OrdMask<i32> = ordmask![_];
+    assert_eq!(mask.split_into_chunks(10), vec![ordmask![_]]);
+    assert_eq!(ordmask![3].split_into_chunks(10), vec![ordmask![3]]);
+}
+
+#[test]
+fn split_and_merge_chunks() {
+    let masks = [
+        ordmask![0, 5, 12, 30],
+        ordmask![_, 0, 5, 12, 30],
+        ordmask![0, 5, 12, 30, 31],
+        ordmask![_, -7, 3, 4, 9, 10, 11, 12, 13, 29],
+    ];
+    for mask in &masks {
+        for chunk_size in 1..12 {
+            let chunks = mask.split_into_chunks(chunk_size);
+            for (i, chunk) in chunks.iter().enumerate() {
+                let start = mask.key_points()[0] + chunk_size * i as i32;
+                let end = start + chunk_size;
+                for x in start.max(-20)..end.min(40) {
+                    assert_eq!(chunk.included(&x), mask.included(&x));
+                }
+            }
+            assert_eq!(OrdMask::merge_chunks(chunks, chunk_size), *mask);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn split_into_chunks_should_panic() {
+    let _ = ordmask![0, 10].split_into_chunks(0);
+}
+
+#[test]
+fn split_into_chunks_near_max() {
+    let mask = ordmask![0u8, 255];
+    let chunks = mask.split_into_chunks(200);
+    assert_eq!(chunks, vec![ordmask![0, 200], ordmask![200, 255]]);
+    assert_eq!(OrdMask::merge_chunks(chunks, 200), mask);
+
+    let mask = ordmask![_, 250u8, 251, 255];
+    for chunk_size in [1, 2, 4, 5, 6, 255] {
+        let chunks = mask.split_into_chunks(chunk_size);
+        assert_eq!(chunks.len(), 5usize.div_ceil(chunk_size as usize));
+        assert_eq!(OrdMask::merge_chunks(chunks, chunk_size), mask);
+    }
+}
+
+#[test]
+#[should_panic(expected = "not in its window")]
+fn merge_chunks_not_contiguous() {
+    let chunks = ordmask![0, 5, 12, 30].split_into_chunks(10);
+    let _ = OrdMask::merge_chunks(chunks, 5);
+}
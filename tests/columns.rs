@@ -0,0 +1,85 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, Error, OrdMask, StartsEndsError};
+use std::ops::Bound;
+
+#[test]
+fn from_alternating_starts_ends() {
+    for mask in all_masks(6) {
+        let (starts, ends): (Vec<i32>, Vec<i32>) = mask
+            .ranges()
+            .filter_map(|range| match range {
+                (Bound::Included(start), Bound::Excluded(end)) => Some((*start, *end)),
+                _ => None,
+            })
+            .unzip();
+        if mask.is_include_min_value() || mask.is_include_max_value() {
+            continue;
+        }
+        assert_eq!(
+            OrdMask::from_alternating_starts_ends(&starts, &ends, false),
+            Ok(mask.clone())
+        );
+        assert_eq!(
+            OrdMask::from_alternating_starts_ends(&starts, &ends, true),
+            Ok(mask.new_complement())
+        );
+    }
+}
+
+#[test]
+fn from_alternating_starts_ends_touching() {
+    assert_eq!(
+        OrdMask::from_alternating_starts_ends(&[0, 5, 10], &[5, 10, 15], false),
+        Ok(ordmask![0, 15])
+    );
+    assert_eq!(
+        OrdMask::from_alternating_starts_ends(&[0, 5], &[5, 10], true),
+        Ok(ordmask![_, 0, 10])
+    );
+    assert_eq!(
+        OrdMask::<i32>::from_alternating_starts_ends(&[], &[], true),
+        Ok(ordmask![_])
+    );
+}
+
+#[test]
+fn from_alternating_starts_ends_errors() {
+    let test_cases = vec![
+        (
+            vec![0],
+            vec![],
+            StartsEndsError::LengthMismatch { starts: 1, ends: 0 },
+        ),
+        (
+            vec![5],
+            vec![0],
+            StartsEndsError::Order(Error::Falling { index: 1 }),
+        ),
+        (
+            vec![5],
+            vec![5],
+            StartsEndsError::Order(Error::DuplicateKeyPoint { index: 1 }),
+        ),
+        (
+            vec![0, 3],
+            vec![5, 10],
+            StartsEndsError::Order(Error::Falling { index: 2 }),
+        ),
+        (
+            vec![0, 5, 12],
+            vec![5, 10, 11],
+            StartsEndsError::Order(Error::Falling { index: 5 }),
+        ),
+    ];
+    for (starts, ends, expected) in test_cases {
+        assert_eq!(
+            OrdMask::from_alternating_starts_ends(&starts, &ends, false),
+            Err(expected),
+            "{:?} {:?}",
+            starts,
+            ends
+        );
+    }
+}
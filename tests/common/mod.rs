@@ -0,0 +1,98 @@
+#![allow(dead_code)]
+
+use ordmask::OrdMask;
+
+/// A linear congruential generator for reproducible pseudo-random tests.
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    /// The next value, using the high 31 bits of the state.
+    pub fn next_u32(&mut self) -> u32 {
+        self.step();
+        (self.0 >> 33) as u32
+    }
+
+    /// The next value in `[0, 1)`, using the high 53 bits of the state.
+    pub fn next_f64(&mut self) -> f64 {
+        self.step();
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn step(&mut self) {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+    }
+}
+
+/// All masks whose key points are a subset of `0..n`.
+pub fn all_masks(n: i32) -> Vec<OrdMask<i32>> {
+    let mut masks = Vec::new();
+    for bits in 0..(1 << n) {
+        let key_points: Vec<i32> = (0..n).filter(|i| bits & (1 << i) != 0).collect();
+        masks.push(OrdMask::from(key_points.clone()));
+        masks.push(OrdMask::from_complement(key_points));
+    }
+    masks
+}
+
+/// All masks whose key points are a subset of `points`.
+pub fn masks_over<T: Ord + Clone>(points: &[T]) -> Vec<OrdMask<T>> {
+    let mut masks = Vec::new();
+    for bits in 0..(1 << points.len()) {
+        let key_points: Vec<T> = (0..points.len())
+            .filter(|i| bits & (1 << i) != 0)
+            .map(|i| points[i].clone())
+            .collect();
+        masks.push(OrdMask::from(key_points.clone()));
+        masks.push(OrdMask::from_complement(key_points));
+    }
+    masks
+}
+
+/// Every construction path of the subset of `0..=u64::MAX` chosen by `bits`,
+/// whose bits `0..6` are the values `0..6` and bit `6` is the tail `6..`.
+///
+/// Some paths put a key point at the minimum value and others fold it,
+/// so the serializations must fold it to be unique.
+pub fn min_value_constructions(bits: u32) -> Vec<OrdMask<u64>> {
+    let points: Vec<u64> = (0..6).filter(|i| bits & (1 << i) != 0).collect();
+    let missing: Vec<u64> = (0..6).filter(|i| bits & (1 << i) == 0).collect();
+    let tail = bits & (1 << 6) != 0;
+    let tail_mask = match tail {
+        true => OrdMask::not_less_than(6),
+        false => OrdMask::empty(),
+    };
+
+    let from_ranges = OrdMask::from_ranges(points.iter().map(|x| (*x, x + 1))) | tail_mask.clone();
+    let mut folded = from_ranges.clone();
+    if folded.key_points().first() == Some(&0) {
+        folded = OrdMask::from_parts_unchecked(folded.key_points()[1..].to_vec(), true);
+    }
+    let mut from_universal = OrdMask::universal() - OrdMask::from_points(missing);
+    if !tail {
+        from_universal = from_universal - OrdMask::not_less_than(6);
+    }
+    let mut inclusive: Vec<(u64, u64)> = points.iter().map(|x| (*x, *x)).collect();
+    if tail {
+        inclusive.push((6, u64::MAX));
+    }
+    let mut with_duplicates = Vec::new();
+    for x in from_ranges.key_points() {
+        with_duplicates.extend([*x, *x, *x]);
+    }
+    vec![
+        from_ranges.clone(),
+        folded,
+        from_universal.clone(),
+        from_universal.new_complement().complement(),
+        OrdMask::from_inclusive_ranges(inclusive),
+        OrdMask::from_points(points) | tail_mask,
+        OrdMask::from_parts_unchecked(with_duplicates, false),
+    ]
+}
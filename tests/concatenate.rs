@@ -0,0 +1,43 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn concatenate_at() {
+    let masks = all_masks(4);
+    for lower in &masks {
+        for upper in &masks {
+            for boundary in -1..=4 {
+                assert_eq!(
+                    OrdMask::concatenate_at(boundary, lower.clone(), upper.clone()),
+                    (lower & &OrdMask::less_than(boundary))
+                        | (upper & &OrdMask::not_less_than(boundary)),
+                    "{} below {} and {} above",
+                    lower,
+                    boundary,
+                    upper
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn concatenate_at_key_point() {
+    let lower = ordmask![0, 10];
+    let upper = ordmask![_, 10, 20];
+    assert_eq!(
+        OrdMask::concatenate_at(10, lower.clone(), upper.clone()),
+        ordmask![0, 10, 20]
+    );
+    assert_eq!(
+        OrdMask::concatenate_at(5, lower.clone(), upper.clone()),
+        ordmask![0, 10, 20]
+    );
+    assert_eq!(OrdMask::concatenate_at(15, upper, lower), ordmask![_, 10]);
+    assert_eq!(
+        OrdMask::concatenate_at(0, OrdMask::universal(), OrdMask::empty()),
+        ordmask![_, 0]
+    );
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, ConsistencyError, OrdMask};
+use std::cmp::Ordering;
+
+#[test]
+fn consistency_check() {
+    for mask in all_masks(6) {
+        assert_eq!(mask.consistency_check(), Ok(()), "{}", mask);
+    }
+    let unsimplified = unsafe { OrdMask::with_unchecked(vec![0, 0, 1, 5, 5, 5], true) };
+    assert_eq!(unsimplified.consistency_check(), Ok(()));
+
+    let falling = unsafe { OrdMask::with_unchecked(vec![0, 10, 5, 20], false) };
+    assert_eq!(
+        falling.consistency_check(),
+        Err(ConsistencyError::Falling { index: 2 })
+    );
+    assert!(!falling.is_valid());
+}
+
+/// A key type whose `Ord` is not a total order, comparing everything as equal but `PartialEq` by value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Broken(i32);
+
+impl PartialOrd for Broken {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Broken {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+#[test]
+fn consistency_check_parity_mismatch() {
+    let mask = unsafe { OrdMask::with_unchecked(vec![Broken(0), Broken(1)], false) };
+    assert_eq!(
+        mask.consistency_check(),
+        Err(ConsistencyError::ParityMismatch { index: 0 })
+    );
+    assert!(mask
+        .consistency_check()
+        .unwrap_err()
+        .to_string()
+        .contains("parity"));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn union_with_inconsistent_operand() {
+    let falling = unsafe { OrdMask::with_unchecked(vec![0, 10, 5, 20], false) };
+    let _ = OrdMask::union(&[&falling, &ordmask![0, 1]]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn operator_with_inconsistent_operand() {
+    let falling = unsafe { OrdMask::with_unchecked(vec![0, 10, 5, 20], false) };
+    let _ = &ordmask![0, 1] & &falling;
+}
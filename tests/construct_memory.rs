@@ -0,0 +1,62 @@
+//! Peak memory of the bulk constructors, measured by a counting allocator.
+//!
+//! The allocator counts the whole test binary, so this file has a single test.
+
+use ordmask::OrdMask;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let current = CURRENT.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(current, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Get the result of `f` and the peak memory allocated by it over the memory allocated before it.
+fn peak_of<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let base = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(base, Ordering::SeqCst);
+    let result = f();
+    (result, PEAK.load(Ordering::SeqCst) - base)
+}
+
+#[test]
+fn bulk_constructors_do_not_reserve_the_input_size() {
+    const N: u64 = 1_000_000;
+    const LIMIT: usize = 1024;
+
+    // only the first and the last points change the state
+    let map: BTreeMap<u64, bool> = (0..N).map(|x| (x, x < N - 1)).collect();
+    let (mask, peak) = peak_of(|| OrdMask::from_key_points_map(map, false));
+    assert_eq!(*mask.key_points(), [0, N - 1]);
+    assert!(peak < LIMIT, "from_key_points_map peaked at {peak} bytes");
+
+    let set: BTreeSet<u64> = (0..N).collect();
+    let (mask, peak) = peak_of(|| OrdMask::from_key_points_set(set, |x| *x < N - 1, false));
+    assert_eq!(*mask.key_points(), [0, N - 1]);
+    assert!(peak < LIMIT, "from_key_points_set peaked at {peak} bytes");
+
+    let events = (0..N).map(|x| (x, x < N - 1));
+    let (mask, peak) = peak_of(|| OrdMask::from_sorted_bool_events(events).unwrap());
+    assert_eq!(*mask.key_points(), [0, N - 1]);
+    assert!(
+        peak < LIMIT,
+        "from_sorted_bool_events peaked at {peak} bytes"
+    );
+}
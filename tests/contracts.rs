@@ -0,0 +1,97 @@
+#![cfg(feature = "contracts")]
+
+use ordmask::contracts::{count_comparisons, Counted};
+use ordmask::OrdMask;
+
+type Mask = OrdMask<Counted<i64>>;
+
+fn counted_mask(points: impl IntoIterator<Item = i64>, reversed: bool) -> Mask {
+    let points: Vec<Counted<i64>> = points.into_iter().map(Counted).collect();
+    match reversed {
+        true => OrdMask::from_complement(points),
+        false => OrdMask::from(points),
+    }
+}
+
+/// Pairs of masks with `n` and `m` key points, interleaved, overlapping and disjoint.
+fn pairs(n: i64, m: i64) -> Vec<(Mask, Mask)> {
+    vec![
+        (
+            counted_mask((0..n).map(|i| i * 2), false),
+            counted_mask((0..m).map(|i| i * 2 + 1), true),
+        ),
+        (
+            counted_mask((0..n).map(|i| i * 3), true),
+            counted_mask((0..m).map(|i| i * 2), false),
+        ),
+        (
+            counted_mask(0..n, false),
+            counted_mask((0..m).map(|i| i + n), false),
+        ),
+    ]
+}
+
+#[test]
+fn pairwise_operations_are_linear() {
+    for (n, m) in [(1, 1), (10, 1000), (1000, 10), (1000, 1000), (4096, 4096)] {
+        let bound = 8 * (n + m) as usize;
+        for (a, b) in pairs(n, m) {
+            let (_, union) = count_comparisons(|| &a | &b);
+            let (_, intersection) = count_comparisons(|| &a & &b);
+            let (_, difference) = count_comparisons(|| &a - &b);
+            let (_, symmetric_difference) = count_comparisons(|| &a ^ &b);
+            for (name, count) in [
+                ("union", union),
+                ("intersection", intersection),
+                ("difference", difference),
+                ("symmetric difference", symmetric_difference),
+            ] {
+                assert!(
+                    count <= bound,
+                    "{} of {} and {} key points took {} comparisons",
+                    name,
+                    n,
+                    m,
+                    count
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn included_is_logarithmic() {
+    for n in [1, 2, 3, 7, 8, 9, 100, 1000, 1024, 65536] {
+        let mask = counted_mask(0..n, false);
+        let bound = (n as f64).log2().ceil() as usize + 1;
+        for value in [-1, 0, n / 3, n / 2, n - 1, n] {
+            let (_, count) = count_comparisons(|| mask.included(&Counted(value)));
+            assert!(
+                count <= bound,
+                "included on {} key points took {} comparisons",
+                n,
+                count
+            );
+        }
+    }
+}
+
+#[test]
+fn simplify_is_linear() {
+    for n in [0, 1, 2, 100, 10000] {
+        for repeats in [1, 2, 3] {
+            let points: Vec<Counted<i64>> = (0..n)
+                .flat_map(|i| std::iter::repeat_n(Counted(i), repeats))
+                .collect();
+            let len = points.len();
+            let mut mask = unsafe { OrdMask::with_unchecked(points, false) };
+            let (_, count) = count_comparisons(|| mask.simplify());
+            assert!(
+                count <= 2 * len,
+                "simplify on {} key points took {} comparisons",
+                len,
+                count
+            );
+        }
+    }
+}
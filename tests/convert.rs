@@ -0,0 +1,225 @@
+mod common;
+
+use common::{all_masks, masks_over};
+use ordmask::{ordmask, ConvertError, Error, OrdMask};
+
+#[test]
+fn try_from() {
+    assert_eq!(OrdMask::try_from(vec![0, 1, 1, 2]), Ok(ordmask![0, 2]));
+    assert_eq!(
+        OrdMask::try_from(vec![0, 2, 1]),
+        Err(Error::Falling { index: 2 })
+    );
+}
+
+#[test]
+fn try_from_complement_vec() {
+    assert_eq!(
+        OrdMask::<i32>::try_from_complement_vec(vec![]),
+        Ok(ordmask![_])
+    );
+    assert_eq!(
+        OrdMask::try_from_complement_vec(vec![0, 1, 1, 2]),
+        Ok(ordmask![_, 0, 2])
+    );
+    assert_eq!(
+        OrdMask::try_from_complement_vec(vec![0, 2, 1]),
+        Err(Error::Falling { index: 2 })
+    );
+    assert_eq!(
+        OrdMask::try_from_complement_vec(vec![0, 10, 20]),
+        Ok(OrdMask::from_complement(vec![0, 10, 20]))
+    );
+}
+
+#[test]
+fn from_sorted_key_points_checking_strict() {
+    assert_eq!(
+        OrdMask::<i32>::from_sorted_key_points_checking_strict(vec![], false),
+        Ok(ordmask![])
+    );
+    assert_eq!(
+        OrdMask::from_sorted_key_points_checking_strict(vec![0, 1, 2], false),
+        Ok(ordmask![0, 1, 2])
+    );
+    assert_eq!(
+        OrdMask::from_sorted_key_points_checking_strict(vec![0, 1, 2], true),
+        Ok(ordmask![_, 0, 1, 2])
+    );
+    assert_eq!(
+        OrdMask::from_sorted_key_points_checking_strict(vec![0, 0, 1, 1], false),
+        Err(Error::DuplicateKeyPoint { index: 1 })
+    );
+    assert_eq!(
+        OrdMask::from_sorted_key_points_checking_strict(vec![0, 1, 0, 0], false),
+        Err(Error::Falling { index: 2 })
+    );
+}
+
+#[test]
+fn error_message() {
+    let message = format!("{}", Error::DuplicateKeyPoint { index: 3 });
+    assert!(message.contains("strictly increasing"));
+    assert!(message.contains("index 3 is equal to the value at index 2"));
+    let message = format!("{:?}", Error::Falling { index: 1 });
+    assert!(message.contains("index 1 is less than the value at index 0"));
+}
+
+#[test]
+fn sortedness() {
+    let test_cases = vec![
+        (vec![], true, true),
+        (vec![0], true, true),
+        (vec![0, 1, 2], true, true),
+        (vec![0, 1, 1, 2], true, false),
+        (vec![0, 2, 1], false, false),
+        (vec![1, 1, 0], false, false),
+    ];
+
+    for (key_points, non_decreasing, strictly_sorted) in test_cases {
+        let mask = unsafe { OrdMask::with_unchecked(key_points.clone(), false) };
+        assert_eq!(mask.is_non_decreasing(), non_decreasing, "{:?}", key_points);
+        assert_eq!(mask.is_valid(), non_decreasing, "{:?}", key_points);
+        assert_eq!(
+            mask.is_strictly_sorted(),
+            strictly_sorted,
+            "{:?}",
+            key_points
+        );
+        assert_eq!(
+            mask.key_points_are_strictly_increasing(),
+            strictly_sorted,
+            "{:?}",
+            key_points
+        );
+    }
+
+    assert!(OrdMask::try_from(vec![0, 1, 1, 2])
+        .unwrap()
+        .is_strictly_sorted());
+}
+
+#[test]
+fn flat_round_trip() {
+    for mask in all_masks(6) {
+        let (points, reversed) = mask.as_flat();
+        assert_eq!(reversed, mask.is_include_min_value());
+        assert!(points.windows(2).all(|pair| pair[0] < pair[1]), "{}", mask);
+        assert_eq!(OrdMask::from_flat(points, reversed), Ok(mask.clone()));
+    }
+    assert_eq!(
+        OrdMask::from_flat(&[0, 0], true),
+        Err(Error::DuplicateKeyPoint { index: 1 })
+    );
+}
+
+#[test]
+fn convert_widening() {
+    for mask in masks_over(&[0u32, 1, 1000, u32::MAX - 1, u32::MAX]) {
+        let wide: OrdMask<u64> = mask.clone().convert();
+        for x in [0, 1, 2, 999, 1000, u32::MAX - 1, u32::MAX] {
+            assert_eq!(
+                wide.included(&(x as u64)),
+                mask.included(&x),
+                "{} at {}",
+                mask,
+                x
+            );
+        }
+        assert_eq!(wide.try_convert::<u32>(), Ok(mask));
+    }
+    let mask: OrdMask<i64> = ordmask![_, -5i8, 5].convert();
+    assert_eq!(mask, ordmask![_, -5i64, 5]);
+}
+
+#[test]
+fn try_convert_narrowing() {
+    let mask = ordmask![_, 10u64, u32::MAX as u64, u32::MAX as u64 + 1, 1 << 40];
+    assert_eq!(
+        mask.clone().try_convert::<u32>(),
+        Err(ConvertError {
+            index: 2,
+            value: u32::MAX as u64 + 1
+        })
+    );
+    assert_eq!(
+        ordmask![-1i64, 10].try_convert::<u8>(),
+        Err(ConvertError {
+            index: 0,
+            value: -1
+        })
+    );
+    assert_eq!(
+        ConvertError {
+            index: 2,
+            value: 5u64
+        }
+        .to_string(),
+        "The key point 5 at index 2 doesn't fit the target type."
+    );
+}
+
+#[test]
+fn tuple_round_trip() {
+    for mask in all_masks(5) {
+        let stored: (Vec<i32>, bool) = mask.clone().into();
+        assert_eq!(stored.1, mask.is_include_min_value());
+        assert_eq!(OrdMask::try_from(stored), Ok(mask.clone()));
+
+        let stored: (Vec<i32>, bool) = mask.new_complement().into();
+        assert_eq!(OrdMask::try_from(stored), Ok(mask.new_complement()));
+    }
+}
+
+#[test]
+fn tuple_try_from_simplifies() {
+    assert_eq!(
+        OrdMask::try_from((vec![0, 0, 5, 10, 10, 10], true)),
+        Ok(ordmask![_, 5, 10])
+    );
+    assert_eq!(
+        OrdMask::try_from((vec![0, 10, 5], false)),
+        Err(Error::Falling { index: 2 })
+    );
+    assert_eq!(
+        OrdMask::try_from((vec![10, 0], true)),
+        OrdMask::<i32>::try_from(vec![10, 0]).map(OrdMask::complement)
+    );
+}
+
+#[test]
+fn parts_round_trip() {
+    for mask in all_masks(5) {
+        let (key_points, reversed) = mask.clone().into_parts_unchecked();
+        assert_eq!(key_points, *mask.key_points());
+        assert_eq!(reversed, mask.is_include_min_value());
+        assert_eq!(OrdMask::from_parts_unchecked(key_points, reversed), mask);
+    }
+
+    let unsorted = OrdMask::from_parts_unchecked(vec![10, 0], false);
+    assert!(!unsorted.is_valid());
+    assert_eq!(unsorted.into_parts_unchecked(), (vec![10, 0], false));
+}
+
+#[test]
+fn from_key_points_fn() {
+    for mask in all_masks(5) {
+        let points = mask.key_points().clone();
+        let reversed = mask.is_include_min_value();
+        let built = OrdMask::from_key_points_fn(points.len(), |i| points[i], reversed);
+        assert_eq!(built, Ok(mask));
+    }
+
+    assert_eq!(
+        OrdMask::from_key_points_fn(4, |i| i / 2, false),
+        Ok(OrdMask::<usize>::empty())
+    );
+    assert_eq!(
+        OrdMask::from_key_points_fn(0, |i| i, true),
+        Ok(OrdMask::universal())
+    );
+    assert_eq!(
+        OrdMask::from_key_points_fn(5, |i| 10 - i, false),
+        Err(Error::Falling { index: 1 })
+    );
+}
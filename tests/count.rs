@@ -0,0 +1,211 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, MultipleBoundaries, OrdMask};
+use std::ops::Bound;
+
+#[test]
+fn boundary_count_in_range() {
+    let mask = ordmask![_, 0, 10, 20];
+    assert_eq!(mask.boundary_count_in_range(&-100, &100), 3);
+    assert_eq!(mask.boundary_count_in_range(&0, &20), 2);
+    assert_eq!(mask.boundary_count_in_range(&1, &20), 1);
+    assert_eq!(mask.boundary_count_in_range(&0, &21), 3);
+    assert_eq!(mask.boundary_count_in_range(&10, &10), 0);
+    assert_eq!(mask.boundary_count_in_range(&10, &11), 1);
+    assert_eq!(mask.boundary_count_in_range(&20, &10), 0);
+
+    let mask: OrdMask<i32> = ordmask![_];
+    assert_eq!(mask.boundary_count_in_range(&-100, &100), 0);
+}
+
+#[test]
+fn count_state_changes_in_range() {
+    for mask in all_masks(5) {
+        for start in -1..=5 {
+            for end in -1..=6 {
+                // A key point `x` changes the state between `x - 1` and `x`
+                let changes = (start..end)
+                    .filter(|x| mask.included(x) != mask.included(&(x - 1)))
+                    .count();
+                assert_eq!(
+                    mask.count_state_changes_in_range(&start, &end),
+                    changes,
+                    "{} in [{}, {})",
+                    mask,
+                    start,
+                    end
+                );
+            }
+        }
+    }
+}
+
+/// Get the included ranges of `mask` as `[lo, hi)`, with unbounded ends at the extreme values.
+fn bounded_ranges(mask: &OrdMask<i32>) -> Vec<(i32, i32)> {
+    mask.ranges()
+        .map(|(start, end)| {
+            let lo = match start {
+                Bound::Included(lo) => *lo,
+                _ => i32::MIN,
+            };
+            let hi = match end {
+                Bound::Excluded(hi) => *hi,
+                _ => i32::MAX,
+            };
+            (lo, hi)
+        })
+        .collect()
+}
+
+#[test]
+fn count_ranges_in_window() {
+    for mask in all_masks(6) {
+        let ranges = bounded_ranges(&mask);
+        for start in -1..8 {
+            for end in -1..8 {
+                let intersecting = ranges
+                    .iter()
+                    .filter(|(lo, hi)| start < end && *lo.max(&start) < *hi.min(&end))
+                    .count();
+                let complete = ranges
+                    .iter()
+                    .filter(|(lo, hi)| {
+                        start <= *lo && *hi <= end && *lo != i32::MIN && *hi != i32::MAX
+                    })
+                    .count();
+                assert_eq!(
+                    mask.count_included_ranges_in_window(&start, &end),
+                    intersecting,
+                    "{mask} [{start}, {end})"
+                );
+                assert_eq!(
+                    mask.count_complete_ranges_in_window(&start, &end),
+                    complete,
+                    "{mask} [{start}, {end})"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn contains_key_point() {
+    for mask in all_masks(5) {
+        for v in -1..6 {
+            assert_eq!(
+                mask.contains_key_point(&v),
+                mask.key_points().contains(&v),
+                "{} at {}",
+                mask,
+                v
+            );
+            assert_eq!(
+                mask.contains_key_point(&v),
+                mask.boundary_count_in_range(&v, &(v + 1)) == 1,
+                "{} at {}",
+                mask,
+                v
+            );
+        }
+    }
+}
+
+#[test]
+fn remove_key_point() {
+    for mask in all_masks(5) {
+        for v in -1..6 {
+            let mut removed = mask.clone();
+            assert_eq!(
+                removed.remove_key_point(&v),
+                mask.contains_key_point(&v),
+                "{} at {}",
+                mask,
+                v
+            );
+            assert!(removed.is_strictly_sorted(), "{} at {}", mask, v);
+            for x in -2..7 {
+                let flipped = mask.contains_key_point(&v) && x >= v;
+                assert_eq!(
+                    removed.included(&x),
+                    mask.included(&x) ^ flipped,
+                    "{} without {} at {}",
+                    mask,
+                    v,
+                    x
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn count_boundary_crossings_between() {
+    for mask in all_masks(6) {
+        for a in -1..7 {
+            for b in -1..7 {
+                let expected = mask
+                    .key_points()
+                    .iter()
+                    .filter(|x| a < **x && **x < b)
+                    .count();
+                let count = mask.count_boundary_crossings_between(&a, &b);
+                assert_eq!(count, expected, "{} in ({}, {})", mask, a, b);
+                if a < b {
+                    let differs = mask.included(&a) != mask.included(&b);
+                    assert_eq!(
+                        differs,
+                        (count + mask.contains_key_point(&b) as usize) % 2 == 1
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn boundary_between() {
+    for mask in all_masks(5) {
+        for lo in -1..7 {
+            for hi in -1..7 {
+                let (a, b) = (lo.min(hi), lo.max(hi));
+                let inside: Vec<&i32> = mask
+                    .key_points()
+                    .iter()
+                    .filter(|x| a < **x && **x <= b)
+                    .collect();
+                let expected = match inside.len() {
+                    0 => Ok(None),
+                    1 => Ok(Some(inside[0])),
+                    count => Err(MultipleBoundaries { count }),
+                };
+                assert_eq!(
+                    mask.boundary_between(&lo, &hi),
+                    expected,
+                    "{mask} ({lo}, {hi})"
+                );
+                if let Ok(boundary) = expected {
+                    assert_eq!(
+                        boundary.is_some(),
+                        mask.included(&lo) != mask.included(&hi),
+                        "{mask} ({lo}, {hi})"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn boundary_between_reversed_bracket() {
+    // included below 10 and at or above 20: the inclusion falls at 10 and rises at 20
+    let mask = ordmask![_, 10, 20];
+    assert_eq!(mask.boundary_between(&5, &15), Ok(Some(&10)));
+    assert_eq!(mask.boundary_between(&25, &15), Ok(Some(&20)));
+    assert_eq!(
+        mask.boundary_between(&25, &5),
+        Err(MultipleBoundaries { count: 2 })
+    );
+    assert_eq!(mask.boundary_between(&12, &18), Ok(None));
+    assert_eq!(mask.boundary_between(&10, &10), Ok(None));
+}
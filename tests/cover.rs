@@ -0,0 +1,100 @@
+use ordmask::{ordmask, OrdMask, RangeBoundError};
+use std::ops::Bound;
+
+#[test]
+fn cover_range() {
+    // exact tiling
+    let shards = [
+        ordmask![_, 0u32],
+        ordmask![0, 100],
+        ordmask![100, 200],
+        ordmask![200],
+    ];
+    let shards: Vec<_> = shards.iter().collect();
+    assert_eq!(OrdMask::cover_range(&shards, ..), Ok(true));
+    assert_eq!(OrdMask::cover_range(&shards[1..], 0..), Ok(true));
+    assert_eq!(OrdMask::cover_range(&shards[1..], ..), Ok(false));
+    assert_eq!(OrdMask::cover_range(&shards[1..3], 0..200), Ok(true));
+    assert_eq!(OrdMask::cover_range(&shards[1..3], 0..201), Ok(false));
+
+    // overlapping tiling
+    let shards = [ordmask![0, 60], ordmask![50, 120], ordmask![100, 200]];
+    let shards: Vec<_> = shards.iter().collect();
+    assert_eq!(OrdMask::cover_range(&shards, 0..200), Ok(true));
+    assert_eq!(OrdMask::cover_range(&shards, 10..10), Ok(true));
+    assert_eq!(OrdMask::cover_range(&shards, 0..), Ok(false));
+
+    // empty
+    assert_eq!(OrdMask::cover_range(&[], 0..1), Ok(false));
+    assert_eq!(OrdMask::<i32>::cover_range(&[&ordmask![_]], ..), Ok(true));
+}
+
+#[test]
+fn uncovered_within() {
+    // one-point hole
+    let shards = [ordmask![0, 5], ordmask![6, 10]];
+    let shards: Vec<_> = shards.iter().collect();
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, 0..10).unwrap(),
+        ordmask![5, 6]
+    );
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, 5..6).unwrap(),
+        ordmask![5, 6]
+    );
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, 6..10).unwrap(),
+        ordmask![]
+    );
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, ..).unwrap(),
+        ordmask![_, 0, 5, 6, 10]
+    );
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, 3..).unwrap(),
+        ordmask![5, 6, 10]
+    );
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, ..8).unwrap(),
+        ordmask![_, 0, 5, 6]
+    );
+
+    // overlapping masks with shared key points
+    let shards = [ordmask![_, 0, 10], ordmask![0, 10], ordmask![5, 10, 20, 30]];
+    let shards: Vec<_> = shards.iter().collect();
+    assert_eq!(OrdMask::uncovered_within(&shards, ..).unwrap(), ordmask![]);
+    assert_eq!(
+        OrdMask::uncovered_within(&shards[1..], ..).unwrap(),
+        ordmask![_, 0, 10, 20, 30]
+    );
+    assert_eq!(
+        OrdMask::uncovered_within(&shards[1..], 7..25).unwrap(),
+        ordmask![10, 20]
+    );
+
+    assert_eq!(
+        OrdMask::uncovered_within(&[], 0..10).unwrap(),
+        ordmask![0, 10]
+    );
+    assert_eq!(
+        OrdMask::<i32>::uncovered_within(&[], ..).unwrap(),
+        ordmask![_]
+    );
+}
+
+#[test]
+fn unrepresentable_bounds() {
+    let shards = [&ordmask![0, 10]];
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, 0..=10),
+        Err(RangeBoundError::IncludedEnd)
+    );
+    assert_eq!(
+        OrdMask::uncovered_within(&shards, (Bound::Excluded(0), Bound::Excluded(10))),
+        Err(RangeBoundError::ExcludedStart)
+    );
+    assert_eq!(
+        OrdMask::cover_range(&shards, 0..=5),
+        Err(RangeBoundError::IncludedEnd)
+    );
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, MaskCursor};
+
+#[test]
+fn cursor_matches_included() {
+    let probes = [-1, -1, 0, 1, 1, 2, 4, 5, 6, 6, 9];
+    for mask in all_masks(6) {
+        let mut cursor = mask.cursor();
+        for x in &probes {
+            assert_eq!(cursor.advance_to(x), mask.included(x), "{} at {}", mask, x);
+        }
+    }
+}
+
+#[test]
+fn cursor_resume_across_batches() {
+    let batches = [vec![-1, 0, 3], vec![3, 4], vec![], vec![5, 8]];
+    for mask in all_masks(6) {
+        let mut position = 0;
+        for batch in &batches {
+            let mut cursor = MaskCursor::resume_at(&mask, position).unwrap();
+            for x in batch {
+                assert_eq!(cursor.advance_to(x), mask.included(x), "{} at {}", mask, x);
+            }
+            position = cursor.position();
+        }
+        assert_eq!(position, mask.key_points().len(), "{}", mask);
+    }
+}
+
+#[test]
+fn cursor_position() {
+    let mask = ordmask![0, 10, 20];
+    let mut cursor = mask.cursor();
+    assert_eq!(cursor.position(), 0);
+    cursor.advance_to(&10);
+    assert_eq!(cursor.position(), 2);
+    cursor.advance_to(&100);
+    assert_eq!(cursor.position(), 3);
+    assert!(MaskCursor::resume_at(&mask, 3).is_some());
+    assert!(MaskCursor::resume_at(&mask, 4).is_none());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the cursor must not move backwards")]
+fn cursor_moving_backwards() {
+    let mask = ordmask![0, 10];
+    let mut cursor = mask.cursor();
+    cursor.advance_to(&10);
+    cursor.advance_to(&9);
+}
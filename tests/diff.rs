@@ -0,0 +1,47 @@
+use ordmask::{ordmask, MaskDiff, OrdMask};
+
+#[test]
+fn diff() {
+    let mask = ordmask![0, 10, 20, 30];
+    assert_eq!(
+        mask.diff(&mask),
+        MaskDiff {
+            added: ordmask![],
+            removed: ordmask![]
+        }
+    );
+    assert_eq!(
+        mask.diff(&ordmask![_]),
+        MaskDiff {
+            added: ordmask![_, 0, 10, 20, 30],
+            removed: ordmask![]
+        }
+    );
+}
+
+#[test]
+fn diff_stream() {
+    // Each version adds the range [10 * i, 10 * i + 5) and removes the range added two versions ago
+    let versions: Vec<OrdMask<i32>> = (0..6)
+        .map(|i| OrdMask::from_ranges((i.max(1) - 1..=i).map(|j| (10 * j, 10 * j + 5))))
+        .collect();
+    let diffs: Vec<_> = OrdMask::diff_stream(&versions).collect();
+    assert_eq!(diffs.len(), versions.len() - 1);
+    for (i, diff) in diffs.iter().enumerate() {
+        let i = i as i32 + 1;
+        assert_eq!(diff.added, OrdMask::in_range(10 * i, 10 * i + 5), "{}", i);
+        let removed = match i {
+            1 => OrdMask::empty(),
+            _ => OrdMask::in_range(10 * (i - 2), 10 * (i - 2) + 5),
+        };
+        assert_eq!(diff.removed, removed, "{}", i);
+    }
+}
+
+#[test]
+fn diff_stream_short() {
+    let versions: [OrdMask<i32>; 0] = [];
+    assert_eq!(OrdMask::diff_stream(&versions).count(), 0);
+    assert_eq!(OrdMask::diff_stream(&[ordmask![0]]).count(), 0);
+    assert_eq!(OrdMask::diff_stream(&[ordmask![0], ordmask![0]]).count(), 1);
+}
@@ -0,0 +1,62 @@
+use ordmask::{assert_mask_eq, ordmask, OrdMask};
+
+#[test]
+fn display() {
+    let test_cases = vec![
+        (OrdMask::empty(), "∅"),
+        (ordmask![_], "(-∞, +∞)"),
+        (ordmask![0], "[0, +∞)"),
+        (ordmask![_, 0], "(-∞, 0)"),
+        (ordmask![0, 10], "[0, 10)"),
+        (ordmask![_, 0, 10], "(-∞, 0) ∪ [10, +∞)"),
+        (ordmask![-5, 0, 10], "[-5, 0) ∪ [10, +∞)"),
+        (ordmask![_, -5, 0, 10], "(-∞, -5) ∪ [0, 10)"),
+    ];
+
+    for (mask, expected) in test_cases {
+        assert_eq!(mask.to_string(), expected);
+    }
+}
+
+#[test]
+fn assert_mask_eq_reports_difference() {
+    let result = std::panic::catch_unwind(|| {
+        assert_mask_eq!(ordmask![0, 10, 20, 30], ordmask![0, 10, 25, 30]);
+    });
+    let payload = result.unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.contains("left: [0, 10) ∪ [20, 30)"), "{}", message);
+    assert!(message.contains("right: [0, 10) ∪ [25, 30)"), "{}", message);
+    assert!(message.contains("diff: [20, 25)"), "{}", message);
+
+    let result = std::panic::catch_unwind(|| {
+        assert_mask_eq!(ordmask![_, 0], ordmask![_], "case {}", 1);
+    });
+    let payload = result.unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.contains("failed: case 1"), "{}", message);
+    assert!(message.contains("diff: [0, +∞)"), "{}", message);
+}
+
+#[test]
+fn display_with() {
+    let hex = |value: &u32, f: &mut std::fmt::Formatter| write!(f, "{:#x}", value);
+    let test_cases = vec![
+        (OrdMask::empty(), "∅"),
+        (ordmask![_], "(-∞, +∞)"),
+        (ordmask![16], "[0x10, +∞)"),
+        (ordmask![_, 16], "(-∞, 0x10)"),
+        (ordmask![_, 16, 255, 4096], "(-∞, 0x10) ∪ [0xff, 0x1000)"),
+    ];
+
+    for (mask, expected) in test_cases {
+        assert_eq!(mask.display_with(hex).to_string(), expected);
+    }
+
+    for mask in [ordmask![_, 0u32, 10, 20], ordmask![3, 4]] {
+        let plain = mask
+            .display_with(|value, f| write!(f, "{}", value))
+            .to_string();
+        assert_eq!(plain, mask.to_string());
+    }
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, EditError, Error, OrdMask};
+
+#[test]
+fn push_key_point_at_end() {
+    for mask in all_masks(5) {
+        let mut pushed = OrdMask::empty();
+        if mask.is_include_min_value() {
+            pushed = OrdMask::universal();
+        }
+        for point in mask.key_points() {
+            pushed.push_key_point_at_end(*point).unwrap();
+        }
+        assert_eq!(pushed, mask);
+
+        let len = mask.key_points().len();
+        if let Some(last) = mask.key_points().last() {
+            assert_eq!(
+                pushed.push_key_point_at_end(last - 1).unwrap_err(),
+                Error::Falling { index: len }
+            );
+            assert_eq!(
+                pushed.push_key_point_at_end(*last).unwrap_err(),
+                Error::DuplicateKeyPoint { index: len }
+            );
+            assert_eq!(pushed, mask);
+        }
+    }
+}
+
+#[test]
+fn push_key_point_at_start() {
+    for mask in all_masks(5) {
+        let mut pushed = OrdMask::empty();
+        if mask.is_include_max_value() {
+            pushed = OrdMask::universal();
+        }
+        for point in mask.key_points().iter().rev() {
+            pushed.push_key_point_at_start(*point).unwrap();
+        }
+        assert_eq!(pushed, mask);
+
+        if let Some(first) = mask.key_points().first() {
+            assert_eq!(
+                pushed.push_key_point_at_start(first + 1).unwrap_err(),
+                Error::Falling { index: 1 }
+            );
+            assert_eq!(
+                pushed.push_key_point_at_start(*first).unwrap_err(),
+                Error::DuplicateKeyPoint { index: 1 }
+            );
+            assert_eq!(pushed, mask);
+        }
+    }
+}
+
+#[test]
+fn push_key_point_chaining() {
+    let mut mask = ordmask![_];
+    mask.push_key_point_at_end(5)
+        .unwrap()
+        .push_key_point_at_start(0)
+        .unwrap()
+        .push_key_point_at_end(10)
+        .unwrap();
+    assert_eq!(mask, ordmask![0, 5, 10]);
+}
+
+#[test]
+fn edit_key_points() {
+    let mut mask = ordmask![_, 0, 10, 20];
+    let result = mask.edit_key_points(|points| {
+        points.insert(1, 5);
+        points.push(20);
+        Ok::<(), String>(())
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(mask, ordmask![_, 0, 5, 10]);
+    assert!(mask.is_strictly_sorted());
+}
+
+#[test]
+fn edit_key_points_closure_error() {
+    let mut mask = ordmask![0, 10, 20];
+    let result = mask.edit_key_points(|points| {
+        points.clear();
+        Err("lookup failed")
+    });
+    assert_eq!(result, Err(EditError::Closure("lookup failed")));
+    assert_eq!(mask, ordmask![0, 10, 20]);
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "The edit of the key points failed: lookup failed"
+    );
+}
+
+#[test]
+fn edit_key_points_rolled_back() {
+    for mask in all_masks(5) {
+        let mut edited = mask.clone();
+        let result = edited.edit_key_points(|points| {
+            points.insert(0, 100);
+            Ok::<(), ()>(())
+        });
+        if mask.key_points().is_empty() {
+            assert_eq!(result, Ok(()));
+        } else {
+            assert_eq!(result, Err(EditError::Order(Error::Falling { index: 1 })));
+            assert_eq!(edited, mask);
+        }
+    }
+}
+
+#[test]
+fn retain_key_points() {
+    for mask in all_masks(5) {
+        let mut retained = mask.clone();
+        retained.retain_key_points(|x| x % 2 == 0);
+        let expected: Vec<i32> = mask
+            .key_points()
+            .iter()
+            .copied()
+            .filter(|x| x % 2 == 0)
+            .collect();
+        assert_eq!(retained.key_points(), &expected);
+        assert_eq!(retained.is_include_min_value(), mask.is_include_min_value());
+    }
+}
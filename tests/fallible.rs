@@ -0,0 +1,104 @@
+//! Every failure mode of the constructors, exercised through the `Result`-returning surface.
+
+use ordmask::{ordmask, ordmask_try, Error, OrdMask, ParseError, TransitionError};
+
+#[test]
+fn try_from_collections() {
+    assert_eq!(OrdMask::try_from(vec![0, 10]), Ok(ordmask![0, 10]));
+    assert_eq!(
+        OrdMask::try_from(vec![10, 0]),
+        Err(Error::Falling { index: 1 })
+    );
+    assert_eq!(OrdMask::try_from(&[0, 10][..]), Ok(ordmask![0, 10]));
+    assert_eq!(
+        OrdMask::try_from(&[0, 10, 5][..]),
+        Err(Error::Falling { index: 2 })
+    );
+    assert_eq!(OrdMask::try_from([0, 5, 5, 10]), Ok(ordmask![0, 10]));
+    assert_eq!(
+        OrdMask::try_from([0, 10, 5]),
+        Err(Error::Falling { index: 2 })
+    );
+    assert_eq!(OrdMask::<i32>::try_from([]), Ok(ordmask![]));
+}
+
+#[test]
+fn try_from_complement() {
+    assert_eq!(
+        OrdMask::try_from_complement_vec(vec![0, 10]),
+        Ok(ordmask![_, 0, 10])
+    );
+    assert_eq!(
+        OrdMask::try_from_complement_vec(vec![10, 0]),
+        Err(Error::Falling { index: 1 })
+    );
+}
+
+#[test]
+fn try_ranges() {
+    assert_eq!(OrdMask::try_in_range(0, 10), Ok(ordmask![0, 10]));
+    assert_eq!(OrdMask::try_in_range(0, 0), Ok(ordmask![]));
+    assert_eq!(
+        OrdMask::try_in_range(10, 0),
+        Err(Error::Falling { index: 1 })
+    );
+    assert_eq!(OrdMask::try_exclude_range(0, 10), Ok(ordmask![_, 0, 10]));
+    assert_eq!(OrdMask::try_exclude_range(0, 0), Ok(ordmask![_]));
+    assert_eq!(
+        OrdMask::try_exclude_range(10, 0),
+        Err(Error::Falling { index: 1 })
+    );
+    assert_eq!(
+        OrdMask::try_from_ranges([Ok((0, 10)), Err("bad range")]),
+        Err("bad range")
+    );
+}
+
+#[test]
+fn try_macro() {
+    assert_eq!(ordmask_try![], Ok(OrdMask::<i32>::empty()));
+    assert_eq!(ordmask_try![_], Ok(OrdMask::<i32>::universal()));
+    assert_eq!(ordmask_try![0, 10,], Ok(ordmask![0, 10]));
+    assert_eq!(ordmask_try![_, 0, 10], Ok(ordmask![_, 0, 10]));
+    assert_eq!(ordmask_try![0, 10, 5], Err(Error::Falling { index: 2 }));
+    assert_eq!(ordmask_try![_, 10, 5], Err(Error::Falling { index: 1 }));
+}
+
+#[test]
+fn try_strict_and_flat() {
+    assert_eq!(
+        OrdMask::from_sorted_key_points_checking_strict(vec![0, 0], false),
+        Err(Error::DuplicateKeyPoint { index: 1 })
+    );
+    assert_eq!(
+        OrdMask::from_flat(&[1, 0], true),
+        Err(Error::Falling { index: 1 })
+    );
+}
+
+#[test]
+fn try_transitions() {
+    assert_eq!(
+        OrdMask::try_from_transitions([(0, true), (10, true)], false),
+        Err(TransitionError::Redundant {
+            point: 10,
+            included: true
+        })
+    );
+    assert_eq!(
+        OrdMask::try_from_transitions([(0, true), (0, false)], false),
+        Err(TransitionError::Contradictory { point: 0 })
+    );
+}
+
+#[test]
+fn try_parse() {
+    assert!(matches!(
+        OrdMask::<i32>::try_from_str_key_points("0,x", ','),
+        Err(ParseError::KeyPoint { index: 1, .. })
+    ));
+    assert_eq!(
+        OrdMask::<i32>::try_from_str_key_points("10,0", ','),
+        Err(ParseError::Order(Error::Falling { index: 1 }))
+    );
+}
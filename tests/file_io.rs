@@ -0,0 +1,95 @@
+#![cfg(feature = "file-io")]
+
+mod common;
+
+use common::masks_over;
+use ordmask::chunked::{ChunkedMaskReader, FILE_CHUNK_POINTS};
+use ordmask::OrdMask;
+use std::fs::{self, File};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("ordmask-{}-{name}.omck", std::process::id()))
+}
+
+#[test]
+fn save_and_load() {
+    let path = temp_path("save-and-load");
+    let large = OrdMask::from(
+        (0..3 * FILE_CHUNK_POINTS as u64)
+            .map(|x| x * 2 + 1)
+            .collect::<Vec<_>>(),
+    );
+    for mask in masks_over(&[0u64, 1, 7, u64::MAX]).iter().chain([&large]) {
+        mask.save_to_file(&path).unwrap();
+        let mut folded = mask.clone();
+        folded.fold_min_value();
+        assert_eq!(OrdMask::load_from_file(&path).unwrap(), folded);
+    }
+    let reader = ChunkedMaskReader::new(File::open(&path).unwrap()).unwrap();
+    assert_eq!(reader.len(), large.key_points().len());
+    assert!(reader.included(&4097).unwrap());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn load_errors() {
+    let path = temp_path("load-errors");
+    let error = OrdMask::load_from_file(&path).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    assert!(error.to_string().contains("failed to open"));
+
+    fs::write(&path, b"not a mask at all, but long enough").unwrap();
+    let error = OrdMask::load_from_file(&path).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(error.to_string().contains("trailer and index"));
+    assert!(error.to_string().contains(&*path.to_string_lossy()));
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn corrupted_index() {
+    let path = temp_path("corrupted-index");
+    let mask = OrdMask::from(
+        (0..3 * FILE_CHUNK_POINTS as u64)
+            .map(|x| x * 2 + 1)
+            .collect::<Vec<_>>(),
+    );
+    mask.save_to_file(&path).unwrap();
+    let bytes = fs::read(&path).unwrap();
+    let index_offset = mask.key_points().len() * 8;
+
+    // the state before the second chunk is flipped
+    let mut corrupted = bytes.clone();
+    corrupted[index_offset + 9 + 8] ^= 1;
+    fs::write(&path, &corrupted).unwrap();
+    let error = OrdMask::load_from_file(&path).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(error.to_string().contains("trailer and index"));
+
+    // the first key point of the second chunk is moved, but the index is still increasing
+    let mut corrupted = bytes;
+    let first = FILE_CHUNK_POINTS as u64 * 2 + 1;
+    corrupted[index_offset + 9..index_offset + 17].copy_from_slice(&(first + 1).to_le_bytes());
+    fs::write(&path, &corrupted).unwrap();
+    let error = OrdMask::load_from_file(&path).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(error.to_string().contains("key points"));
+    let reader = ChunkedMaskReader::new(File::open(&path).unwrap()).unwrap();
+    assert_eq!(
+        reader.included(&(first + 1)).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn save_errors() {
+    let path = temp_path("missing-dir").join("mask.omck");
+    let error = OrdMask::from(vec![1u64, 2])
+        .save_to_file(&path)
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    assert!(error.to_string().contains("failed to create"));
+}
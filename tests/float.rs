@@ -0,0 +1,79 @@
+use ordmask::{ordmask, OrdMask};
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug)]
+struct Float(f64);
+
+impl PartialEq for Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0).is_eq()
+    }
+}
+
+impl Eq for Float {}
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Float {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+const NEG_INF: Float = Float(f64::NEG_INFINITY);
+const INF: Float = Float(f64::INFINITY);
+
+#[test]
+fn infinity_as_key_point() {
+    let mask = ordmask![NEG_INF, Float(0.0), INF];
+    assert!(mask.is_valid());
+    assert!(mask.included(&NEG_INF));
+    assert!(mask.included(&Float(f64::MIN)));
+    assert!(mask.excluded(&Float(0.0)));
+    assert!(mask.excluded(&Float(f64::MAX)));
+    assert!(mask.included(&INF));
+    assert!(mask.excluded(&Float(-f64::NAN)));
+    assert!(mask.included(&Float(f64::NAN)));
+}
+
+#[test]
+fn infinity_as_query() {
+    let mask = ordmask![_, Float(-1.0), Float(1.0)];
+    assert!(mask.included(&NEG_INF));
+    assert!(mask.included(&INF));
+    assert!(mask.excluded(&Float(0.0)));
+
+    let bounded = OrdMask::in_range(Float(-1.0), Float(1.0));
+    assert!(bounded.excluded(&NEG_INF));
+    assert!(bounded.excluded(&INF));
+}
+
+#[test]
+fn nan_ordered_by_sign() {
+    let mask = OrdMask::in_range(NEG_INF, INF);
+    assert!(mask.excluded(&Float(f64::NAN)));
+    assert!(mask.excluded(&Float(-f64::NAN)));
+    assert!(ordmask![_, NEG_INF].included(&Float(-f64::NAN)));
+    assert!(ordmask![INF].included(&Float(f64::NAN)));
+    assert!(OrdMask::try_from(vec![Float(f64::NAN), INF]).is_err());
+}
+
+#[test]
+fn negative_zero_and_zero_are_distinct_key_points() {
+    let mask = ordmask![Float(-0.0), Float(0.0)];
+    assert!(mask.is_valid());
+    assert_eq!(mask.key_points().len(), 2);
+    assert!(mask.included(&Float(-0.0)));
+    assert!(mask.excluded(&Float(0.0)));
+    assert!(mask.excluded(&Float(-1.0)));
+
+    let mut simplified = mask.clone();
+    simplified.simplify();
+    assert_eq!(simplified, mask);
+    assert_ne!(Float(-0.0), Float(0.0));
+    assert_eq!(Float(f64::NAN), Float(f64::NAN));
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn from_ranges() {
+    let test_cases = vec![
+        (vec![], ordmask![]),
+        (vec![(0, 10)], ordmask![0, 10]),
+        (vec![(10, 0)], ordmask![]),
+        (vec![(5, 5)], ordmask![]),
+        (vec![(0, 10), (10, 20)], ordmask![0, 20]),
+        (vec![(0, 10), (2, 5)], ordmask![0, 10]),
+        (vec![(20, 30), (0, 10)], ordmask![0, 10, 20, 30]),
+        (
+            vec![(0, 10), (5, 15), (14, 16), (30, 40)],
+            ordmask![0, 16, 30, 40],
+        ),
+    ];
+
+    for (ranges, expected) in test_cases {
+        assert_eq!(
+            OrdMask::from_ranges(ranges.clone()),
+            expected,
+            "{:?}",
+            ranges
+        );
+    }
+}
+
+#[test]
+fn from_nested_ranges() {
+    assert_eq!(
+        OrdMask::from_nested_ranges((0, 10), vec![]),
+        ordmask![0, 10]
+    );
+    assert_eq!(
+        OrdMask::from_nested_ranges((0, 10), vec![(0, 2), (8, 10)]),
+        ordmask![2, 8]
+    );
+    assert_eq!(
+        OrdMask::from_nested_ranges((0, 10), vec![(5, 7), (2, 3), (6, 8)]),
+        ordmask![0, 2, 3, 5, 8, 10]
+    );
+    assert_eq!(
+        OrdMask::from_nested_ranges((0, 10), vec![(0, 10)]),
+        ordmask![]
+    );
+    assert_eq!(OrdMask::from_nested_ranges((10, 0), vec![]), ordmask![]);
+    assert_eq!(
+        OrdMask::from_nested_ranges((0, 10), vec![(20, 15)]),
+        ordmask![0, 10]
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_nested_ranges_should_panic() {
+    let _ = OrdMask::from_nested_ranges((0, 10), vec![(5, 11)]);
+}
+
+#[test]
+fn try_from_ranges() {
+    let ranges: Vec<Result<(i32, i32), String>> = vec![
+        Ok((0, 10)),
+        Ok((5, 15)),
+        Err("first".to_string()),
+        Ok((20, 30)),
+        Err("second".to_string()),
+    ];
+    let mut consumed = 0;
+    let result = OrdMask::try_from_ranges(ranges.into_iter().inspect(|_| consumed += 1));
+    assert_eq!(result, Err("first".to_string()));
+    assert_eq!(consumed, 3);
+
+    let empty: Vec<Result<(i32, i32), ()>> = vec![];
+    assert_eq!(OrdMask::try_from_ranges(empty), Ok(ordmask![]));
+}
+
+#[test]
+fn try_from_ranges_matches_from_ranges() {
+    for mask in all_masks(6) {
+        let mut ranges: Vec<(i32, i32)> = mask
+            .to_half_open_pairs()
+            .map(|(start, end)| (start.copied().unwrap_or(-1), end.copied().unwrap_or(6)))
+            .collect();
+        ranges.push((3, 1));
+        ranges.push((2, 4));
+        ranges.reverse();
+        let expected = OrdMask::from_ranges(ranges.clone());
+        let result = OrdMask::try_from_ranges(ranges.into_iter().map(Ok::<_, ()>));
+        assert_eq!(result, Ok(expected), "{}", mask);
+    }
+}
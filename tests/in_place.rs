@@ -0,0 +1,114 @@
+mod common;
+
+use common::{all_masks, masks_over};
+use ordmask::OrdMask;
+
+#[test]
+fn symmetric_difference_in_place() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            let mut result = a.clone();
+            result.symmetric_difference_in_place(b);
+            assert_eq!(result, a.symmetric_difference(b), "{} ^ {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn symmetric_difference_in_place_with_many_key_points() {
+    let points: Vec<i32> = (0..40).map(|i| i * 3).collect();
+    let small: Vec<OrdMask<i32>> = masks_over(&[0, 1, 30, 59, 60, 200]);
+    let large = [
+        OrdMask::from(points.clone()),
+        OrdMask::from_complement(points.clone()),
+        OrdMask::from(points.iter().map(|x| x + 1).collect::<Vec<_>>()),
+    ];
+    for a in small.iter().chain(&large) {
+        for b in &large {
+            let mut result = a.clone();
+            result.symmetric_difference_in_place(b);
+            assert_eq!(result, a.symmetric_difference(b), "{} ^ {}", a, b);
+
+            let mut result = b.clone();
+            result.symmetric_difference_in_place(a);
+            assert_eq!(result, b.symmetric_difference(a), "{} ^ {}", b, a);
+        }
+    }
+}
+
+#[test]
+fn intersection_in_place() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            let mut result = a.clone();
+            result.intersection_in_place(b);
+            assert_eq!(result, a & b, "{} & {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn intersection_in_place_nearly_universal() {
+    let points: Vec<i32> = (0..100).map(|i| i * 3).collect();
+    let large = [
+        OrdMask::from(points.clone()),
+        OrdMask::from_complement(points),
+    ];
+    for a in &large {
+        for b in masks_over(&[-1, 0, 1, 30, 31, 150, 297, 298, 400]) {
+            let mut result = a.clone();
+            result.intersection_in_place(&b);
+            assert_eq!(result, a & &b, "{} & {}", a, b);
+
+            let mut result = b.clone();
+            result.intersection_in_place(a);
+            assert_eq!(result, &b & a, "{} & {}", b, a);
+        }
+    }
+}
+
+#[test]
+fn union_in_place() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            let mut result = a.clone();
+            result.union_in_place(b);
+            assert_eq!(result, a | b, "{} | {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn union_in_place_reuses_capacity() {
+    let points: Vec<i32> = (0..100).map(|i| i * 3).collect();
+    for b in masks_over(&[-1, 0, 1, 30, 31, 150, 297, 298, 400]) {
+        let mut buffer = Vec::with_capacity(points.len() + b.key_points().len());
+        buffer.extend_from_slice(&points);
+        let mut result = OrdMask::from(buffer);
+        let capacity = result.capacity();
+        result.union_in_place(&b);
+        assert_eq!(result, OrdMask::from(points.clone()) | &b, "{}", b);
+        assert_eq!(result.capacity(), capacity);
+    }
+}
+
+#[test]
+fn xor_with_single_range() {
+    for a in all_masks(5) {
+        for start in 0..6 {
+            for end in 0..6 {
+                let mut result = a.clone();
+                result.xor_with_single_range(start, end);
+                let expected = if start < end {
+                    a.symmetric_difference(&OrdMask::in_range(start, end))
+                } else {
+                    a.clone()
+                };
+                assert_eq!(result, expected, "{} ^ [{}, {})", a, start, end);
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+mod common;
+
+use common::masks_over;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn to_inclusive_ranges() {
+    let test_cases = vec![
+        (OrdMask::<u8>::empty(), vec![]),
+        (OrdMask::universal(), vec![(0, 255)]),
+        (ordmask![255], vec![(255, 255)]),
+        (ordmask![_, 255], vec![(0, 254)]),
+        (ordmask![0, 1], vec![(0, 0)]),
+        (ordmask![_, 0, 255], vec![(255, 255)]),
+        (ordmask![_, 1, 254], vec![(0, 0), (254, 255)]),
+    ];
+
+    for (mask, expected) in test_cases {
+        assert_eq!(mask.to_inclusive_ranges(), expected, "{}", mask);
+    }
+}
+
+#[test]
+fn from_inclusive_ranges() {
+    assert_eq!(OrdMask::from_inclusive_ranges([(0u8, 255)]), ordmask![0]);
+    assert_eq!(
+        OrdMask::from_inclusive_ranges([(255u8, 255)]),
+        ordmask![255]
+    );
+    assert_eq!(OrdMask::from_inclusive_ranges([(10u8, 5)]), ordmask![]);
+    assert_eq!(
+        OrdMask::from_inclusive_ranges([(200u8, 255), (100, 255), (0, 10), (10, 20)]),
+        ordmask![0, 21, 100]
+    );
+}
+
+#[test]
+fn inclusive_ranges_round_trip() {
+    for mask in masks_over(&[0u8, 1, 100, 254, 255]) {
+        let ranges = mask.to_inclusive_ranges();
+        let restored = OrdMask::from_inclusive_ranges(ranges.clone());
+        for x in 0..=255 {
+            assert_eq!(restored.included(&x), mask.included(&x), "{}", mask);
+            let in_ranges = ranges
+                .iter()
+                .any(|(first, last)| (first..=last).contains(&&x));
+            assert_eq!(in_ranges, mask.included(&x), "{}", mask);
+        }
+        assert_eq!(restored.to_inclusive_ranges(), ranges);
+    }
+}
+
+#[test]
+fn close_and_open_tail_at_max() {
+    let masks = masks_over(&[0u8, 1, 100, 254, 255]);
+    for mask in &masks {
+        let mut closed = mask.clone();
+        closed.close_tail_at_max();
+        let mut twice = closed.clone();
+        twice.close_tail_at_max();
+        assert_eq!(twice, closed, "{}", mask);
+        if mask.included(&255) && mask.key_points().last() != Some(&255) {
+            assert_eq!(closed.key_points().last(), Some(&255), "{}", mask);
+            assert!(!closed.is_strictly_sorted(), "{}", mask);
+        } else {
+            assert_eq!(closed, *mask);
+        }
+
+        for x in 0..=255 {
+            assert_eq!(closed.included(&x), mask.included(&x), "{} at {}", mask, x);
+        }
+        assert_eq!(closed.to_inclusive_ranges(), mask.to_inclusive_ranges());
+        assert_eq!(closed.to_point_set(), mask.to_point_set(), "{}", mask);
+        for other in &masks {
+            let union = &closed | other;
+            let intersection = &closed & other;
+            for x in 0..=255 {
+                assert_eq!(union.included(&x), (mask | other).included(&x));
+                assert_eq!(intersection.included(&x), (mask & other).included(&x));
+            }
+        }
+
+        let mut opened = closed.clone();
+        opened.open_tail_from_max();
+        assert_eq!(opened, *mask);
+        closed.normalize();
+        assert_eq!(closed, *mask);
+    }
+}
+
+#[test]
+fn max_value_through_both_representations() {
+    let open = OrdMask::from_inclusive_ranges([(250u8, 255)]);
+    let split = OrdMask::from_inclusive_ranges([(250u8, 254), (255, 255)]);
+    let mut closed = open.clone();
+    closed.close_tail_at_max();
+    assert_eq!(open, ordmask![250]);
+    assert_eq!(split, open);
+    assert_eq!(closed.key_points(), &[250, 255, 255]);
+    for mask in [&open, &split, &closed] {
+        assert!(mask.included(&255));
+        assert!(mask.included(&250));
+        assert!(!mask.included(&249));
+        assert_eq!(mask.to_inclusive_ranges(), vec![(250, 255)]);
+    }
+    assert_eq!(
+        closed.to_half_open_pairs().collect::<Vec<_>>(),
+        vec![(Some(&250), Some(&255)), (Some(&255), None)]
+    );
+
+    // the tail starting at the maximum value is already closed
+    let mut point = OrdMask::point(255u8);
+    point.close_tail_at_max();
+    assert_eq!(point, ordmask![255]);
+}
+
+#[test]
+fn in_range_inclusive() {
+    for first in 0..=255u8 {
+        for last in [
+            0,
+            first.saturating_sub(1),
+            first,
+            first.saturating_add(1),
+            255,
+        ] {
+            let mask = OrdMask::in_range_inclusive(first, last);
+            assert_eq!(mask, OrdMask::from_inclusive_ranges([(first, last)]));
+            for v in 0..=255u8 {
+                assert_eq!(mask.included(&v), first <= v && v <= last);
+            }
+        }
+    }
+}
+
+#[test]
+fn point_and_greater_than() {
+    for value in 0..=255u8 {
+        let point = OrdMask::point(value);
+        let greater = OrdMask::greater_than(value);
+        for v in 0..=255u8 {
+            assert_eq!(point.included(&v), v == value);
+            assert_eq!(greater.included(&v), v > value);
+        }
+    }
+    assert!(OrdMask::point(()).included(&()));
+    assert_eq!(OrdMask::greater_than(()), OrdMask::empty());
+}
+
+#[test]
+fn from_range_inclusive_at_boundaries() {
+    let mask: OrdMask<u8> = (0..=255).into();
+    assert_eq!(mask, ordmask![0]);
+    assert!(mask.included(&0) && mask.included(&255));
+
+    let mask: OrdMask<u8> = (255..=255).into();
+    assert_eq!(mask, ordmask![255]);
+    assert!(mask.excluded(&254) && mask.included(&255));
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let mask: OrdMask<u8> = (10..=5).into();
+    assert_eq!(mask, OrdMask::empty());
+
+    let mut exhausted = 3u8..=3;
+    exhausted.next();
+    let mask: OrdMask<u8> = exhausted.into();
+    assert_eq!(mask, OrdMask::empty());
+}
+
+#[test]
+fn from_range_to_inclusive_at_boundaries() {
+    let mask: OrdMask<u8> = (..=0).into();
+    assert_eq!(mask, ordmask![_, 1]);
+    let mask: OrdMask<u8> = (..=255).into();
+    assert_eq!(mask, OrdMask::universal());
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::masks_over;
+use ordmask::{ordmask, OrdMask};
+
+fn check(mask: &OrdMask<i32>, sorted_keys: &[i32]) {
+    let index_mask = mask.to_index_mask(sorted_keys);
+    for (i, key) in sorted_keys.iter().enumerate() {
+        assert_eq!(
+            index_mask.included(&i),
+            mask.included(key),
+            "mask: {:?}, keys: {:?}, index: {}",
+            mask,
+            sorted_keys,
+            i
+        );
+    }
+}
+
+#[test]
+fn to_index_mask() {
+    let mask = ordmask![0, 10];
+    assert_eq!(mask.to_index_mask(&[]), ordmask![]);
+    assert_eq!(mask.to_index_mask(&[-5, 0, 5, 10, 15]), ordmask![1, 3]);
+    assert_eq!(mask.to_index_mask(&[0, 0, 10, 10]), ordmask![0, 2]);
+    assert_eq!(mask.to_index_mask(&[-5, -5, 15, 15]), ordmask![]);
+    assert_eq!(mask.to_index_mask(&[-5, 5, 5, 15]), ordmask![1, 3]);
+
+    let mask = ordmask![_, 0, 10];
+    assert_eq!(mask.to_index_mask(&[-5, 0, 5, 10, 15]), ordmask![_, 1, 3]);
+    assert_eq!(mask.to_index_mask(&[1, 2, 3]), ordmask![_, 0, 3]);
+}
+
+#[test]
+fn to_index_mask_matches_included() {
+    let masks = [
+        ordmask![],
+        ordmask![_],
+        ordmask![2],
+        ordmask![_, 2],
+        ordmask![1, 3],
+        ordmask![_, 1, 3],
+        ordmask![0, 2, 3, 5],
+        ordmask![_, 0, 2, 3, 5, 6],
+    ];
+    let columns: [&[i32]; 7] = [
+        &[],
+        &[2],
+        &[0, 1, 2, 3, 4, 5, 6],
+        &[1, 1, 1, 3, 3, 3],
+        &[-1, 2, 2, 2, 4, 4, 7],
+        &[0, 0, 0, 0],
+        &[2, 2, 3, 3, 5, 5, 6, 6],
+    ];
+    for mask in &masks {
+        for column in &columns {
+            check(mask, column);
+        }
+    }
+}
+
+#[test]
+fn select() {
+    let slice = [0, 10, 20, 30, 40];
+    let select = |mask: OrdMask<usize>| mask.select(&slice).copied().collect::<Vec<_>>();
+    assert_eq!(select(ordmask![]), vec![]);
+    assert_eq!(select(ordmask![_]), slice);
+    assert_eq!(select(ordmask![_, 2]), vec![0, 10]);
+    assert_eq!(select(ordmask![1, 2, 4]), vec![10, 40]);
+    assert_eq!(select(ordmask![3, 10]), vec![30, 40]);
+    assert_eq!(select(ordmask![5, 10]), vec![]);
+    assert_eq!(select(ordmask![0, 0, 2, 100]), vec![20, 30, 40]);
+}
+
+#[test]
+fn drain_from() {
+    for mask in masks_over(&[0usize, 1, 2, 4, 5, 7]) {
+        let original: Vec<usize> = (0..5).collect();
+        let mut vec = original.clone();
+        let drained = mask.drain_from(&mut vec);
+        let selected: Vec<usize> = mask.select(&original).copied().collect();
+        assert_eq!(drained, selected, "{}", mask);
+        let remaining: Vec<usize> = original
+            .iter()
+            .copied()
+            .filter(|i| mask.excluded(i))
+            .collect();
+        assert_eq!(vec, remaining, "{}", mask);
+    }
+
+    let mut vec = vec!['a', 'b'];
+    assert_eq!(OrdMask::universal().drain_from(&mut vec), vec!['a', 'b']);
+    assert!(vec.is_empty());
+}
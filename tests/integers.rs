@@ -0,0 +1,49 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn enumerate_covered_integers_in_range() {
+    for mask in all_masks(5) {
+        let mask: OrdMask<i64> = mask.convert();
+        for start in -1..7 {
+            for end in -1..7 {
+                let covered = mask.enumerate_covered_integers_in_range(start, end);
+                let expected: Vec<i64> = (start..end).filter(|x| mask.included(x)).collect();
+                assert_eq!(
+                    covered.remaining(),
+                    expected.len() as u64,
+                    "{} in [{}, {})",
+                    mask,
+                    start,
+                    end
+                );
+                assert_eq!(
+                    covered.collect::<Vec<_>>(),
+                    expected,
+                    "{} in [{}, {})",
+                    mask,
+                    start,
+                    end
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn enumerate_covered_integers_at_extremes() {
+    let mask = ordmask![_, i64::MIN + 2, i64::MAX - 1];
+    let covered = mask.enumerate_covered_integers_in_range(i64::MIN, i64::MAX);
+    assert_eq!(
+        covered.collect::<Vec<_>>(),
+        vec![i64::MIN, i64::MIN + 1, i64::MAX - 1]
+    );
+
+    let universal: OrdMask<i64> = ordmask![_];
+    let mut covered = universal.enumerate_covered_integers_in_range(i64::MIN, i64::MAX);
+    assert_eq!(covered.remaining(), u64::MAX);
+    assert_eq!(covered.next(), Some(i64::MIN));
+    assert_eq!(covered.remaining(), u64::MAX - 1);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "intern")]
+
+mod common;
+
+use common::all_masks;
+use ordmask::intern::MaskPool;
+use ordmask::{ordmask, OrdMask};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn intern_equivalent_masks() {
+    let pool = MaskPool::new();
+    let masks = all_masks(5);
+    let shared: Vec<_> = masks.iter().map(|mask| pool.intern(mask.clone())).collect();
+    assert_eq!(pool.len(), masks.len());
+
+    for (mask, shared) in masks.iter().zip(&shared) {
+        assert_eq!(**shared, *mask);
+        let complemented = pool.intern(mask.new_complement().complement());
+        assert!(Arc::ptr_eq(&complemented, shared), "{}", mask);
+        let with_duplicates = unsafe {
+            let mut key_points = vec![-3, -3];
+            key_points.extend(mask.key_points());
+            OrdMask::with_unchecked(key_points, mask.is_include_min_value())
+        };
+        assert!(
+            Arc::ptr_eq(&pool.intern(with_duplicates), shared),
+            "{}",
+            mask
+        );
+    }
+    assert_eq!(pool.len(), masks.len());
+
+    let a = pool.intern(OrdMask::from_ranges([(0, 2), (2, 4)]));
+    let b = pool.intern(OrdMask::in_range(0, 4));
+    assert!(Arc::ptr_eq(&a, &b));
+    assert_eq!(pool.len(), masks.len());
+}
+
+#[test]
+fn gc() {
+    let pool = MaskPool::new();
+    let kept = pool.intern(ordmask![0, 10]);
+    let dropped = pool.intern(ordmask![20, 30]);
+    assert_eq!(pool.len(), 2);
+
+    pool.gc();
+    assert_eq!(pool.len(), 2);
+
+    drop(dropped);
+    pool.gc();
+    assert_eq!(pool.len(), 1);
+    assert!(Arc::ptr_eq(&pool.intern(ordmask![0, 10]), &kept));
+
+    drop(kept);
+    pool.gc();
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn intern_across_threads() {
+    let pool = Arc::new(MaskPool::new());
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let pool = pool.clone();
+            thread::spawn(move || pool.intern(OrdMask::in_range(0, 10 + i % 2)))
+        })
+        .collect();
+    let shared: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(pool.len(), 2);
+    for mask in &shared {
+        let expected = shared
+            .iter()
+            .find(|other| other.key_points() == mask.key_points())
+            .unwrap();
+        assert!(Arc::ptr_eq(mask, expected));
+    }
+}
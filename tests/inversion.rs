@@ -0,0 +1,81 @@
+use ordmask::{ordmask, Error, InversionListError, OrdMask};
+
+const ASCII_ALPHABETIC: [u32; 4] = [0x41, 0x5B, 0x61, 0x7B];
+
+#[test]
+fn from_inversion_list() {
+    let mask = OrdMask::from_inversion_list(&ASCII_ALPHABETIC).unwrap();
+    for (i, boundary) in ASCII_ALPHABETIC.iter().enumerate() {
+        let starts = i % 2 == 0;
+        assert_eq!(mask.included(&(boundary - 1)), !starts, "{:#X}", boundary);
+        assert_eq!(mask.included(boundary), starts, "{:#X}", boundary);
+    }
+    assert_eq!(mask.to_inversion_list(), ASCII_ALPHABETIC);
+
+    assert_eq!(OrdMask::from_inversion_list(&[]), Ok(ordmask![]));
+    assert_eq!(
+        OrdMask::from_inversion_list(&[1, 1]),
+        Err(Error::DuplicateKeyPoint { index: 1 })
+    );
+    assert_eq!(
+        OrdMask::from_inversion_list(&[2, 1]),
+        Err(Error::Falling { index: 1 })
+    );
+}
+
+#[test]
+fn to_inversion_list() {
+    assert_eq!(OrdMask::<u32>::empty().to_inversion_list(), vec![]);
+    assert_eq!(OrdMask::<u32>::universal().to_inversion_list(), vec![0]);
+    assert_eq!(ordmask![0u32, 10].to_inversion_list(), vec![0, 10]);
+    assert_eq!(ordmask![_, 0u32, 10].to_inversion_list(), vec![10]);
+    assert_eq!(ordmask![_, 5u32, 10].to_inversion_list(), vec![0, 5, 10]);
+}
+
+#[test]
+fn from_char_inversion_list() {
+    let mask = OrdMask::from_char_inversion_list(&ASCII_ALPHABETIC).unwrap();
+    for c in ['A', 'Z', 'a', 'z'] {
+        assert!(mask.included(&c), "{}", c);
+    }
+    for c in ['@', '[', '`', '{'] {
+        assert!(mask.excluded(&c), "{}", c);
+    }
+    assert_eq!(mask.to_char_inversion_list(), ASCII_ALPHABETIC);
+
+    // Ranges within the surrogate gap are empty
+    let mask = OrdMask::from_char_inversion_list(&[0xD800, 0xDC00]).unwrap();
+    assert_eq!(mask, ordmask![]);
+
+    // A range crossing the surrogate gap
+    let mask = OrdMask::from_char_inversion_list(&[0xD700, 0xE100]).unwrap();
+    assert!(mask.included(&'\u{D7FF}') && mask.included(&'\u{E000}'));
+    assert_eq!(mask.to_char_inversion_list(), vec![0xD700, 0xE100]);
+
+    // A range up to the end of the code space
+    let mask = OrdMask::from_char_inversion_list(&[0x41, 0x110000]).unwrap();
+    assert_eq!(mask, ordmask!['A']);
+    assert!(mask.included(&char::MAX));
+
+    assert_eq!(
+        OrdMask::from_char_inversion_list(&[0x41, 0x110001]),
+        Err(InversionListError::OutOfRange { index: 1 })
+    );
+    assert_eq!(
+        OrdMask::from_char_inversion_list(&[0x41, 0x41]),
+        Err(InversionListError::Order(Error::DuplicateKeyPoint {
+            index: 1
+        }))
+    );
+}
+
+#[test]
+fn to_char_inversion_list() {
+    assert_eq!(
+        OrdMask::<char>::universal().to_char_inversion_list(),
+        vec![0]
+    );
+    assert_eq!(ordmask![_, '\0', 'A'].to_char_inversion_list(), vec![0x41]);
+    assert_eq!(ordmask![_, 'A'].to_char_inversion_list(), vec![0, 0x41]);
+    assert_eq!(ordmask!['\u{E000}'].to_char_inversion_list(), vec![0xE000]);
+}
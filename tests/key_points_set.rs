@@ -0,0 +1,51 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+use std::collections::BTreeSet;
+use std::ops::ControlFlow;
+
+#[test]
+fn from_key_points_predicate_with_early_exit() {
+    let key_points = BTreeSet::from([0, 1, 2, 3, 4, 5]);
+    for mask in all_masks(6) {
+        let expected = OrdMask::from_key_points_set(
+            key_points.clone(),
+            |x| mask.included(x),
+            mask.is_include_min_value(),
+        );
+        // break at the last key point of the mask, after which the state is constant
+        let last = mask.key_points().last().copied();
+        let mut calls = 0;
+        let result = OrdMask::from_key_points_predicate_with_early_exit(
+            key_points.clone(),
+            |x| {
+                calls += 1;
+                match Some(*x) >= last {
+                    true => ControlFlow::Break(mask.included(x)),
+                    false => ControlFlow::Continue(mask.included(x)),
+                }
+            },
+            mask.is_include_min_value(),
+        );
+        assert_eq!(result, expected, "{}", mask);
+        assert_eq!(calls, last.map_or(1, |last| last as usize + 1), "{}", mask);
+    }
+}
+
+#[test]
+fn early_exit_never_breaking() {
+    let mask = OrdMask::from_key_points_predicate_with_early_exit(
+        BTreeSet::from([0, 10, 20]),
+        |x| ControlFlow::Continue(*x == 10),
+        true,
+    );
+    assert_eq!(mask, ordmask![_, 0, 10, 20]);
+
+    let mask = OrdMask::from_key_points_predicate_with_early_exit(
+        BTreeSet::from([0, 10, 20]),
+        |_| ControlFlow::Break(true),
+        true,
+    );
+    assert_eq!(mask, ordmask![_]);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "known")]
+
+use ordmask::known::{ascii_printable, ports, unicode_scalar_values, unprivileged_ports};
+
+#[test]
+fn ports_endpoints() {
+    let (all, unprivileged) = (ports(), unprivileged_ports());
+    for port in 0..=u16::MAX {
+        assert_eq!(all.included(&port), port != 0, "{port}");
+        assert_eq!(unprivileged.included(&port), port >= 1024, "{port}");
+    }
+    assert!(unprivileged.is_subset_of(&all));
+}
+
+#[test]
+fn ascii_printable_matches_std() {
+    let mask = ascii_printable();
+    for byte in 0..=u8::MAX {
+        let printable = byte.is_ascii_graphic() || byte == b' ';
+        assert_eq!(mask.included(&byte), printable, "{byte:#x}");
+    }
+}
+
+#[test]
+fn unicode_scalar_values_match_char() {
+    let mask = unicode_scalar_values();
+    for value in (0..=0x11_0000).chain([u32::MAX]) {
+        assert_eq!(
+            mask.included(&value),
+            char::from_u32(value).is_some(),
+            "{value:#x}"
+        );
+    }
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{all_masks, Lcg};
+use ordmask::{ordmask, MultiMaskIndex, OrdMask};
+
+#[test]
+fn apply_mask_union() {
+    let rules: Vec<_> = all_masks(4)
+        .into_iter()
+        .enumerate()
+        .map(|(i, m)| (m, i))
+        .collect();
+    for x in -1..=4 {
+        let expected: Vec<_> = rules
+            .iter()
+            .filter(|(mask, _)| mask.included(&x))
+            .map(|(_, i)| i)
+            .collect();
+        assert_eq!(OrdMask::apply_mask_union(&rules, &x), expected);
+    }
+}
+
+#[test]
+fn apply_mask_union_degenerate() {
+    let rules: [(OrdMask<i32>, &str); 0] = [];
+    assert!(OrdMask::apply_mask_union(&rules, &0).is_empty());
+
+    let rules = [(ordmask![_], 'a'), (ordmask![], 'b'), (ordmask![_], 'c')];
+    assert_eq!(OrdMask::apply_mask_union(&rules, &0), vec![&'a', &'c']);
+}
+
+#[test]
+fn multi_mask_index() {
+    let masks = all_masks(5);
+    let index = MultiMaskIndex::new(masks.iter().enumerate());
+    for x in -1..=5 {
+        let expected: Vec<_> = (0..masks.len())
+            .filter(|i| masks[*i].included(&x))
+            .collect();
+        assert_eq!(index.matching(&x), expected, "at {}", x);
+    }
+}
+
+#[test]
+fn multi_mask_index_pseudo_random() {
+    let mut lcg = Lcg::new(42);
+    let mut next = || lcg.next_u32() as i32 % 1000;
+    let masks: Vec<OrdMask<i32>> = (0..50)
+        .map(|_| OrdMask::from_ranges((0..10).map(|_| (next(), next()))))
+        .collect();
+    let index = MultiMaskIndex::new(masks.iter().enumerate());
+    for _ in 0..1000 {
+        let x = next() - 10;
+        let expected: Vec<_> = (0..masks.len())
+            .filter(|i| masks[*i].included(&x))
+            .collect();
+        assert_eq!(index.matching(&x), expected, "at {}", x);
+    }
+}
+
+#[test]
+fn multi_mask_index_degenerate() {
+    let index: MultiMaskIndex<i32, &str> = MultiMaskIndex::new([]);
+    assert!(index.matching(&0).is_empty());
+
+    let universal = OrdMask::universal();
+    let index = MultiMaskIndex::new([("all", &universal), ("none", &ordmask![])]);
+    assert_eq!(index.matching(&0), &["all"]);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use common::all_masks;
+use ordmask::ordmask;
+use std::time::{Duration, Instant};
+
+#[test]
+fn measure_in_range() {
+    let mask = ordmask![_, 0, 10, 20];
+    assert_eq!(mask.measure_in_range(&-5, &25), 15);
+    assert_eq!(mask.measure_in_range(&0, &10), 0);
+    assert_eq!(mask.measure_in_range(&10, &10), 0);
+    assert_eq!(mask.measure_in_range(&20, &10), 0);
+    assert_eq!(mask.total_excluded_length_in_range(&-5, &25), 15);
+    assert_eq!(mask.total_excluded_length_in_range(&20, &10), 0);
+
+    for mask in all_masks(6) {
+        for start in -2..8 {
+            for end in start..8 {
+                let measure = mask.measure_in_range(&start, &end);
+                let expected = (start..end).filter(|x| mask.included(x)).count() as i32;
+                assert_eq!(measure, expected, "{} in [{}, {})", mask, start, end);
+                assert_eq!(
+                    mask.total_excluded_length_in_range(&start, &end),
+                    (end - start) - measure
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn measure_with_different_length_type() {
+    let t0 = Instant::now();
+    let t = |secs| t0 + Duration::from_secs(secs);
+    let recording = ordmask![t(0), t(10), t(20), t(30)];
+    assert_eq!(
+        recording.measure_in_range(&t(5), &t(25)),
+        Duration::from_secs(10)
+    );
+    assert_eq!(
+        recording.total_excluded_length_in_range(&t(5), &t(40)),
+        Duration::from_secs(20)
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::masks_over;
+use ordmask::{ordmask, OrdMask};
+use std::cmp::Reverse;
+
+#[test]
+fn invert_order() {
+    for mask in masks_over(&[0u8, 1, 2, 100, 254, 255]) {
+        let inverted = mask.clone().invert_order();
+        assert!(inverted.is_valid() && inverted.is_simplified());
+        for x in 0..=255 {
+            assert_eq!(
+                inverted.included(&Reverse(x)),
+                mask.included(&x),
+                "mask: {}, x: {}",
+                mask,
+                x
+            );
+        }
+        let restored = OrdMask::from_reversed_order(inverted);
+        match mask.key_points().first() {
+            // The key point at the minimum value is folded into the orientation
+            Some(0) => {
+                assert_eq!(restored.key_points()[..], mask.key_points()[1..]);
+                assert_ne!(restored.is_include_min_value(), mask.is_include_min_value());
+            }
+            _ => assert_eq!(restored, mask),
+        }
+    }
+}
+
+#[test]
+fn from_reversed_order() {
+    let mask = ordmask![Reverse(200u8), Reverse(100), Reverse(0)];
+    let restored = OrdMask::from_reversed_order(mask.clone());
+    assert_eq!(restored, ordmask![_, 1, 101, 201]);
+    for x in 0..=255 {
+        assert_eq!(restored.included(&x), mask.included(&Reverse(x)));
+    }
+
+    let mask = ordmask![_, Reverse(255u8), Reverse(10)];
+    let restored = OrdMask::from_reversed_order(mask.clone());
+    for x in 0..=255 {
+        assert_eq!(restored.included(&x), mask.included(&Reverse(x)));
+    }
+    assert_eq!(restored, ordmask![_, 11]);
+    assert_eq!(restored.invert_order(), ordmask![Reverse(10)]);
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn overlay() {
+    let masks = all_masks(3);
+    for default in &masks {
+        for values in &masks {
+            for domain in &masks {
+                assert_eq!(
+                    default.overlay(values, domain),
+                    (values & domain) | (default - domain),
+                    "{} overlaid by {} in {}",
+                    default,
+                    values,
+                    domain
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn overlay_with_degenerate_domain() {
+    let default = ordmask![0, 10, 20, 30];
+    let values = ordmask![_, 5, 25];
+    assert_eq!(default.overlay(&values, &OrdMask::empty()), default);
+    assert_eq!(default.overlay(&values, &OrdMask::universal()), values);
+    assert_eq!(
+        default.overlay(&OrdMask::empty(), &OrdMask::universal()),
+        ordmask![]
+    );
+    assert_eq!(
+        default.overlay(&OrdMask::universal(), &ordmask![_, 5]),
+        ordmask![_, 10, 20, 30]
+    );
+}
+
+#[test]
+fn carve_out() {
+    let masks = all_masks(4);
+    for a in &masks {
+        for b in &masks {
+            let (overlap, rest) = a.carve_out(b);
+            assert_eq!(overlap, a & b, "{} and {}", a, b);
+            assert_eq!(rest, a - b, "{} and {}", a, b);
+            assert_eq!(&overlap | &rest, *a, "{} and {}", a, b);
+            assert!((&overlap & &rest).is_empty(), "{} and {}", a, b);
+        }
+    }
+}
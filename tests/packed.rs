@@ -0,0 +1,162 @@
+#![cfg(feature = "packed")]
+
+mod common;
+
+use common::{masks_over, min_value_constructions, Lcg};
+use ordmask::packed::{query_included, LazyMask, PackError, PackedMask};
+use ordmask::{ordmask, OrdMask};
+use std::collections::HashSet;
+
+fn check(mask: &OrdMask<u32>, probes: impl IntoIterator<Item = u32>) {
+    let packed = PackedMask::from_mask(mask).unwrap();
+    let mut folded = mask.clone();
+    folded.fold_min_value();
+    assert_eq!(packed.to_mask(), folded);
+    for x in probes {
+        assert_eq!(packed.included(x), mask.included(&x), "{} at {}", mask, x);
+    }
+    let restored = PackedMask::from_bytes(&packed.as_bytes()).unwrap();
+    assert_eq!(restored, packed);
+}
+
+#[test]
+fn packed_round_trip() {
+    for mask in masks_over(&[0u32, 1, 2, 100, 65535]) {
+        check(&mask, [0, 1, 2, 3, 99, 100, 101, 65535, 65536, u32::MAX]);
+    }
+}
+
+#[test]
+fn packed_pseudo_random() {
+    let mut lcg = Lcg::new(7);
+    let mut next = || lcg.next_u32();
+    for len in [0, 1, 63, 64, 65, 200, 1000] {
+        let mut point = next() % 1000;
+        let mut key_points = Vec::with_capacity(len);
+        for _ in 0..len {
+            key_points.push(point);
+            point += 1 + next() % 1000;
+        }
+        let reversed = next() % 2 == 0;
+        let mask = OrdMask::from_sorted_key_points_checking_strict(key_points, reversed).unwrap();
+        let probes: Vec<u32> = (0..2000).map(|_| next() % (point + 10)).collect();
+        check(
+            &mask,
+            probes.iter().copied().chain(mask.key_points().clone()),
+        );
+    }
+}
+
+#[test]
+fn packed_delta_overflow() {
+    assert_eq!(
+        PackedMask::from_mask(&ordmask![1u32, 65536, 131072]),
+        Err(PackError::DeltaOverflow { index: 2 })
+    );
+    assert!(PackedMask::from_mask(&ordmask![1u32, 65536, 131071]).is_ok());
+    // the key point at 0 is folded before the deltas are computed
+    assert_eq!(
+        PackedMask::from_mask(&ordmask![0u32, 65536, 131072]),
+        Err(PackError::DeltaOverflow { index: 1 })
+    );
+}
+
+#[test]
+fn packed_invalid_bytes() {
+    assert_eq!(PackedMask::from_bytes(&[]), Err(PackError::InvalidBytes));
+    assert_eq!(
+        PackedMask::from_bytes(&[2, 0, 0, 0, 0]),
+        Err(PackError::InvalidBytes)
+    );
+    assert_eq!(
+        PackedMask::from_bytes(&[0, 2, 0, 0, 0, 1, 0, 0, 0]),
+        Err(PackError::InvalidBytes)
+    );
+    // A first key point at 0, which `from_mask` folds into the flag
+    assert_eq!(
+        PackedMask::from_bytes(&[0, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0]),
+        Err(PackError::InvalidBytes)
+    );
+    assert_eq!(
+        PackedMask::from_bytes(&[1, 0, 0, 0, 0]),
+        Ok(PackedMask::from_mask(&OrdMask::universal()).unwrap())
+    );
+    // A zero delta
+    assert_eq!(
+        PackedMask::from_bytes(&[0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0]),
+        Err(PackError::InvalidBytes)
+    );
+    // A delta overflowing u32
+    assert_eq!(
+        PackedMask::from_bytes(&[0, 2, 0, 0, 0, 255, 255, 255, 255, 1, 0]),
+        Err(PackError::InvalidBytes)
+    );
+}
+
+#[test]
+fn lazy_mask_agrees_with_decoding() {
+    let mut lcg = Lcg::new(11);
+    let mut next = || lcg.next_u32();
+    for len in [0, 1, 2, 3, 64, 65, 500] {
+        for reversed in [false, true] {
+            let mut point = next() % 1000;
+            let mut key_points = Vec::with_capacity(len);
+            for _ in 0..len {
+                key_points.push(point);
+                point += 1 + next() % 1000;
+            }
+            let mask =
+                OrdMask::from_sorted_key_points_checking_strict(key_points, reversed).unwrap();
+            let bytes = PackedMask::from_mask(&mask).unwrap().as_bytes();
+            let lazy = LazyMask::new(&bytes).unwrap();
+            assert_eq!(lazy.first_key_point(), mask.key_points().first().copied());
+            assert_eq!(lazy.range_count(), mask.ranges().len(), "{}", mask);
+            let probes = (0..500).map(|_| next() % (point + 10));
+            for x in probes.chain(mask.key_points().clone()).chain([0, u32::MAX]) {
+                assert_eq!(lazy.included(x), Ok(mask.included(&x)), "{} at {}", mask, x);
+                assert_eq!(query_included(&bytes, x), Ok(mask.included(&x)));
+            }
+        }
+    }
+}
+
+#[test]
+fn lazy_mask_invalid_bytes() {
+    let bytes = PackedMask::from_mask(&ordmask![10u32, 20, 30])
+        .unwrap()
+        .as_bytes();
+    assert_eq!(
+        LazyMask::new(&bytes[..bytes.len() - 1]),
+        Err(PackError::InvalidBytes)
+    );
+    assert_eq!(
+        LazyMask::new(&[2, 0, 0, 0, 0]),
+        Err(PackError::InvalidBytes)
+    );
+
+    // a zero delta is found by the queries reaching it
+    let mut zero_delta = bytes.clone();
+    zero_delta[9..11].copy_from_slice(&0u16.to_le_bytes());
+    assert_eq!(query_included(&zero_delta, 5), Ok(false));
+    assert_eq!(
+        query_included(&zero_delta, 15),
+        Err(PackError::InvalidBytes)
+    );
+}
+
+#[test]
+fn serialization_is_canonical() {
+    let mut serialized_sets = HashSet::new();
+    for bits in 0..(1 << 7) {
+        let serialized: HashSet<Vec<u8>> = min_value_constructions(bits)
+            .into_iter()
+            .map(|mask| {
+                let mask = mask.try_convert::<u32>().unwrap();
+                PackedMask::from_mask(&mask).unwrap().as_bytes()
+            })
+            .collect();
+        assert_eq!(serialized.len(), 1, "{:07b}", bits);
+        serialized_sets.extend(serialized);
+    }
+    assert_eq!(serialized_sets.len(), 1 << 7);
+}
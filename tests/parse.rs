@@ -0,0 +1,71 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, Error, OrdMask, ParseError};
+
+fn parse(s: &str) -> Result<OrdMask<i32>, ParseError<std::num::ParseIntError>> {
+    OrdMask::try_from_str_key_points(s, ',')
+}
+
+#[test]
+fn try_from_str_key_points() {
+    let test_cases = vec![
+        ("", ordmask![]),
+        ("  ", ordmask![]),
+        ("_", ordmask![_]),
+        ("0", ordmask![0]),
+        ("_,0", ordmask![_, 0]),
+        ("0,10,20", ordmask![0, 10, 20]),
+        (" _ , -5 , 15 ", ordmask![_, -5, 15]),
+        ("0,10,10,20", ordmask![0, 20]),
+    ];
+
+    for (s, expected) in test_cases {
+        assert_eq!(parse(s), Ok(expected), "{:?}", s);
+    }
+}
+
+#[test]
+fn try_from_str_key_points_error() {
+    assert!(matches!(
+        parse("0,ten,20"),
+        Err(ParseError::KeyPoint { index: 1, .. })
+    ));
+    assert!(matches!(
+        parse("_,0,,20"),
+        Err(ParseError::KeyPoint { index: 2, .. })
+    ));
+    assert!(matches!(
+        parse("0,_"),
+        Err(ParseError::KeyPoint { index: 1, .. })
+    ));
+    assert_eq!(
+        parse("0,20,10"),
+        Err(ParseError::Order(Error::Falling { index: 2 }))
+    );
+
+    let message = parse("0,x").unwrap_err().to_string();
+    assert!(message.contains("index 1"));
+}
+
+#[test]
+fn to_str_key_points() {
+    assert_eq!(OrdMask::<i32>::universal().to_str_key_points(','), "_");
+    assert_eq!(ordmask![_, -1].to_str_key_points(' '), "_ -1");
+    assert_eq!(ordmask![3].to_str_key_points(','), "3");
+}
+
+#[test]
+fn str_key_points_round_trip() {
+    for mask in all_masks(6) {
+        for sep in [',', ';', ' '] {
+            let s = mask.to_str_key_points(sep);
+            assert_eq!(
+                OrdMask::try_from_str_key_points(&s, sep),
+                Ok(mask.clone()),
+                "{:?}",
+                s
+            );
+        }
+    }
+}
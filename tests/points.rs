@@ -0,0 +1,113 @@
+mod common;
+
+use common::{all_masks, masks_over};
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn is_point_set() {
+    let test_cases = vec![
+        (OrdMask::<u8>::empty(), Some(vec![])),
+        (OrdMask::universal(), None),
+        (ordmask![0, 1], Some(vec![0])),
+        (ordmask![0, 2], None),
+        (ordmask![3, 4, 10, 11], Some(vec![3, 10])),
+        (ordmask![3, 4, 10, 12], None),
+        (ordmask![255], Some(vec![255])),
+        (ordmask![254], None),
+        (ordmask![_, 1], None),
+        (ordmask![_, 0, 1, 3, 4], None),
+    ];
+
+    for (mask, expected) in test_cases {
+        assert_eq!(mask.is_point_set(), expected.is_some(), "{}", mask);
+        assert_eq!(mask.to_point_set(), expected, "{}", mask);
+    }
+}
+
+#[test]
+fn from_points() {
+    assert_eq!(OrdMask::<u8>::from_points([]), ordmask![]);
+    assert_eq!(OrdMask::from_points([7u8, 7, 7]), ordmask![7, 8]);
+    assert_eq!(OrdMask::from_points([9u8, 3, 1, 2]), ordmask![1, 4, 9, 10]);
+    assert_eq!(OrdMask::from_points([255u8, 254]), ordmask![254]);
+    assert_eq!(OrdMask::from_points([255u8, 100]), ordmask![100, 101, 255]);
+    assert_eq!(OrdMask::from_points(0..=255u8), ordmask![0]);
+}
+
+#[test]
+fn points_round_trip() {
+    let points = vec![0u8, 2, 100, 255];
+    let mask = OrdMask::from_points(points.clone());
+    assert!(mask.is_point_set());
+    assert_eq!(mask.to_point_set(), Some(points));
+
+    // Consecutive points are merged, so they can't be restored as a point set
+    let mask = OrdMask::from_points([4u8, 5, 255]);
+    assert_eq!(mask, ordmask![4, 6, 255]);
+    assert!(!mask.is_point_set());
+    assert_eq!(mask.to_point_set(), None);
+
+    for mask in masks_over(&[0u8, 1, 2, 254, 255]) {
+        if let Some(points) = mask.to_point_set() {
+            assert_eq!(OrdMask::from_points(points.clone()), mask);
+            for x in 0..=255 {
+                assert_eq!(points.contains(&x), mask.included(&x), "{}", mask);
+            }
+        }
+    }
+}
+
+#[test]
+fn extend_to_cover() {
+    for mask in masks_over(&[0u8, 1, 2, 4, 254, 255]) {
+        for x in [0u8, 1, 2, 3, 4, 5, 253, 254, 255] {
+            let expected = &mask | &OrdMask::from_points([x]);
+            assert_eq!(mask.extend_to_cover(&x), expected, "{} at {}", mask, x);
+            let mut in_place = mask.clone();
+            in_place.extend_to_cover_in_place(&x);
+            assert_eq!(in_place, expected, "{} at {}", mask, x);
+        }
+    }
+}
+
+#[test]
+fn missing_from_sorted() {
+    let observations: Vec<Vec<i32>> = vec![
+        vec![],
+        vec![0],
+        vec![0, 0, 0],
+        vec![1, 2, 3],
+        vec![0, 1, 2, 3, 4, 5],
+        vec![-1, 2, 2, 4, 6],
+        vec![1, 3, 5],
+        vec![-2, -1, 0, 4, 5, 6, 7],
+    ];
+    for mask in all_masks(6) {
+        for observed in &observations {
+            let missing = mask.missing_from_sorted(observed);
+            let expected = mask.minus(&[&OrdMask::from_points(observed.iter().copied())]);
+            assert_eq!(missing, expected, "{} {:?}", mask, observed);
+        }
+    }
+}
+
+#[test]
+fn missing_from_sorted_at_max_value() {
+    let mask = ordmask![250u8];
+    assert_eq!(
+        mask.missing_from_sorted(&[253, 254, 255]),
+        ordmask![250, 253]
+    );
+    assert_eq!(
+        mask.missing_from_sorted(&[250, 255, 255]),
+        ordmask![251, 255]
+    );
+    assert_eq!(
+        mask.missing_from_sorted(&(250..=255).collect::<Vec<_>>()),
+        ordmask![]
+    );
+    assert_eq!(
+        ordmask![_, 3u8].missing_from_sorted(&[0, 1]),
+        ordmask![_, 0, 2, 3]
+    );
+}
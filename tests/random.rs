@@ -0,0 +1,74 @@
+mod common;
+
+use common::{all_masks, Lcg};
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn random_submask_is_subset() {
+    let mut lcg = Lcg::new(3);
+    for mask in all_masks(6) {
+        for granularity in 1..4 {
+            for keep_fraction in [0.0, 0.3, 0.7, 1.0] {
+                let submask = mask.random_submask(|| lcg.next_f64(), keep_fraction, &granularity);
+                assert!(submask.is_subset_of(&mask), "{} ⊇ {}", mask, submask);
+                assert!(submask.is_strictly_sorted(), "{}", submask);
+                if keep_fraction == 0.0 {
+                    assert!(submask.is_empty());
+                }
+                if keep_fraction == 1.0 {
+                    let bounded = mask.intersection_with_range(
+                        *mask.key_points().first().unwrap_or(&0),
+                        *mask.key_points().last().unwrap_or(&0),
+                    );
+                    assert_eq!(submask, bounded, "{}", mask);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn random_submask_skips_unbounded_ranges() {
+    let mask = ordmask![_, 0, 10, 20, 30];
+    assert_eq!(mask.random_submask(|| 0.0, 1.0, &5), ordmask![10, 20]);
+    assert_eq!(
+        OrdMask::<i32>::universal().random_submask(|| 0.0, 1.0, &5),
+        ordmask![]
+    );
+}
+
+#[test]
+fn random_submask_keeps_fraction() {
+    let mask = ordmask![0i64, 40_000, 50_000, 110_000];
+    for (seed, keep_fraction) in [(1, 0.3), (2, 0.5), (3, 0.7)] {
+        let mut lcg = Lcg::new(seed);
+        let submask = mask.random_submask(|| lcg.next_f64(), keep_fraction, &10);
+        let kept = submask.measure_in_range(&0, &110_000) as f64 / 100_000.0;
+        assert!(
+            (kept - keep_fraction).abs() < 0.02,
+            "kept {} instead of {}",
+            kept,
+            keep_fraction
+        );
+    }
+}
+
+#[test]
+fn random_submask_near_max() {
+    let mask = ordmask![250u8, 255];
+    assert_eq!(mask.random_submask(|| 0.0, 1.0, &4), ordmask![250, 255]);
+    assert_eq!(mask.random_submask(|| 0.0, 1.0, &5), ordmask![250, 255]);
+    let mut coin = [0.0, 1.0].into_iter();
+    assert_eq!(
+        mask.random_submask(|| coin.next().unwrap(), 0.5, &4),
+        ordmask![250, 254]
+    );
+    let mask = ordmask![u64::MAX - 25, u64::MAX];
+    assert_eq!(mask.random_submask(|| 0.0, 1.0, &10), mask);
+}
+
+#[test]
+#[should_panic(expected = "the granularity must be positive")]
+fn random_submask_zero_granularity() {
+    ordmask![0, 10].random_submask(|| 0.0, 1.0, &0);
+}
@@ -0,0 +1,266 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+use std::ops::{Bound, Bound::*, RangeBounds};
+
+#[test]
+fn ranges() {
+    let mask: OrdMask<i32> = ordmask![];
+    assert_eq!(mask.ranges().len(), 0);
+    assert_eq!(mask.ranges().next(), None);
+
+    let mask: OrdMask<i32> = ordmask![_];
+    assert_eq!(mask.ranges().len(), 1);
+    assert_eq!(
+        mask.ranges().collect::<Vec<_>>(),
+        vec![(Unbounded, Unbounded)]
+    );
+
+    let mask = ordmask![_, 0];
+    assert_eq!(
+        mask.ranges().collect::<Vec<_>>(),
+        vec![(Unbounded, Excluded(&0))]
+    );
+
+    let mask = ordmask![_, 0, 10];
+    assert_eq!(mask.ranges().len(), 2);
+    assert_eq!(
+        mask.ranges().collect::<Vec<_>>(),
+        vec![(Unbounded, Excluded(&0)), (Included(&10), Unbounded)]
+    );
+
+    let mask = ordmask![0, 10, 20, 30];
+    assert_eq!(mask.ranges().len(), 2);
+    assert_eq!(
+        mask.ranges().collect::<Vec<_>>(),
+        vec![
+            (Included(&0), Excluded(&10)),
+            (Included(&20), Excluded(&30))
+        ]
+    );
+}
+
+#[test]
+fn to_half_open_pairs() {
+    let test_cases = vec![
+        (ordmask![], vec![]),
+        (ordmask![_], vec![(None, None)]),
+        (ordmask![0], vec![(Some(&0), None)]),
+        (ordmask![_, 0], vec![(None, Some(&0))]),
+        (ordmask![0, 10], vec![(Some(&0), Some(&10))]),
+        (
+            ordmask![_, 0, 10],
+            vec![(None, Some(&0)), (Some(&10), None)],
+        ),
+        (
+            ordmask![0, 10, 20, 30],
+            vec![(Some(&0), Some(&10)), (Some(&20), Some(&30))],
+        ),
+        (
+            ordmask![_, 0, 10, 20, 30],
+            vec![(None, Some(&0)), (Some(&10), Some(&20)), (Some(&30), None)],
+        ),
+    ];
+
+    for (mask, expected) in &test_cases {
+        assert_eq!(mask.to_half_open_pairs().collect::<Vec<_>>(), *expected);
+    }
+}
+
+#[test]
+fn ranges_from() {
+    let mask = ordmask![0, 10, 20, 30];
+    let from = |x: i32| mask.ranges_from(&x).collect::<Vec<_>>();
+    let all: Vec<_> = mask.ranges().collect();
+    assert_eq!(from(-100), all);
+    assert_eq!(from(0), all);
+    assert_eq!(from(9), all);
+    assert_eq!(from(10), all[1..]);
+    assert_eq!(from(19), all[1..]);
+    assert_eq!(from(20), all[1..]);
+    assert_eq!(from(29), all[1..]);
+    assert_eq!(from(30), vec![]);
+    assert_eq!(mask.ranges_from(&10).len(), 1);
+
+    let mask = ordmask![_, 0, 10, 20];
+    let from = |x: i32| mask.ranges_from(&x).collect::<Vec<_>>();
+    let all: Vec<_> = mask.ranges().collect();
+    assert_eq!(from(-100), all);
+    assert_eq!(from(-1), all);
+    assert_eq!(from(0), all[1..]);
+    assert_eq!(from(10), all[1..]);
+    assert_eq!(from(20), vec![]);
+    assert_eq!(from(100), vec![]);
+
+    let mask: OrdMask<i32> = ordmask![_];
+    assert_eq!(
+        mask.ranges_from(&0).collect::<Vec<_>>(),
+        vec![(Unbounded, Unbounded)]
+    );
+    let mask: OrdMask<i32> = ordmask![];
+    assert_eq!(mask.ranges_from(&0).next(), None);
+}
+
+#[test]
+fn gaps_from() {
+    let mask = ordmask![0, 10, 20, 30];
+    let gaps = |x: i32| mask.gaps_from(&x).collect::<Vec<_>>();
+    assert_eq!(
+        gaps(-100),
+        vec![
+            (Unbounded, Excluded(&0)),
+            (Included(&10), Excluded(&20)),
+            (Included(&30), Unbounded)
+        ]
+    );
+    assert_eq!(
+        gaps(0),
+        vec![(Included(&10), Excluded(&20)), (Included(&30), Unbounded)]
+    );
+    assert_eq!(
+        gaps(10),
+        vec![(Included(&10), Excluded(&20)), (Included(&30), Unbounded)]
+    );
+    assert_eq!(gaps(20), vec![(Included(&30), Unbounded)]);
+    assert_eq!(gaps(100), vec![(Included(&30), Unbounded)]);
+
+    let mask = ordmask![_, 0, 10];
+    assert_eq!(
+        mask.gaps_from(&-5).collect::<Vec<_>>(),
+        vec![(Included(&0), Excluded(&10))]
+    );
+    assert_eq!(mask.gaps_from(&10).next(), None);
+}
+
+#[test]
+fn first_range_after() {
+    let owned = |(start, end): (Bound<&i32>, Bound<&i32>)| (start.cloned(), end.cloned());
+    let starts_at_or_after = |value: i32| move |(start, _): &(Bound<i32>, Bound<i32>)| matches!(start, Included(start) if *start >= value);
+    for mask in all_masks(5) {
+        let complement = mask.new_complement();
+        for value in -1..7 {
+            let expected = mask.ranges().map(owned).find(starts_at_or_after(value));
+            assert_eq!(
+                mask.first_included_range_after(&value),
+                expected,
+                "{mask} {value}"
+            );
+            let expected = complement
+                .ranges()
+                .map(owned)
+                .find(starts_at_or_after(value));
+            assert_eq!(
+                mask.first_excluded_gap_after(&value),
+                expected,
+                "{mask} {value}"
+            );
+        }
+    }
+}
+
+#[test]
+fn last_range_before() {
+    let owned = |(start, end): (Bound<&i32>, Bound<&i32>)| (start.cloned(), end.cloned());
+    let ends_at_or_before = |value: i32| move |(_, end): &(Bound<i32>, Bound<i32>)| matches!(end, Excluded(end) if *end <= value);
+    for mask in all_masks(5) {
+        let complement = mask.new_complement();
+        for value in -1..7 {
+            let expected = mask
+                .ranges()
+                .map(owned)
+                .filter(ends_at_or_before(value))
+                .last();
+            assert_eq!(
+                mask.last_included_range_before(&value),
+                expected,
+                "{mask} {value}"
+            );
+            let expected = complement
+                .ranges()
+                .map(owned)
+                .filter(ends_at_or_before(value))
+                .last();
+            assert_eq!(
+                mask.last_excluded_gap_before(&value),
+                expected,
+                "{mask} {value}"
+            );
+        }
+    }
+}
+
+#[test]
+fn every_other_range() {
+    let mask = ordmask![_, 0, 10, 20, 30, 40];
+    assert_eq!(mask.every_other_range(0), ordmask![_, 0, 30, 40]);
+    assert_eq!(mask.every_other_range(1), ordmask![10, 20]);
+    assert_eq!(mask.every_other_range(2), ordmask![30, 40]);
+    assert_eq!(mask.every_other_range(3), ordmask![]);
+    assert_eq!(mask.every_other_range(0) | mask.every_other_range(1), mask);
+
+    let mask = ordmask![0, 10, 20];
+    assert_eq!(mask.every_other_range(0), ordmask![0, 10]);
+    assert_eq!(mask.every_other_range(1), ordmask![20]);
+
+    let mask: OrdMask<i32> = ordmask![_];
+    assert_eq!(mask.every_other_range(0), ordmask![_]);
+    assert_eq!(mask.every_other_range(1), ordmask![]);
+    let mask: OrdMask<i32> = ordmask![];
+    assert_eq!(mask.every_other_range(0), ordmask![]);
+}
+
+#[test]
+fn find_and_filter_ranges() {
+    let mask = ordmask![_, 0, 10, 20, 30];
+    let bounded = |start: Bound<&i32>, end: Bound<&i32>| start != Unbounded && end != Unbounded;
+    assert_eq!(
+        mask.find_range_by_predicate(bounded),
+        Some((Included(10), Excluded(20)))
+    );
+    assert_eq!(
+        mask.find_range_by_predicate(|start, _| start == Unbounded),
+        Some((Unbounded, Excluded(0)))
+    );
+    assert_eq!(mask.find_range_by_predicate(|_, _| false), None);
+    assert_eq!(
+        mask.filter_ranges_collect(|_, end| end != Unbounded),
+        vec![(Unbounded, Excluded(0)), (Included(10), Excluded(20))]
+    );
+    assert_eq!(
+        mask.filter_ranges_collect(|_, _| true).len(),
+        mask.ranges().len()
+    );
+    assert_eq!(mask.filter_ranges_collect(|_, _| false), vec![]);
+}
+
+#[test]
+fn remove_ranges_not_touching() {
+    let mask = ordmask![_, 0, 10, 20, 30];
+    let touching = |x: i32| mask.remove_ranges_not_touching(&x);
+    assert_eq!(touching(-5), ordmask![_, 0]);
+    assert_eq!(touching(0), ordmask![_, 0]);
+    assert_eq!(touching(5), ordmask![]);
+    assert_eq!(touching(10), ordmask![10, 20]);
+    assert_eq!(touching(20), ordmask![10, 20]);
+    assert_eq!(touching(25), ordmask![]);
+    assert_eq!(touching(30), ordmask![30]);
+    assert_eq!(touching(100), ordmask![30]);
+
+    let mask: OrdMask<i32> = ordmask![_];
+    assert_eq!(mask.remove_ranges_not_touching(&0), ordmask![_]);
+    let mask: OrdMask<i32> = ordmask![];
+    assert_eq!(mask.remove_ranges_not_touching(&0), ordmask![]);
+
+    for mask in all_masks(5) {
+        for x in -1..=5 {
+            let touching = mask.remove_ranges_not_touching(&x);
+            assert!(touching.is_subset_of(&mask), "{} at {}", mask, x);
+            assert!(touching.ranges().len() <= 1, "{} at {}", mask, x);
+            let expected = mask
+                .ranges()
+                .find(|(start, end)| (*start, *end).contains(&x) || *end == Excluded(&x));
+            assert_eq!(touching.ranges().next(), expected, "{} at {}", mask, x);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, MaskLike, OrdMask};
+
+fn queries(mask: &impl MaskLike<i32>) -> (Vec<bool>, bool, bool, bool, bool, usize) {
+    (
+        (-1..8).map(|x| mask.included(&x)).collect(),
+        mask.is_empty(),
+        mask.is_universal(),
+        mask.is_include_min_value(),
+        mask.is_include_max_value(),
+        mask.ranges().count(),
+    )
+}
+
+#[test]
+fn referenced_mask() {
+    for mask in all_masks(6) {
+        let referenced = mask.as_referenced_mask();
+        assert_eq!(queries(&referenced), queries(&mask));
+        assert_eq!(
+            (-1..8).map(|x| referenced.excluded(&x)).collect::<Vec<_>>(),
+            (-1..8).map(|x| mask.excluded(&x)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            referenced.ranges().collect::<Vec<_>>(),
+            mask.ranges().collect::<Vec<_>>()
+        );
+        assert_eq!(MaskLike::key_points(&referenced), &mask.key_points()[..]);
+        assert_eq!(referenced.to_mask(), mask);
+    }
+
+    let mask: OrdMask<i32> = ordmask![];
+    assert!(mask.as_referenced_mask().is_empty());
+    let mask: OrdMask<i32> = ordmask![_];
+    assert!(mask.as_referenced_mask().is_universal());
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn reflect_around() {
+    for mask in all_masks(5) {
+        for center in -1..=5 {
+            let reflected = mask.reflect_around(center);
+            assert_eq!(reflected.reflect_around(center), mask, "{}", mask);
+            for x in -10..=20 {
+                // [a, b) is reflected to [2c - b, 2c - a), so x maps to 2c - 1 - x
+                assert_eq!(
+                    reflected.included(&(2 * center - 1 - x)),
+                    mask.included(&x),
+                    "{} around {}",
+                    mask,
+                    center
+                );
+            }
+            assert_eq!(
+                mask.is_symmetric_around(center),
+                reflected == mask,
+                "{} around {}",
+                mask,
+                center
+            );
+        }
+    }
+}
+
+#[test]
+fn is_symmetric_around() {
+    assert!(OrdMask::<i32>::empty().is_symmetric_around(0));
+    assert!(OrdMask::<i32>::universal().is_symmetric_around(0));
+    assert!(ordmask![-1, 1].is_symmetric_around(0));
+    assert!(!ordmask![-1, 2].is_symmetric_around(0));
+    assert!(ordmask![_, -1, 1].is_symmetric_around(0));
+    assert!(ordmask![0, 1, 3, 4].is_symmetric_around(2));
+    assert!(!ordmask![0, 1, 3, 4].is_symmetric_around(1));
+    assert!(!ordmask![_, 0].is_symmetric_around(0));
+}
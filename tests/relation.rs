@@ -0,0 +1,100 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+use std::ops::{Bound, RangeBounds};
+
+#[test]
+fn is_subset_of() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            assert_eq!(a.is_subset_of(b), &(a & b) == a, "{} ⊆ {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn strictly_contains() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            assert_eq!(
+                a.strictly_contains(b),
+                b.is_subset_of(a) && a != b,
+                "{} ⊃ {}",
+                a,
+                b
+            );
+        }
+    }
+
+    assert!(ordmask![_, 0].strictly_contains(&ordmask![_, -1]));
+    assert!(!ordmask![_, 0].strictly_contains(&ordmask![_, 0]));
+    assert!(ordmask![0].strictly_contains(&ordmask![0, 10]));
+    assert!(!ordmask![0, 10].strictly_contains(&ordmask![0]));
+}
+
+#[test]
+fn find_common_value() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            let common = a & b;
+            match a.find_common_value(b) {
+                Some(v) => {
+                    assert!(a.included(&v) && b.included(&v), "{} ∩ {} at {}", a, b, v);
+                    let expected = common
+                        .key_points()
+                        .first()
+                        .filter(|_| !common.is_include_min_value());
+                    assert_eq!(expected.copied().unwrap_or(i32::MIN), v, "{} ∩ {}", a, b);
+                }
+                None => assert!(common.is_empty(), "{} ∩ {}", a, b),
+            }
+        }
+    }
+}
+
+#[test]
+fn find_common_value_with_empty_regions() {
+    assert_eq!(ordmask![_, 0u8].find_common_value(&ordmask![_]), None);
+    assert_eq!(ordmask![_, 0u8, 5].find_common_value(&ordmask![_]), Some(5));
+    assert_eq!(
+        OrdMask::from(vec![3, 3, 7]).find_common_value(&ordmask![0, 10]),
+        Some(7)
+    );
+}
+
+#[test]
+fn is_within() {
+    type Bounds = (Bound<i32>, Bound<i32>);
+    let mut ranges: Vec<Bounds> = Vec::new();
+    for lo in (-2..=20).step_by(2) {
+        for hi in (-2..=20).step_by(2) {
+            for start in [Bound::Included(lo), Bound::Excluded(lo), Bound::Unbounded] {
+                for end in [Bound::Included(hi), Bound::Excluded(hi), Bound::Unbounded] {
+                    ranges.push((start, end));
+                }
+            }
+        }
+    }
+    // key points are multiples of 4 and bounds are even, so there are odd probes between them
+    for mask in all_masks(5) {
+        let mask = OrdMask::from_flat(
+            &mask.key_points().iter().map(|x| x * 4).collect::<Vec<_>>(),
+            mask.is_include_min_value(),
+        )
+        .unwrap();
+        for range in &ranges {
+            let expected = (-10..=30).all(|v| mask.excluded(&v) || range.contains(&v));
+            assert_eq!(
+                mask.is_within(*range),
+                expected,
+                "{} within {:?}",
+                mask,
+                range
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use ordmask::{ordmask, OrdMask, RepairPolicy, RepairReport};
+
+fn repair(key_points: Vec<i32>, policy: RepairPolicy) -> (OrdMask<i32>, RepairReport) {
+    OrdMask::repair(key_points, false, policy)
+}
+
+#[test]
+fn repair_valid_key_points() {
+    for policy in [RepairPolicy::SortAndSimplify, RepairPolicy::DropOffending] {
+        let (mask, report) = repair(vec![0, 10, 20], policy);
+        assert_eq!(mask, ordmask![0, 10, 20]);
+        assert!(report.is_clean());
+
+        let (mask, report) = repair(vec![0, 10, 10, 20], policy);
+        assert_eq!(mask, ordmask![0, 20]);
+        assert_eq!(
+            report,
+            RepairReport {
+                moved: 0,
+                dropped: 2
+            }
+        );
+
+        let (mask, report) = OrdMask::<i32>::repair(vec![], true, policy);
+        assert_eq!(mask, ordmask![_]);
+        assert!(report.is_clean());
+    }
+}
+
+#[test]
+fn repair_sort_and_simplify() {
+    let test_cases = vec![
+        (vec![10, 0], ordmask![0, 10], 2, 0),
+        (vec![0, 30, 10, 20], ordmask![0, 10, 20, 30], 3, 0),
+        (vec![0, 20, 10, 20, 30], ordmask![0, 10, 30], 2, 2),
+        (vec![5, 5, 1, 9], ordmask![1, 9], 2, 2),
+    ];
+
+    for (key_points, expected, moved, dropped) in test_cases {
+        let (mask, report) = repair(key_points.clone(), RepairPolicy::SortAndSimplify);
+        assert_eq!(mask, expected, "{:?}", key_points);
+        assert_eq!(report, RepairReport { moved, dropped }, "{:?}", key_points);
+    }
+}
+
+#[test]
+fn repair_drop_offending() {
+    let test_cases = vec![
+        (vec![10, 0], ordmask![10], 1),
+        (vec![0, 30, 10, 20], ordmask![0, 30], 2),
+        (vec![0, 20, 10, 20, 30], ordmask![0, 30], 3),
+        (vec![5, 5, 1, 9], ordmask![9], 3),
+    ];
+
+    for (key_points, expected, dropped) in test_cases {
+        let (mask, report) = repair(key_points.clone(), RepairPolicy::DropOffending);
+        assert_eq!(mask, expected, "{:?}", key_points);
+        assert_eq!(
+            report,
+            RepairReport { moved: 0, dropped },
+            "{:?}",
+            key_points
+        );
+    }
+}
+
+#[test]
+fn repair_keeps_reversed() {
+    let (mask, report) = OrdMask::repair(vec![10, 0], true, RepairPolicy::SortAndSimplify);
+    assert_eq!(mask, ordmask![_, 0, 10]);
+    assert_eq!(report.moved, 2);
+    assert!(mask.included(&-1));
+    assert!(!mask.included(&5));
+}
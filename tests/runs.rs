@@ -0,0 +1,41 @@
+mod common;
+
+use common::masks_over;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn encode_as_run_pairs() {
+    let test_cases = vec![
+        (OrdMask::<u8>::empty(), vec![(0, 100, false)]),
+        (OrdMask::universal(), vec![(0, 100, true)]),
+        (ordmask![0], vec![(0, 100, true)]),
+        (ordmask![_, 0, 50], vec![(0, 50, false), (50, 100, true)]),
+        (ordmask![_, 50, 100], vec![(0, 50, true), (50, 100, false)]),
+        (ordmask![_, 50, 150], vec![(0, 50, true), (50, 100, false)]),
+        (ordmask![100], vec![(0, 100, false)]),
+    ];
+
+    for (mask, expected) in test_cases {
+        assert_eq!(mask.encode_as_run_pairs(100), expected, "{}", mask);
+    }
+    assert_eq!(ordmask![_, 10u8].encode_as_run_pairs(0), vec![]);
+}
+
+#[test]
+fn run_pairs_cover_domain() {
+    for mask in masks_over(&[0u8, 1, 100, 254, 255]) {
+        let runs = mask.encode_as_run_pairs(255);
+        assert_eq!(runs.first().map(|run| run.0), Some(0), "{}", mask);
+        assert_eq!(runs.last().map(|run| run.1), Some(255), "{}", mask);
+        for pair in runs.windows(2) {
+            assert_eq!(pair[0].1, pair[1].0, "{}", mask);
+            assert_ne!(pair[0].2, pair[1].2, "{}", mask);
+        }
+        for (start, end, included) in runs {
+            assert!(start < end, "{}", mask);
+            for x in start..end {
+                assert_eq!(mask.included(&x), included, "{}", mask);
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::all_masks;
+
+#[test]
+fn scan() {
+    for mask in all_masks(5) {
+        let states = mask.scan(Vec::new(), |mut events, entering| {
+            events.push(entering);
+            events
+        });
+        assert_eq!(states.len(), mask.key_points().len(), "{}", mask);
+        for (i, point) in mask.key_points().iter().enumerate() {
+            assert_eq!(states[i].len(), i + 1, "{}", mask);
+            assert_eq!(states[i][i], mask.included(point), "{} at {}", mask, point);
+        }
+    }
+}
+
+#[test]
+fn scan_included_length() {
+    // the state carries the last key point, since `f` only sees the events
+    for mask in all_masks(5) {
+        let mut points = mask.key_points().iter();
+        let lengths = mask.scan((0, None), |(total, last), entering| {
+            let point = *points.next().unwrap();
+            match (entering, last) {
+                (false, Some(last)) => (total + point - last, None),
+                (true, _) => (total, Some(point)),
+                (false, None) => (total, None),
+            }
+        });
+        for (i, point) in mask.key_points().iter().enumerate() {
+            let first = &mask.key_points()[0];
+            let expected = mask.measure_in_range(first, point);
+            assert_eq!(lengths[i].0, expected, "{} at {}", mask, point);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "shared")]
+
+use ordmask::shared::SharedMask;
+use ordmask::{ordmask, OrdMask};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/// The `i`-th version includes `[i, i + 1)` of each of the blocks of `[0, 1000)`,
+/// so a torn read would be a mix of versions.
+fn version(i: u64) -> OrdMask<u64> {
+    OrdMask::from_ranges((0..100).map(|block| (block * 10 + i, block * 10 + i + 1)))
+}
+
+#[test]
+fn swap_while_querying() {
+    let shared = Arc::new(SharedMask::new(version(0)));
+    let done = Arc::new(AtomicBool::new(false));
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let (shared, done) = (shared.clone(), done.clone());
+            thread::spawn(move || {
+                while !done.load(Ordering::Relaxed) {
+                    let snapshot = shared.load();
+                    let i = (0..10).find(|i| snapshot.included(i)).unwrap();
+                    assert_eq!(*snapshot, version(i));
+                }
+            })
+        })
+        .collect();
+    for round in 0..200 {
+        shared.store(version(round % 10));
+    }
+    done.store(true, Ordering::Relaxed);
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
+
+#[test]
+fn rcu_from_many_threads() {
+    let shared = Arc::new(SharedMask::<u64>::default());
+    let writers: Vec<_> = (0..8)
+        .map(|t| {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                for i in 0..50 {
+                    let value = t * 100 + i;
+                    shared.rcu(|mask| mask | &OrdMask::in_range(value, value + 1));
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    let expected = OrdMask::from_ranges((0..8).map(|t| (t * 100, t * 100 + 50)));
+    assert_eq!(*shared.load(), expected);
+}
+
+#[test]
+fn swap_returns_the_replaced_mask() {
+    let shared = SharedMask::from(ordmask![0, 10]);
+    let snapshot = shared.load();
+    let replaced = shared.swap(ordmask![20, 30]);
+    assert!(Arc::ptr_eq(&snapshot, &replaced));
+    assert!(shared.included(&25));
+    assert!(shared.excluded(&5));
+    assert_eq!(*snapshot, ordmask![0, 10]);
+}
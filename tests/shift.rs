@@ -0,0 +1,58 @@
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn shift_operators() {
+    let mask: OrdMask<u64> = ordmask![10, 20, 30];
+    assert_eq!(&mask + 5u64, ordmask![15, 25, 35]);
+    assert_eq!(&mask - 5u64, ordmask![5, 15, 25]);
+    assert_eq!(mask.clone() + 0, mask);
+
+    let mask: OrdMask<i32> = ordmask![_, -10, 10];
+    assert_eq!(&mask + -5, ordmask![_, -15, 5]);
+    assert_eq!(&mask - -5, ordmask![_, -5, 15]);
+
+    let universal: OrdMask<u8> = ordmask![_];
+    assert_eq!(universal + 255, ordmask![_]);
+}
+
+#[test]
+fn shift_operators_do_not_conflict_with_difference() {
+    let mask: OrdMask<u64> = ordmask![0, 20];
+    let other: OrdMask<u64> = ordmask![5, 10];
+    assert_eq!(mask.clone() - other.clone(), ordmask![0, 5, 10, 20]);
+    assert_eq!(&mask - &other, ordmask![0, 5, 10, 20]);
+    assert_eq!(mask.clone() + 10u64 - 5u64, ordmask![5, 25]);
+    assert_eq!(&mask + 5u64 - &other, ordmask![10, 25]);
+}
+
+#[test]
+fn checked_and_saturating_shift() {
+    let mask: OrdMask<u8> = ordmask![10, 250];
+    assert_eq!(mask.checked_shift_right(5), Some(ordmask![15, 255]));
+    assert_eq!(mask.checked_shift_right(6), None);
+    assert_eq!(mask.checked_shift_left(10), Some(ordmask![0, 240]));
+    assert_eq!(mask.checked_shift_left(11), None);
+
+    assert_eq!(mask.saturating_shift_right(10), ordmask![20]);
+    assert!(mask.saturating_shift_right(10).included(&255));
+    assert_eq!(mask.saturating_shift_right(5), ordmask![15, 255]);
+    assert_eq!(ordmask![_, 250u8].saturating_shift_right(10), ordmask![_]);
+    assert_eq!(mask.saturating_shift_left(20), ordmask![_, 230]);
+    assert_eq!(mask.saturating_shift_right(250), ordmask![]);
+    assert_eq!(
+        ordmask![_, 10u8].saturating_shift_left(20),
+        OrdMask::empty()
+    );
+    assert_eq!(ordmask![5u8, 10].saturating_shift_left(5), ordmask![_, 5]);
+    assert_eq!(
+        ordmask![-100i8, 0].saturating_shift_left(100),
+        ordmask![_, -100]
+    );
+    assert_eq!(ordmask![_, 10u8, 20].saturating_shift_left(30), ordmask![_]);
+}
+
+#[test]
+#[should_panic]
+fn shift_operator_should_panic() {
+    let _ = ordmask![10u8, 250] + 6;
+}
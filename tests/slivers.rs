@@ -0,0 +1,102 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+fn widths(mask: &OrdMask<i32>, included: bool) -> Vec<i32> {
+    let mut widths = Vec::new();
+    let key_points = mask.key_points();
+    for i in 1..key_points.len() {
+        if mask.included(&key_points[i - 1]) == included {
+            widths.push(key_points[i] - key_points[i - 1]);
+        }
+    }
+    widths
+}
+
+#[test]
+fn drop_slivers() {
+    let test_cases = vec![
+        (ordmask![0, 1], ordmask![]),
+        (ordmask![0, 2], ordmask![0, 2]),
+        (ordmask![0, 1, 2], ordmask![2]),
+        (ordmask![_, 0, 1], ordmask![_, 0, 1]),
+        (ordmask![_, 0, 1, 2, 5, 7], ordmask![_, 0, 5, 7]),
+        (ordmask![0, 1, 2, 3, 4, 5], ordmask![]),
+    ];
+
+    for (mut mask, expected) in test_cases {
+        let original = mask.clone();
+        mask.drop_slivers(&2);
+        assert_eq!(mask, expected, "{}", original);
+    }
+}
+
+#[test]
+fn fill_slivers() {
+    let test_cases = vec![
+        (ordmask![_, 0, 1], ordmask![_]),
+        (ordmask![_, 0, 2], ordmask![_, 0, 2]),
+        (ordmask![0, 1, 2], ordmask![0]),
+        (ordmask![0, 1, 2, 3, 4, 5], ordmask![0, 5]),
+        (ordmask![0, 1], ordmask![0, 1]),
+    ];
+
+    for (mut mask, expected) in test_cases {
+        let original = mask.clone();
+        mask.fill_slivers(&2);
+        assert_eq!(mask, expected, "{}", original);
+    }
+}
+
+#[test]
+fn slivers_properties() {
+    for mask in all_masks(6) {
+        for min_width in 0..=3 {
+            let mut dropped = mask.clone();
+            dropped.drop_slivers(&min_width);
+            assert!(dropped.is_subset_of(&mask), "{}", mask);
+            assert!(widths(&dropped, true).iter().all(|w| *w >= min_width));
+            assert_eq!(mask.included(&-1), dropped.included(&-1), "{}", mask);
+            assert_eq!(mask.included(&9), dropped.included(&9), "{}", mask);
+
+            let mut filled = mask.clone();
+            filled.fill_slivers(&min_width);
+            assert!(mask.is_subset_of(&filled), "{}", mask);
+            assert!(widths(&filled, false).iter().all(|w| *w >= min_width));
+            assert_eq!(mask.included(&-1), filled.included(&-1), "{}", mask);
+            assert_eq!(mask.included(&9), filled.included(&9), "{}", mask);
+        }
+    }
+}
+
+#[test]
+fn in_range_min_width() {
+    assert_eq!(OrdMask::in_range_min_width(0, 2, &2), ordmask![0, 2]);
+    assert_eq!(OrdMask::in_range_min_width(0, 1, &2), ordmask![]);
+    assert_eq!(OrdMask::in_range_min_width(5, 5, &0), ordmask![]);
+    assert_eq!(OrdMask::in_range_min_width(2u8, 1, &0), ordmask![]);
+}
+
+#[test]
+fn trim_to_significant_ranges() {
+    for mask in all_masks(6) {
+        for start in -1..7 {
+            for end in -1..7 {
+                for min_width in 0..4 {
+                    let mut expected = mask.intersection_with_range(start, end);
+                    expected.drop_slivers(&min_width);
+                    assert_eq!(
+                        mask.trim_to_significant_ranges(&min_width, (start, end)),
+                        expected,
+                        "{} in [{}, {}) at least {}",
+                        mask,
+                        start,
+                        end,
+                        min_width
+                    );
+                }
+            }
+        }
+    }
+}
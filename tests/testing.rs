@@ -0,0 +1,121 @@
+use ordmask::testing::{check_predecessor_laws, check_successor_laws};
+use ordmask::{Predecessor, Successor};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Cents(i64);
+
+impl Successor for Cents {
+    fn next_value(&self) -> Option<Self> {
+        self.0.checked_add(1).map(Cents)
+    }
+}
+
+impl Predecessor for Cents {
+    fn prev_value(&self) -> Option<Self> {
+        self.0.checked_sub(1).map(Cents)
+    }
+}
+
+/// Skips a value, so 1 is between 0 and its "successor".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Skipping(u8);
+
+impl Successor for Skipping {
+    fn next_value(&self) -> Option<Self> {
+        self.0.checked_add(2).map(Skipping)
+    }
+}
+
+impl Predecessor for Skipping {
+    fn prev_value(&self) -> Option<Self> {
+        self.0.checked_sub(2).map(Skipping)
+    }
+}
+
+/// Claims there is nothing beyond 100.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Capped(u8);
+
+impl Successor for Capped {
+    fn next_value(&self) -> Option<Self> {
+        (self.0 < 100).then(|| Capped(self.0 + 1))
+    }
+}
+
+impl Predecessor for Capped {
+    fn prev_value(&self) -> Option<Self> {
+        (self.0 > 100).then(|| Capped(self.0 - 1))
+    }
+}
+
+/// Goes the wrong way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Backwards(u8);
+
+impl Successor for Backwards {
+    fn next_value(&self) -> Option<Self> {
+        Some(Backwards(self.0.wrapping_sub(1)))
+    }
+}
+
+impl Predecessor for Backwards {
+    fn prev_value(&self) -> Option<Self> {
+        Some(Backwards(self.0.wrapping_add(1)))
+    }
+}
+
+#[test]
+fn lawful_implementations() {
+    check_successor_laws(&[i64::MIN, -1, 0, 1, i64::MAX]);
+    check_predecessor_laws(&[i64::MIN, -1, 0, 1, i64::MAX]);
+    check_successor_laws(&(0..=255u8).collect::<Vec<_>>());
+    check_predecessor_laws(&(0..=255u8).collect::<Vec<_>>());
+    check_successor_laws(&[()]);
+    check_predecessor_laws(&[()]);
+    let cents = [
+        Cents(i64::MIN),
+        Cents(-1),
+        Cents(0),
+        Cents(1),
+        Cents(i64::MAX),
+    ];
+    check_successor_laws(&cents);
+    check_predecessor_laws(&cents);
+    check_successor_laws::<u8>(&[]);
+}
+
+#[test]
+#[should_panic(expected = "is between")]
+fn successor_skipping_a_value() {
+    check_successor_laws(&[Skipping(0), Skipping(1)]);
+}
+
+#[test]
+#[should_panic(expected = "is between")]
+fn predecessor_skipping_a_value() {
+    check_predecessor_laws(&[Skipping(1), Skipping(2)]);
+}
+
+#[test]
+#[should_panic(expected = "is greater than")]
+fn successor_missing_before_the_maximum() {
+    check_successor_laws(&[Capped(100), Capped(101)]);
+}
+
+#[test]
+#[should_panic(expected = "is less than")]
+fn predecessor_missing_after_the_minimum() {
+    check_predecessor_laws(&[Capped(99), Capped(100)]);
+}
+
+#[test]
+#[should_panic(expected = "not greater than")]
+fn successor_going_backwards() {
+    check_successor_laws(&[Backwards(5)]);
+}
+
+#[test]
+#[should_panic(expected = "not less than")]
+fn predecessor_going_backwards() {
+    check_predecessor_laws(&[Backwards(5)]);
+}
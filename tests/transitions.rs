@@ -0,0 +1,124 @@
+use ordmask::{ordmask, Error, OrdMask, TransitionError};
+
+#[test]
+fn from_transitions_lenient() {
+    let mask = OrdMask::from_transitions_lenient([(20, true), (0, true), (10, false)], false);
+    assert_eq!(mask, ordmask![0, 10, 20]);
+
+    // duplicate points, the last one wins
+    let mask =
+        OrdMask::from_transitions_lenient([(0, true), (5, true), (0, false), (5, false)], false);
+    assert_eq!(mask, ordmask![]);
+    let mask =
+        OrdMask::from_transitions_lenient([(5, true), (0, false), (0, true), (9, false)], false);
+    assert_eq!(mask, ordmask![0, 9]);
+
+    // leading redundant transitions
+    let mask = OrdMask::from_transitions_lenient([(-5, false), (-1, false), (0, true)], false);
+    assert_eq!(mask, ordmask![0]);
+    let mask = OrdMask::from_transitions_lenient([(-5, true), (0, false)], true);
+    assert_eq!(mask, ordmask![_, 0]);
+
+    // all redundant
+    let mask = OrdMask::from_transitions_lenient([(3, false), (1, false)], false);
+    assert_eq!(mask, OrdMask::empty());
+    let mask = OrdMask::from_transitions_lenient([(3, true), (1, true)], true);
+    assert_eq!(mask, OrdMask::universal());
+    let mask = OrdMask::from_transitions_lenient(Vec::<(i32, bool)>::new(), true);
+    assert_eq!(mask, OrdMask::universal());
+}
+
+#[test]
+fn try_from_transitions() {
+    let mask = OrdMask::try_from_transitions([(20, true), (0, true), (10, false)], false);
+    assert_eq!(mask, Ok(ordmask![0, 10, 20]));
+
+    let mask = OrdMask::try_from_transitions([(10, true), (0, false)], true);
+    assert_eq!(mask, Ok(ordmask![_, 0, 10]));
+
+    let mask = OrdMask::try_from_transitions([(0, true), (5, true), (5, true)], false);
+    assert_eq!(
+        mask,
+        Err(TransitionError::Redundant {
+            point: 5,
+            included: true
+        })
+    );
+
+    let mask = OrdMask::try_from_transitions([(5, true), (0, false), (0, true)], false);
+    assert_eq!(
+        mask,
+        Err(TransitionError::Redundant {
+            point: 0,
+            included: false
+        })
+    );
+
+    let mask = OrdMask::try_from_transitions([(5, false), (0, true), (0, false)], false);
+    assert_eq!(mask, Err(TransitionError::Contradictory { point: 0 }));
+
+    let mask = OrdMask::try_from_transitions([(3, true)], true);
+    assert_eq!(
+        mask,
+        Err(TransitionError::Redundant {
+            point: 3,
+            included: true
+        })
+    );
+}
+
+#[test]
+fn from_sorted_bool_events() {
+    let test_cases = vec![
+        (vec![], Ok(ordmask![])),
+        (vec![(0, false)], Ok(ordmask![])),
+        (vec![(0, true)], Ok(ordmask![0])),
+        (vec![(0, true), (0, false)], Ok(ordmask![])),
+        (vec![(0, true), (0, false), (0, true)], Ok(ordmask![0])),
+        (vec![(0, true), (5, false), (5, true)], Ok(ordmask![0])),
+        (vec![(0, true), (0, true), (5, false)], Ok(ordmask![0, 5])),
+        (
+            vec![(0, true), (5, false), (10, true)],
+            Ok(ordmask![0, 5, 10]),
+        ),
+        (
+            vec![(5, true), (0, false)],
+            Err(Error::Falling { index: 1 }),
+        ),
+        (
+            vec![(0, false), (5, false), (1, true)],
+            Err(Error::Falling { index: 2 }),
+        ),
+    ];
+
+    for (events, expected) in test_cases {
+        assert_eq!(
+            OrdMask::from_sorted_bool_events(events.clone()),
+            expected,
+            "{:?}",
+            events
+        );
+    }
+}
+
+#[test]
+fn from_sorted_bool_events_matches_lenient() {
+    let events = [
+        (0, true),
+        (0, false),
+        (3, true),
+        (4, true),
+        (6, false),
+        (6, true),
+        (9, false),
+    ];
+    for len in 0..=events.len() {
+        assert_eq!(
+            OrdMask::from_sorted_bool_events(events[..len].iter().copied()),
+            Ok(OrdMask::from_transitions_lenient(
+                events[..len].iter().copied(),
+                false
+            ))
+        );
+    }
+}
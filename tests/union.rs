@@ -0,0 +1,95 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{ordmask, OrdMask};
+
+#[test]
+fn merge_with_capacity_growth() {
+    let masks = all_masks(4);
+    for a in &masks {
+        for b in &masks {
+            assert_eq!(
+                OrdMask::merge_with_capacity_growth(&[a, b], 0),
+                OrdMask::union(&[a, b])
+            );
+        }
+    }
+
+    let masks = [
+        ordmask![0, 10],
+        ordmask![_, -5, 3],
+        ordmask![3, 4, 10, 20],
+        ordmask![10, 15, 30],
+        ordmask![25, 26],
+    ];
+    let masks: Vec<_> = masks.iter().collect();
+    for len in 0..=masks.len() {
+        let union = OrdMask::merge_with_capacity_growth(&masks[..len], 16);
+        assert_eq!(union, OrdMask::union(&masks[..len]));
+        assert!(union.key_points().capacity() >= 16);
+    }
+}
+
+#[test]
+fn union_of_complements() {
+    let masks = all_masks(4);
+    for a in &masks {
+        for b in &masks {
+            assert_eq!(
+                OrdMask::union_of_complements(&[a, b]),
+                OrdMask::union(&[&a.new_complement(), &b.new_complement()]),
+                "{} and {}",
+                a,
+                b
+            );
+        }
+    }
+    assert_eq!(OrdMask::<i32>::union_of_complements(&[]), ordmask![]);
+    assert_eq!(
+        OrdMask::union_of_complements(&[&ordmask![0, 10]]),
+        ordmask![_, 0, 10]
+    );
+}
+
+#[test]
+fn union_assuming_disjoint() {
+    let masks = all_masks(5);
+    for a in &masks {
+        for b in &masks {
+            if !(a & b).is_empty() {
+                continue;
+            }
+            let union = unsafe { a.clone().union_assuming_disjoint(b.clone()) };
+            assert_eq!(union, a | b, "{} and {}", a, b);
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the masks must be disjoint")]
+fn union_assuming_disjoint_overlapping() {
+    let _ = unsafe { ordmask![0, 10].union_assuming_disjoint(ordmask![5, 20]) };
+}
+
+#[test]
+fn union_with_key_point_limit() {
+    let masks = all_masks(4);
+    for a in &masks {
+        for b in &masks {
+            let union = a | b;
+            for limit in 0..6 {
+                let result = OrdMask::union_with_key_point_limit(&[a, b], limit);
+                let context = format!("{a} | {b} limited to {limit}");
+                assert!(result.key_points().len() <= limit, "{context}");
+                assert!(union.is_subset_of(&result), "{context}");
+                if union.key_points().len() <= limit {
+                    assert_eq!(result, union, "{context}");
+                } else {
+                    let kept = &union.key_points()[..result.key_points().len()];
+                    assert_eq!(result.key_points(), kept, "{context}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use ordmask::{ordmask, OrdMask};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Every mask over `()` in every representation.
+fn all_unit_masks() -> Vec<OrdMask<()>> {
+    vec![
+        OrdMask::empty(),
+        OrdMask::universal(),
+        OrdMask::not_less_than(()),
+        OrdMask::less_than(()),
+    ]
+}
+
+#[test]
+fn unit_constructors() {
+    let all: OrdMask<()> = OrdMask::universal();
+    let none: OrdMask<()> = OrdMask::empty();
+    assert!(all.included(&()) && all.is_universal());
+    assert!(none.excluded(&()) && none.is_empty());
+
+    assert_eq!(ordmask![_], all);
+    assert_eq!(ordmask![], none);
+    assert_eq!(ordmask![(), ()], none);
+    assert_eq!(ordmask![_, (), ()], all);
+    assert_eq!(ordmask![()], OrdMask::not_less_than(()));
+    assert_eq!(ordmask![_, ()], OrdMask::less_than(()));
+
+    assert!(OrdMask::not_less_than(()).included(&()));
+    assert!(OrdMask::less_than(()).excluded(&()));
+    assert_eq!(OrdMask::in_range((), ()), none);
+    assert_eq!(OrdMask::exclude_range((), ()), all);
+    assert_eq!(OrdMask::from_ranges([((), ())]), none);
+    assert_eq!(OrdMask::from_inclusive_ranges([((), ())]), ordmask![()]);
+    assert_eq!(
+        OrdMask::from_key_points_set(BTreeSet::from([()]), |_| true, false),
+        ordmask![()]
+    );
+    assert_eq!(
+        OrdMask::from_key_points_map(BTreeMap::from([((), false)]), true),
+        ordmask![_, ()]
+    );
+    assert_eq!(
+        OrdMask::from_transitions_lenient([((), true)], false),
+        ordmask![()]
+    );
+}
+
+#[test]
+fn unit_operations() {
+    let included = |mask: &OrdMask<()>| mask.included(&());
+    for a in &all_unit_masks() {
+        assert_eq!(included(&a.new_complement()), !included(a));
+        assert_eq!(a.to_inclusive_ranges().is_empty(), !included(a));
+        assert!(a.is_valid() && a.is_simplified());
+        for b in &all_unit_masks() {
+            assert_eq!(included(&(a | b)), included(a) || included(b));
+            assert_eq!(included(&(a & b)), included(a) && included(b));
+            assert_eq!(included(&(a - b)), included(a) && !included(b));
+            assert_eq!(included(&(a ^ b)), included(a) != included(b));
+            assert_eq!(
+                included(&OrdMask::union(&[a, b])),
+                included(a) || included(b)
+            );
+            assert_eq!(
+                included(&OrdMask::intersection(&[a, b])),
+                included(a) && included(b)
+            );
+
+            let (mut a, mut b) = (a.clone(), b.clone());
+            a.fold_min_value();
+            b.fold_min_value();
+            assert_eq!(a.is_subset_of(&b), !included(&a) || included(&b));
+            assert_eq!(a == b, included(&a) == included(&b));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "validate")]
+
+use ordmask::validate::{MaskValidateErrors, MaskViolation};
+use ordmask::{ordmask, validate_fields, OrdMask};
+use std::sync::LazyLock;
+
+static ALLOWED_PORTS: LazyLock<OrdMask<u16>> = LazyLock::new(|| ordmask![1024, 49152]);
+
+struct Config {
+    port: u16,
+    workers: usize,
+}
+
+impl Config {
+    fn validate(&self) -> Result<(), MaskValidateErrors> {
+        validate_fields!(self, {
+            port in ALLOWED_PORTS,
+            workers in OrdMask::in_range(1, 65),
+        })
+    }
+}
+
+#[test]
+fn valid() {
+    let config = Config {
+        port: 8080,
+        workers: 64,
+    };
+    assert_eq!(config.validate(), Ok(()));
+}
+
+#[test]
+fn single_violation() {
+    let config = Config {
+        port: 8080,
+        workers: 0,
+    };
+    let errors = config.validate().unwrap_err();
+    assert_eq!(
+        errors.violations,
+        vec![MaskViolation {
+            field: "workers",
+            value: "0".into()
+        }]
+    );
+    assert_eq!(
+        errors.to_string(),
+        "The field workers is 0, which is not included in its mask."
+    );
+}
+
+#[test]
+fn multiple_violations() {
+    let config = Config {
+        port: 443,
+        workers: 100,
+    };
+    let errors = config.validate().unwrap_err();
+    let fields: Vec<_> = errors.violations.iter().map(|v| v.field).collect();
+    assert_eq!(fields, vec!["port", "workers"]);
+    assert_eq!(errors.violations[0].value, "443");
+}
+
+mod renamed {
+    extern crate ordmask as masks;
+
+    use masks::validate::MaskValidateErrors;
+
+    struct Limits {
+        depth: u8,
+    }
+
+    fn validate(limits: &Limits) -> Result<(), MaskValidateErrors> {
+        masks::validate_fields!(limits, { depth in masks::OrdMask::in_range(1, 9) })
+    }
+
+    #[test]
+    fn macro_works_through_a_renamed_crate() {
+        assert!(validate(&Limits { depth: 3 }).is_ok());
+        assert_eq!(
+            validate(&Limits { depth: 0 }).unwrap_err().violations.len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::{all_masks, masks_over};
+use ordmask::OrdMask;
+
+#[test]
+fn intersection_with_range() {
+    for mask in all_masks(4) {
+        for start in -1..6 {
+            for end in -1..6 {
+                let result = mask.intersection_with_range(start, end);
+                let expected = mask.clone() & OrdMask::in_range(start, end);
+                assert!(result.is_valid(), "{mask} [{start}, {end})");
+                for v in -2..8 {
+                    assert_eq!(
+                        result.included(&v),
+                        expected.included(&v),
+                        "{mask} [{start}, {end}) at {v}"
+                    );
+                }
+                assert_eq!(
+                    result.key_points().len(),
+                    expected.key_points().len(),
+                    "{mask} [{start}, {end})"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn intersection_with_range_at_key_points() {
+    for mask in masks_over(&[1, 3, 5]) {
+        for (start, end) in [(1, 3), (1, 5), (3, 5), (0, 6), (3, 3)] {
+            let result = mask.intersection_with_range(start, end);
+            assert_eq!(
+                result,
+                mask.clone() & OrdMask::in_range(start, end),
+                "{mask}"
+            );
+        }
+    }
+}
+
+#[test]
+fn transfer_range() {
+    let masks = all_masks(4);
+    for source in &masks {
+        for dest in &masks {
+            for start in -1..6 {
+                for end in -1..6 {
+                    let (mut a, mut b) = (source.clone(), dest.clone());
+                    let moved = a.transfer_range(&mut b, &start, &end);
+                    let context = format!("{source} -> {dest} [{start}, {end})");
+                    assert_eq!(
+                        moved,
+                        source.intersection_with_range(start, end),
+                        "{context}"
+                    );
+                    assert_eq!(&a | &b, source | dest, "{context}");
+                    assert_eq!(&a & &b, (source & dest) - &moved, "{context}");
+                    assert!(
+                        a.is_strictly_sorted() && b.is_strictly_sorted(),
+                        "{context}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::all_masks;
+use ordmask::{OrdMask, Workspace};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[test]
+fn operations_with_workspace() {
+    let masks = all_masks(6);
+    let mut ws = Workspace::new();
+    for a in &masks {
+        for b in &masks {
+            assert_eq!(
+                OrdMask::union_with_workspace(a, b, &mut ws),
+                OrdMask::union(&[a, b])
+            );
+            assert_eq!(
+                OrdMask::intersection_with_workspace(a, b, &mut ws),
+                OrdMask::intersection(&[a, b])
+            );
+            assert_eq!(OrdMask::minus_with_workspace(a, b, &mut ws), a.minus(&[b]));
+            assert_eq!(
+                OrdMask::symmetric_difference_with_workspace(a, b, &mut ws),
+                a.symmetric_difference(b)
+            );
+        }
+    }
+}
+
+#[test]
+fn workspace_allocates_only_output() {
+    let a = OrdMask::from((0..1000).map(|i| i * 4).collect::<Vec<_>>());
+    let b = OrdMask::from((0..1000).map(|i| i * 4 + 2).collect::<Vec<_>>());
+    let mut ws = Workspace::new();
+    let _ = OrdMask::union_with_workspace(&a, &b, &mut ws);
+
+    let before = allocations();
+    for _ in 0..100 {
+        let union = OrdMask::union_with_workspace(&a, &b, &mut ws);
+        assert_eq!(union.key_points().len(), 1000);
+    }
+    assert_eq!(allocations() - before, 100);
+
+    let before = allocations();
+    let empty = OrdMask::intersection_with_workspace(&a, &OrdMask::empty(), &mut ws);
+    assert!(empty.is_empty());
+    assert_eq!(allocations() - before, 0);
+
+    let before = allocations();
+    let _ = OrdMask::union_with_workspace(&a, &b, &mut Workspace::new());
+    assert!(allocations() - before > 1);
+}